@@ -0,0 +1,31 @@
+/**
+ * Keyframes registry for the host-side `animation` style prop.
+ *
+ * Animations run entirely in Bevy; React only registers the keyframes and sets
+ * `style.animation`. Lifecycle arrives as `onAnimationStart` / `onAnimationEnd`.
+ */
+
+import type { BevyStyle } from "./types";
+
+/**
+ * Register (or replace) keyframes under `name`. Keys are `from`, `to` or
+ * percentages (`"50%"`, `"0%, 100%"`); values are style objects.
+ *
+ * ```ts
+ * registerKeyframes("pulse", {
+ *   from: { opacity: 1 },
+ *   "50%": { opacity: 0.4, transform: "scale(1.1)" },
+ *   to: { opacity: 1 },
+ * });
+ * ```
+ */
+export function registerKeyframes(
+  name: string,
+  keyframes: Record<string, BevyStyle>
+): void {
+  if (typeof __react_register_keyframes !== "function") {
+    console.warn("[bevy-react] __react_register_keyframes is not available yet");
+    return;
+  }
+  __react_register_keyframes(name, JSON.stringify(keyframes));
+}
@@ -1,9 +1,18 @@
 import { forwardRef, type ReactNode, type Ref } from "react";
-import type { ButtonProps, ImageProps, NodeProps, TextProps } from "../types";
+import type {
+  ButtonProps,
+  ImageProps,
+  MaterialProps,
+  NodeProps,
+  SvgProps,
+  TextProps,
+  ViewportProps,
+} from "../types";
 import type { BevyHostInstance } from "../entity";
 
 /**
- * Text display component. Maps to Bevy's Text component.
+ * Text display component. Maps to Bevy's Text component; nested `<Text>` and
+ * mixed string children become `TextSpan`s of the same paragraph.
  */
 export function Text(props: TextProps): ReactNode {
   return <bevy-text {...props}>{props.children}</bevy-text>;
@@ -16,6 +25,21 @@ export function Image(props: ImageProps): ReactNode {
   return <bevy-image {...props} />;
 }
 
+/**
+ * Vector image from `src` or inline `markup`, rasterized at the node's
+ * laid-out size (and again on resize).
+ */
+export function Svg(props: SvgProps): ReactNode {
+  return <bevy-svg {...props} />;
+}
+
+/**
+ * Live view of a Bevy camera, rendered into an image sized to this node.
+ */
+export function Viewport(props: ViewportProps): ReactNode {
+  return <bevy-viewport {...props} />;
+}
+
 /**
  * Container node for layout. Maps to Bevy's Node component.
  * Supports `components` (named Rust bundles) and entity refs via `useEntityRef`.
@@ -38,3 +62,16 @@ export const Button = forwardRef(function Button(
 ): ReactNode {
   return <bevy-button {...(props as ButtonProps)} {...({ ref } as object)} />;
 });
+
+/**
+ * Node drawn with a Rust-registered `UiMaterial` (`MaterialNode`).
+ * `props` are written into the material's uniforms whenever they change.
+ */
+export const Material = forwardRef(function Material(
+  props: MaterialProps,
+  ref: Ref<BevyHostInstance>
+): ReactNode {
+  return (
+    <bevy-material {...(props as MaterialProps)} {...({ ref } as object)} />
+  );
+});
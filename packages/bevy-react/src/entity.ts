@@ -4,10 +4,14 @@
  * Host components expose a public instance `{ nodeId }` via reconciler refs.
  * After Bevy processes CreateNode, `__react_entity_id(nodeId)` returns
  * `Entity::to_bits()` for use from Rust (`Entity::from_bits`) or gameplay bridges.
+ * `measure(ref)` asks the host for the node's laid-out rect.
  */
 
 import { useCallback, useEffect, useState, type RefCallback } from "react";
 
+import { callNative } from "./bridge";
+import type { LayoutEventData } from "./types";
+
 /** Public instance returned by host component refs (`getPublicInstance`). */
 export type BevyHostInstance = {
   nodeId: number;
@@ -120,3 +124,30 @@ export function useEntityRef(): [
   const entity = useEntity(nodeId);
   return [ref, entity];
 }
+
+/** Bridge command handled by the plugin: `{ nodeId }` → `LayoutEventData`. */
+export const MEASURE_NODE_COMMAND = "measure_node";
+
+/**
+ * Laid-out rect of a host node in logical pixels (same shape as `onLayout`).
+ * Accepts a host instance, a ref holding one (or a node id), or a node id.
+ * Resolves after the next Bevy frame; rejects before the node is laid out.
+ */
+export function measure(
+  nodeRef:
+    | BevyHostInstance
+    | { current: BevyHostInstance | number | null }
+    | number
+    | null
+    | undefined
+): Promise<LayoutEventData> {
+  const target =
+    nodeRef && typeof nodeRef === "object" && "current" in nodeRef
+      ? nodeRef.current
+      : nodeRef;
+  const nodeId = typeof target === "number" ? target : target?.nodeId;
+  if (nodeId == null) {
+    return Promise.reject(new Error("measure: ref is not attached"));
+  }
+  return callNative<LayoutEventData>(MEASURE_NODE_COMMAND, { nodeId });
+}
@@ -38,7 +38,8 @@ declare function __react_register_event_dispatcher(callback: EventDispatcher): v
 /**
  * Forward host events into the reconciler's per-root instance map.
  * Event type names match what Rust enqueues: click, press, release, focus, blur,
- * mouseenter, mouseleave, mousemove, drag, keydown, keyup, wheel, scroll.
+ * mouseenter, mouseleave, mousemove, drag, keydown, keyup, wheel, scroll,
+ * animationstart, animationend, layout, load, error.
  */
 export function hostDispatchEvent(
   rootId: string,
@@ -110,6 +110,23 @@ declare global {
    */
   function __react_clear_container(rootId: string): void;
 
+  /**
+   * Mark the end of a React commit for a root (per-op path only; BRRP frames
+   * carry a Commit op). The host emits `ReactRootCommitted`.
+   */
+  function __react_commit(rootId: string): void;
+
+  /**
+   * Report that a root's export rendered (`ReactRootMounted`). Called by the
+   * host's mount script, not by app code.
+   */
+  function __react_root_mounted(rootId: string): void;
+
+  /**
+   * Report that a root failed to import or render (`ReactRootFailed`).
+   */
+  function __react_root_failed(rootId: string, message: string): void;
+
   /**
    * Commit one BRRP binary batch (host built with `--features binary_ops`).
    * Decodes into the same ReactClientProto channel as the per-op natives.
@@ -154,6 +171,20 @@ declare global {
    */
   function __react_request_blur(): void;
 
+  /**
+   * Register (or replace) a named keyframes definition used by the `animation` style prop.
+   * @param name - Animation name referenced from `animation: "<name> ..."`
+   * @param keyframesJson - JSON object keyed by `from` / `to` / `"50%"` with style objects
+   */
+  function __react_register_keyframes(name: string, keyframesJson: string): void;
+
+  /**
+   * Register (or replace) a named style class referenced by the `className` prop.
+   * @param name - Class name
+   * @param styleJson - JSON-encoded style object (same shape as `style`)
+   */
+  function __react_register_style(name: string, styleJson: string): void;
+
   /**
    * Registers a callback the host invokes when flushing bridge state channels.
    */
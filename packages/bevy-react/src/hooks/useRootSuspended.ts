@@ -0,0 +1,33 @@
+import { useContext, useSyncExternalStore } from "react";
+import {
+  BevyRootContext,
+  isRootSuspended,
+  subscribeRootLifecycle,
+} from "../roots";
+
+/**
+ * True while the host has this component's root paused (`ReactRootPaused`).
+ *
+ * Paused roots stay mounted but hidden and receive no events. Gate timers,
+ * polling and animation effects on this value so they stop while hidden:
+ *
+ * ```tsx
+ * const suspended = useRootSuspended();
+ * useEffect(() => {
+ *   if (suspended) return;
+ *   const handle = setInterval(tick, 100);
+ *   return () => clearInterval(handle);
+ * }, [suspended]);
+ * ```
+ */
+export function useRootSuspended(): boolean {
+  const rootId = useContext(BevyRootContext);
+  return useSyncExternalStore(
+    (onStoreChange) =>
+      rootId === null
+        ? () => {}
+        : subscribeRootLifecycle(rootId, () => onStoreChange()),
+    () => (rootId === null ? false : isRootSuspended(rootId)),
+    () => false
+  );
+}
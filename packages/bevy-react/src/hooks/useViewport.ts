@@ -0,0 +1,29 @@
+import { useBridgeState } from "../bridge";
+
+/** Logical size and scale factor of the primary window (`ReactViewport`). */
+export interface Viewport {
+  width: number;
+  height: number;
+  scaleFactor: number;
+}
+
+/** Bridge channel the host publishes `ReactViewport` on. */
+export const VIEWPORT_CHANNEL = "viewport";
+
+const INITIAL_VIEWPORT: Viewport = { width: 1280, height: 720, scaleFactor: 1 };
+
+/**
+ * Primary window size for logic-level breakpoints. Re-renders only when the
+ * window is resized or its scale factor changes.
+ *
+ * For purely visual changes prefer a `media` style block, which the host
+ * re-evaluates without a React render:
+ *
+ * ```tsx
+ * const { width } = useViewport();
+ * return width < 1280 ? <CompactHud /> : <FullHud />;
+ * ```
+ */
+export function useViewport(): Viewport {
+  return useBridgeState<Viewport>(VIEWPORT_CHANNEL, INITIAL_VIEWPORT);
+}
@@ -13,8 +13,10 @@ import {
   getInstance,
   getRoot,
   renderRoot,
+  resumeRoot,
   rootCount,
   setInstanceLookup,
+  suspendRoot,
   unmountRoot,
 } from "./roots";
 
@@ -33,6 +35,9 @@ export type {
   WheelEventPayload,
 } from "./events";
 
+export { registerKeyframes } from "./animation";
+export { registerStyle, StyleSheet } from "./stylesheet";
+
 export {
   callNative,
   getBridgeState,
@@ -46,15 +51,34 @@ export {
 } from "./bridge";
 
 export {
+  bevyStateChannel,
+  setBevyState,
+  setBevyStateCommand,
+  useBevyState,
+} from "./states";
+
+export { MEASURE_TEXT_COMMAND, measureText } from "./measure";
+export type { MeasureTextStyle, TextSize } from "./measure";
+
+export {
+  BevyRootContext,
   ensureRoot,
   getInstance,
   getRoot,
+  isRootSuspended,
   listRoots,
   renderRoot,
+  resumeRoot,
   rootCount,
+  subscribeRootLifecycle,
+  suspendRoot,
   unmountRoot,
 } from "./roots";
 
+export { useRootSuspended } from "./hooks/useRootSuspended";
+export { useViewport, VIEWPORT_CHANNEL } from "./hooks/useViewport";
+export type { Viewport } from "./hooks/useViewport";
+
 export {
   BevyErrorBoundary,
   reportErrorToHost,
@@ -64,6 +88,8 @@ export type { ReportErrorOptions } from "./ErrorBoundary";
 
 export {
   entityFromBits,
+  measure,
+  MEASURE_NODE_COMMAND,
   resolveEntity,
   useEntity,
   useEntityRef,
@@ -116,6 +142,10 @@ export interface BevyReactApp {
   render: (rootId: string) => void;
   dispatchEvent: typeof dispatchEvent;
   unmount: (rootId: string) => void;
+  /** Host inserted `ReactRootPaused`: keep the tree, stop timers / effects. */
+  suspend: (rootId: string) => void;
+  /** Host removed `ReactRootPaused`. */
+  resume: (rootId: string) => void;
 }
 
 // Wire root-scoped instance lookup once at module load.
@@ -144,5 +174,7 @@ export function createBevyApp(
     dispatchEvent,
     render: (rootId: string) => render(element, rootId, options),
     unmount: (rootId: string) => unmountRoot(rootId),
+    suspend: (rootId: string) => suspendRoot(rootId),
+    resume: (rootId: string) => resumeRoot(rootId),
   };
 }
@@ -0,0 +1,43 @@
+/**
+ * Host-side text measurement.
+ *
+ * Rust handles the `measure_text` bridge command by laying the string out with
+ * the same font resolution as text nodes (`fontFamily` / `fontWeight` /
+ * `fontStyle` through `ReactFontRegistry`, then the default font).
+ */
+
+import { callNative } from "./bridge";
+import type { BevyStyle } from "./types";
+
+/** Bridge command handled by the plugin. */
+export const MEASURE_TEXT_COMMAND = "measure_text";
+
+/** Text style props that affect measurement. */
+export type MeasureTextStyle = Pick<
+  BevyStyle,
+  | "fontSize"
+  | "fontFamily"
+  | "fontWeight"
+  | "fontStyle"
+  | "lineHeight"
+  | "whiteSpace"
+>;
+
+/** Size in logical pixels. */
+export interface TextSize {
+  width: number;
+  height: number;
+}
+
+/**
+ * Measure `text` as a text node with `style` would lay it out, wrapped at
+ * `maxWidth` when given. Resolves after the next Bevy frame; rejects while the
+ * font is still loading.
+ */
+export function measureText(
+  text: string,
+  style: MeasureTextStyle = {},
+  maxWidth?: number
+): Promise<TextSize> {
+  return callNative<TextSize>(MEASURE_TEXT_COMMAND, { text, style, maxWidth });
+}
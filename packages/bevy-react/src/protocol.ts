@@ -28,6 +28,7 @@ export const enum OpCode {
   DestroyNode = 0x08,
   ClearContainer = 0x09,
   Commit = 0x0a,
+  RegisterStyle = 0x0b,
 }
 
 /** One mutation inside a batch. `rootId` lives on the frame, not per op. */
@@ -51,7 +52,9 @@ export type BinaryOp =
   | { op: "UpdateText"; nodeId: number; content: string }
   | { op: "DestroyNode"; nodeId: number }
   | { op: "ClearContainer" }
-  | { op: "Commit" };
+  | { op: "Commit" }
+  /** Style class for `className`; not root-scoped. */
+  | { op: "RegisterStyle"; name: string; styleJson: string };
 
 export type EncodeBatchOptions = {
   /** When true, set FLAG_STRING_TABLE and intern repeated strings. */
@@ -235,6 +238,10 @@ function collectOpStrings(op: BinaryOp, intern: StringInterner): void {
     case "UpdateText":
       intern.intern(op.content);
       break;
+    case "RegisterStyle":
+      intern.intern(op.name);
+      intern.intern(op.styleJson);
+      break;
     default:
       break;
   }
@@ -289,6 +296,11 @@ function writeOpInline(w: Writer, op: BinaryOp): void {
     case "Commit":
       w.writeU8(OpCode.Commit);
       break;
+    case "RegisterStyle":
+      w.writeU8(OpCode.RegisterStyle);
+      w.writeInlineString(op.name);
+      w.writeInlineString(op.styleJson);
+      break;
   }
 }
 
@@ -345,6 +357,11 @@ function writeOpWithTable(
     case "Commit":
       w.writeU8(OpCode.Commit);
       break;
+    case "RegisterStyle":
+      w.writeU8(OpCode.RegisterStyle);
+      writeStringRef(w, op.name, intern);
+      writeStringRef(w, op.styleJson, intern);
+      break;
   }
 }
 
@@ -430,6 +447,12 @@ function readOp(cur: Cursor, table: string[] | null): BinaryOp {
       return { op: "ClearContainer" };
     case OpCode.Commit:
       return { op: "Commit" };
+    case OpCode.RegisterStyle:
+      return {
+        op: "RegisterStyle",
+        name: readStringRef(cur, table),
+        styleJson: readStringRef(cur, table),
+      };
     default:
       throw new DecodeError(`UnknownOpcode(${opcode})`);
   }
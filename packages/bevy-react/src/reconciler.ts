@@ -154,11 +154,6 @@ function isTextInstance(
   return !("type" in child);
 }
 
-function flushBevyTextContent(hostConfig: BevyHostConfig, host: Instance): void {
-  const content = (host.textSlots ?? []).map((slot) => slot.text).join("");
-  hostConfig.updateNode(host.nodeId, JSON.stringify({ content }));
-}
-
 /**
  * Build a props diff for host updates. Returns null when nothing serializable changed.
  * Skips structural `children` (host children) but tracks text content for bevy-text.
@@ -218,7 +213,8 @@ function serializeProps(props: Props, type?: string): string {
 
   for (const [key, value] of Object.entries(props)) {
     // For text hosts, flatten text-only children into `content`.
-    // Do not set content when children include elements — HostText folding handles that.
+    // Do not set content when children include elements — those children are
+    // appended as their own nodes and the host turns them into spans.
     if (key === "children") {
       if (type === "bevy-text") {
         const content = flattenTextChildren(value);
@@ -227,7 +223,7 @@ function serializeProps(props: Props, type?: string): string {
         } else if (value === undefined || value === null) {
           serializable["content"] = null;
         }
-        // else: mixed/host children — leave content unset; slots sync later
+        // else: mixed/host children — leave content unset; each child is its own span
       }
       continue;
     }
@@ -355,6 +351,31 @@ function invokeHandler(
       if (typeof onScroll === "function") onScroll(synthetic);
       break;
     }
+    case "animationstart": {
+      const onAnimationStart = props.onAnimationStart;
+      if (typeof onAnimationStart === "function") onAnimationStart(synthetic);
+      break;
+    }
+    case "animationend": {
+      const onAnimationEnd = props.onAnimationEnd;
+      if (typeof onAnimationEnd === "function") onAnimationEnd(synthetic);
+      break;
+    }
+    case "layout": {
+      const onLayout = props.onLayout;
+      if (typeof onLayout === "function") onLayout(synthetic);
+      break;
+    }
+    case "load": {
+      const onLoad = props.onLoad;
+      if (typeof onLoad === "function") onLoad(synthetic);
+      break;
+    }
+    case "error": {
+      const onError = props.onError;
+      if (typeof onError === "function") onError(synthetic);
+      break;
+    }
     case "change": {
       const onChange = props.onChange;
       if (typeof onChange === "function") onChange(synthetic);
@@ -612,10 +633,6 @@ class BevyHostConfig {
   getInstanceFromScope = () => null;
   detachDeletedInstance = (instance: Instance | TextInstance) => {
     if (isTextInstance(instance)) {
-      if (instance.textHost) {
-        // Folded into bevy-text — host owns the ECS entity.
-        return;
-      }
       if (instance.nodeId < 0) {
         return;
       }
@@ -667,9 +684,8 @@ class BevyHostConfig {
     _rootContainer: Container,
     _hostContext: HostContext
   ): TextInstance => {
-    // Defer ECS create until we know the parent. Text under `bevy-text` is folded
-    // into the host's content (see appendInitialChild) so UpdateText cannot target
-    // a sibling entity while glyphs live on the host.
+    // Defer ECS create until the text is attached. Under a `bevy-text` the host
+    // turns it into a span of that paragraph, so UpdateText edits it in place.
     return {
       nodeId: -1,
       text,
@@ -724,10 +740,6 @@ class BevyHostConfig {
     newText: string
   ): void => {
     textInstance.text = newText;
-    if (textInstance.textHost) {
-      flushBevyTextContent(this, textInstance.textHost);
-      return;
-    }
     if (textInstance.nodeId < 0) {
       return;
     }
@@ -736,7 +748,6 @@ class BevyHostConfig {
 
   resetTextContent = (instance: Instance): void => {
     if (instance.type === "bevy-text") {
-      instance.textSlots = [];
       this.updateNode(instance.nodeId, JSON.stringify({ content: "" }));
     }
   }
@@ -757,15 +768,6 @@ class BevyHostConfig {
     parent: Instance,
     child: Instance | TextInstance
   ): void => {
-    if (isTextInstance(child) && parent.type === "bevy-text") {
-      child.textHost = parent;
-      child.nodeId = parent.nodeId;
-      parent.textSlots = parent.textSlots ?? [];
-      parent.textSlots.push(child);
-      flushBevyTextContent(this, parent);
-      return;
-    }
-
     if (isTextInstance(child)) {
       if (child.nodeId < 0) {
         child.nodeId = this.createText(child.text);
@@ -789,13 +791,6 @@ class BevyHostConfig {
     child: Instance | TextInstance
   ): void => {
     if (isTextInstance(child)) {
-      if (child.textHost) {
-        const host = child.textHost;
-        host.textSlots = (host.textSlots ?? []).filter((s) => s !== child);
-        child.textHost = undefined;
-        flushBevyTextContent(this, host);
-        return;
-      }
       if (child.nodeId >= 0) {
         this.removeChildRpc(container.rootId, child.nodeId);
         this.destroyNode(child.nodeId);
@@ -813,13 +808,6 @@ class BevyHostConfig {
     parent: Instance,
     child: Instance | TextInstance
   ): void => {
-    if (isTextInstance(child) && child.textHost === parent) {
-      parent.textSlots = (parent.textSlots ?? []).filter((s) => s !== child);
-      child.textHost = undefined;
-      flushBevyTextContent(this, parent);
-      return;
-    }
-
     if (isTextInstance(child)) {
       if (child.nodeId >= 0) {
         this.removeChildRpc(parent.nodeId, child.nodeId);
@@ -860,26 +848,6 @@ class BevyHostConfig {
     child: Instance | TextInstance,
     beforeChild: Instance | TextInstance
   ): void => {
-    if (isTextInstance(child) && parent.type === "bevy-text") {
-      child.textHost = parent;
-      child.nodeId = parent.nodeId;
-      parent.textSlots = parent.textSlots ?? [];
-      const beforeIdx = isTextInstance(beforeChild)
-        ? parent.textSlots.indexOf(beforeChild)
-        : parent.textSlots.length;
-      const existingIdx = parent.textSlots.indexOf(child);
-      if (existingIdx !== -1) {
-        parent.textSlots.splice(existingIdx, 1);
-      }
-      if (beforeIdx >= 0) {
-        parent.textSlots.splice(beforeIdx, 0, child);
-      } else {
-        parent.textSlots.push(child);
-      }
-      flushBevyTextContent(this, parent);
-      return;
-    }
-
     if (isTextInstance(child) && child.nodeId < 0) {
       child.nodeId = this.createText(child.text);
       this.instanceMap.set(child.nodeId, child);
@@ -953,7 +921,15 @@ class BevyHostConfig {
   }
 
   resetAfterCommit = (_containerInfo: Container): void => {
-    this.flushBinaryCommit();
+    if (this.useBinary) {
+      // BRRP frames end with a Commit op, which the host attributes to the root.
+      this.flushBinaryCommit();
+      return;
+    }
+    // Host emits `ReactRootCommitted`; older hosts lack the native.
+    if (typeof __react_commit === "function") {
+      __react_commit(this.props.rootId);
+    }
   }
 
   // -------------------
@@ -962,8 +938,8 @@ class BevyHostConfig {
 
   shouldSetTextContent = (type: Type, props: Props): boolean => {
     // Prefer host text content for bevy-text when children are text-only
-    // (including `["label: ", n]`). Falls back to HostText folding if React
-    // still creates text instances.
+    // (including `["label: ", n]`). Mixed children (`Deal <Text>50</Text>`)
+    // create text instances that the host turns into spans.
     if (type === "bevy-text") {
       return flattenTextChildren(props.children) !== undefined;
     }
@@ -1,4 +1,4 @@
-import type { ReactNode } from "react";
+import { createContext, createElement, type ReactNode } from "react";
 import { injectBevyReactDevTools } from "./devtools";
 import { reportErrorToHost, withErrorBoundary } from "./ErrorBoundary";
 import {
@@ -29,9 +29,17 @@ export type BevyRootState = {
   fiberRoot: ReturnType<BevyReconciler["createContainer"]>;
   instanceMap: BevyInstanceMap;
   binaryOps?: boolean;
+  /** True while the host has the root paused (`ReactRootPaused`). */
+  suspended: boolean;
 };
 
+type RootLifecycleListener = (suspended: boolean) => void;
+
 const roots = new Map<string, BevyRootState>();
+const lifecycleListeners = new Map<string, Set<RootLifecycleListener>>();
+
+/** Id of the root a component is rendered into (provided by `renderRoot`). */
+export const BevyRootContext = createContext<string | null>(null);
 
 /**
  * Get an existing root's state, if mounted.
@@ -104,6 +112,7 @@ export function ensureRoot(
     fiberRoot,
     instanceMap,
     binaryOps: options?.binaryOps,
+    suspended: false,
   };
   roots.set(rootId, state);
   return state;
@@ -119,7 +128,11 @@ export function renderRoot(
 ): void {
   const root = ensureRoot(rootId, options);
   root.reconciler.updateContainer(
-    withErrorBoundary(element),
+    createElement(
+      BevyRootContext.Provider,
+      { value: rootId },
+      withErrorBoundary(element)
+    ),
     root.fiberRoot,
     null,
     () => {
@@ -152,6 +165,61 @@ export function unmountRoot(rootId: string): void {
 
   root.instanceMap.clear();
   roots.delete(rootId);
+  lifecycleListeners.delete(rootId);
+}
+
+function setRootSuspended(rootId: string, suspended: boolean): void {
+  const root = roots.get(rootId);
+  if (!root || root.suspended === suspended) {
+    return;
+  }
+  root.suspended = suspended;
+  for (const listener of lifecycleListeners.get(rootId) ?? []) {
+    listener(suspended);
+  }
+}
+
+/**
+ * Mark a root suspended (host `ReactRootPaused` inserted). The tree stays
+ * mounted; subscribers pause timers / effects until {@link resumeRoot}.
+ */
+export function suspendRoot(rootId: string): void {
+  setRootSuspended(rootId, true);
+}
+
+/**
+ * Resume a suspended root (host `ReactRootPaused` removed). State is intact.
+ */
+export function resumeRoot(rootId: string): void {
+  setRootSuspended(rootId, false);
+}
+
+/** Whether `rootId` is currently suspended by the host. */
+export function isRootSuspended(rootId: string): boolean {
+  return roots.get(rootId)?.suspended ?? false;
+}
+
+/**
+ * Subscribe to suspend / resume transitions for a root.
+ * Returns an unsubscribe function.
+ */
+export function subscribeRootLifecycle(
+  rootId: string,
+  listener: RootLifecycleListener
+): () => void {
+  let set = lifecycleListeners.get(rootId);
+  if (!set) {
+    set = new Set();
+    lifecycleListeners.set(rootId, set);
+  }
+  set.add(listener);
+
+  return () => {
+    set!.delete(listener);
+    if (set!.size === 0) {
+      lifecycleListeners.delete(rootId);
+    }
+  };
 }
 
 /**
@@ -0,0 +1,36 @@
+/**
+ * Bevy `States` exposed through the data bridge.
+ *
+ * Rust registers a state with `app.register_react_state::<AppState>("AppState")`,
+ * which publishes the current value on `bevy_state:AppState` and handles
+ * `set_bevy_state:AppState` calls by queueing `NextState::set`.
+ */
+
+import { callNative, useBridgeState } from "./bridge";
+
+/** Bridge channel carrying the current value of a registered state. */
+export function bevyStateChannel(name: string): string {
+  return `bevy_state:${name}`;
+}
+
+/** Bridge command that queues a transition for a registered state. */
+export function setBevyStateCommand(name: string): string {
+  return `set_bevy_state:${name}`;
+}
+
+/**
+ * Current value of a Bevy state registered with `register_react_state`.
+ * Unit enum variants arrive as strings (`"Menu"`). `undefined` until the
+ * first flush.
+ */
+export function useBevyState<T = string>(name: string): T | undefined {
+  return useBridgeState<T | undefined>(bevyStateChannel(name), undefined);
+}
+
+/**
+ * Queue a transition (`NextState::set`). Resolves once the host has accepted
+ * the value; rejects when it does not deserialize into the state type.
+ */
+export function setBevyState<T = string>(name: string, value: T): Promise<void> {
+  return callNative<void>(setBevyStateCommand(name), value);
+}
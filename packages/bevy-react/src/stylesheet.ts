@@ -0,0 +1,64 @@
+/**
+ * Style classes for the `className` prop.
+ *
+ * Classes are registered with the host once and referenced by name, so large
+ * style objects are not re-serialized on every commit. The host cascades
+ * classes (in `className` order) under the inline `style`, resolves `var()`
+ * theme tokens, and restyles nodes when a class is re-registered.
+ */
+
+import type { BevyStyle } from "./types";
+
+/** Last JSON sent per class, so re-registering an identical style is a no-op. */
+const registered = new Map<string, string>();
+let nextSheetId = 1;
+
+/**
+ * Register (or replace) the style class `name`. Nodes already using it
+ * restyle on the host without a React render.
+ */
+export function registerStyle(name: string, style: BevyStyle): void {
+  const styleJson = JSON.stringify(style);
+  if (registered.get(name) === styleJson) {
+    return;
+  }
+  if (typeof __react_register_style !== "function") {
+    console.warn("[bevy-react] __react_register_style is not available yet");
+    return;
+  }
+  registered.set(name, styleJson);
+  __react_register_style(name, styleJson);
+}
+
+/**
+ * ```tsx
+ * const styles = StyleSheet.create({
+ *   card: { padding: 12, backgroundColor: "var(--surface)" },
+ *   title: { fontSize: 18, hover: { color: "var(--accent)" } },
+ * });
+ *
+ * <node className={styles.card} style={{ width: 200 }} />
+ * <node className={[styles.card, selected && "card-selected"]} />
+ * ```
+ */
+export const StyleSheet = {
+  /**
+   * Register every entry as a class and return its generated class names.
+   * Names are unique per call, so two sheets may reuse the same keys.
+   */
+  create<T extends Record<string, BevyStyle>>(
+    styles: T
+  ): { [K in keyof T]: string } {
+    const sheetId = nextSheetId++;
+    const names = {} as { [K in keyof T]: string };
+    for (const key of Object.keys(styles) as Array<keyof T & string>) {
+      const name = `${key}-${sheetId}`;
+      registerStyle(name, styles[key]!);
+      names[key] = name;
+    }
+    return names;
+  },
+
+  /** Register a class under a fixed, app-wide name (e.g. `"button-primary"`). */
+  register: registerStyle,
+};
@@ -1,11 +1,13 @@
 import type { ReactNode, Ref } from "react";
-import type { BevyHostInstance } from "./entity";
+import type { BevyHostInstance, EntityId } from "./entity";
 
 /**
  * Style properties that map to Bevy's UI Style component.
  * Values can be:
  * - Numbers (treated as pixels)
  * - Strings like "100px", "50%", "auto"
+ * - Theme variables: `"var(--accent)"`, `"var(--space-2, 8px)"` (resolved on the
+ *   host against `ReactTheme` and the nearest `theme` prop)
  */
 export interface BevyStyle {
   // Size
@@ -63,6 +65,10 @@ export interface BevyStyle {
   gridColumnEnd?: string | number;
   gridRowStart?: string | number;
   gridRowEnd?: string | number;
+  /** `"main"` or `"1 / 2 / 3 / 4"` (row-start / column-start / row-end / column-end) */
+  gridArea?: string | number;
+  /** One quoted string per row: `"'header header' 'side main'"` */
+  gridTemplateAreas?: string;
 
   // Spacing (shorthands accept 1–4 CSS values, e.g. `"8px 16px"`)
   gap?: string | number;
@@ -108,8 +114,12 @@ export interface BevyStyle {
   boxShadow?: string;
   /** CSS text-shadow → Bevy `TextShadow` (e.g. `"2px 3px 0 black"`). */
   textShadow?: string;
-  /** 0–1 or percentage string. Bevy has no UiOpacity; multiply into colors in render. */
+  /** 0–1 or percentage string. Fades the node and all of its descendants. */
   opacity?: string | number;
+  /** `translate(...)` / `scale(...)` / `rotate(...)` list → Bevy `UiTransform` (host-side). */
+  transform?: string;
+  /** CSS `transform-origin`, e.g. `"top left"` or `"0 100%"`. Defaults to the centre. */
+  transformOrigin?: string;
   zIndex?: number;
 
   // Display / overflow
@@ -123,11 +133,34 @@ export interface BevyStyle {
   // Text (also used on Text style)
   color?: string;
   fontSize?: string | number;
-  /** Font asset path (e.g. `"fonts/FiraSans.ttf"`). Generic CSS families ignored. */
+  /**
+   * Comma list of families registered in `ReactFontRegistry` and/or font asset
+   * paths (e.g. `"Inter, fonts/FiraSans.ttf"`), tried in order.
+   */
   fontFamily?: string;
+  /** `100`–`900`, `"normal"`, `"bold"`; picks a face of a registered family. */
+  fontWeight?: number | "normal" | "bold" | "bolder" | "lighter";
+  fontStyle?: "normal" | "italic" | "oblique";
   textAlign?: "left" | "right" | "center" | "justify" | "start" | "end";
   /** Unitless multiplier or `"24px"`. */
   lineHeight?: string | number;
+  /**
+   * `normal` / `nowrap` / `pre-line` collapse white space; `nowrap` / `pre`
+   * disable wrapping. Unset renders text as written.
+   */
+  whiteSpace?:
+    | "normal"
+    | "nowrap"
+    | "pre"
+    | "pre-wrap"
+    | "pre-line"
+    | "break-spaces";
+  /** `"ellipsis"` cuts text that overflows its box (or `maxLines`) with `…`. */
+  textOverflow?: "clip" | "ellipsis";
+  /** Show at most this many lines; the rest is cut. */
+  maxLines?: number;
+  /** Alias of `maxLines`. */
+  WebkitLineClamp?: number;
 
   /**
    * Pointer hit testing for full-screen / transparent HUD roots.
@@ -135,9 +168,32 @@ export interface BevyStyle {
    * `"auto"` → restore default blocking (omit for default).
    */
   pointerEvents?: "none" | "auto";
+  /**
+   * Window cursor while the pointer is over the node (inherited by children;
+   * the innermost hovered node wins). A CSS keyword, or a custom image with
+   * hotspot and fallbacks: `"url(cursors/sword.png) 4 4, pointer"`.
+   * Text inputs default to `"text"`.
+   */
+  cursor?:
+    | "auto"
+    | "default"
+    | "pointer"
+    | "text"
+    | "grab"
+    | "grabbing"
+    | "not-allowed"
+    | "move"
+    | "crosshair"
+    | "help"
+    | "wait"
+    | "ew-resize"
+    | "ns-resize"
+    | (string & {});
 
   // Image
   objectFit?: "fill" | "contain" | "cover" | "none" | "scale-down" | "stretch" | "auto";
+  /** Nine-slice border in px, CSS 1–4 values: `"16"` or `"8 12"`. Wins over `objectFit`. */
+  imageSlice?: string;
   tint?: string;
   tintColor?: string;
 
@@ -157,11 +213,44 @@ export interface BevyStyle {
    */
   checked?: BevyStyle;
   /**
-   * Host-side transitions for color/numeric props between interaction states.
-   * String: `"backgroundColor 100ms"` or `"backgroundColor 100ms, opacity 200ms"`.
-   * Object: `{ backgroundColor: 100 }` (values in milliseconds).
+   * Host-side transitions for colors, opacity, transform and lengths. Runs when
+   * interaction state or the base style (a prop update) changes the target.
+   * String: `"width 200ms ease-out 50ms, opacity 1s"` (duration, timing, delay).
+   * Object: `{ backgroundColor: 100 }` (milliseconds) or `{ padding: "150ms ease" }`.
    */
   transition?: string | Record<string, number | string>;
+  /**
+   * Responsive overrides keyed by media query, evaluated on the host against
+   * the node's render target and re-applied on resize without a React render:
+   * `{ "(max-width: 1280px)": { padding: 8 }, "(min-resolution: 2dppx)": {...} }`.
+   * Matching blocks cascade over the style in source order.
+   */
+  media?: Record<string, BevyStyle>;
+  /**
+   * Host-side keyframe animations, CSS shorthand:
+   * `"pulse 1s ease-in-out 200ms infinite alternate both"`. Comma-separate to
+   * run several. Register names with `registerKeyframes` first.
+   */
+  animation?: string;
+  /** `"running"` | `"paused"`, comma-separated per `animation` entry. */
+  animationPlayState?: string;
+}
+
+/**
+ * Pin a node to a Bevy entity's projected screen position (`anchor` prop).
+ * The host writes `left` / `top` every frame; React does not re-render.
+ */
+export interface AnchorProp {
+  /** Target entity: an `EntityId` or its `bits`. */
+  entity: EntityId | number;
+  /** World-space offset from the entity's translation, e.g. `[0, 2, 0]`. */
+  offset?: [number, number, number];
+  /** Point of the node placed on the projection (fractions of its size). Default `[0.5, 0.5]`. */
+  pivot?: [number, number];
+  /** Hide while off-screen or behind the camera. Default `true`. */
+  hideOffscreen?: boolean;
+  /** Camera to project through; defaults to the root's UI camera. */
+  camera?: EntityId | number;
 }
 
 /**
@@ -175,6 +264,18 @@ export interface NodeProps {
    * Register appliers with `BundleRegistry::register("Glow", ...)`.
    */
   components?: string[];
+  /** Follow a world entity on screen (nameplates, markers). */
+  anchor?: AnchorProp;
+  /**
+   * Theme variables for this node and its subtree, e.g. `{ "--accent": "#4af" }`.
+   * Overrides the root / global `ReactTheme` for `var()` in descendant styles.
+   */
+  theme?: Record<string, string | number>;
+  /**
+   * Style classes registered with `StyleSheet`, cascaded in order under `style`.
+   * Falsy array entries are skipped: `[styles.card, active && styles.active]`.
+   */
+  className?: string | Array<string | false | null | undefined>;
   onClick?: (event?: PointerSyntheticEvent | PointerEventData) => void;
   onPress?: (event?: PointerSyntheticEvent | PointerEventData) => void;
   onRelease?: (event?: PointerSyntheticEvent | PointerEventData) => void;
@@ -185,6 +286,9 @@ export interface NodeProps {
   onDrag?: (event?: PointerSyntheticEvent | PointerEventData) => void;
   onWheel?: (event?: WheelSyntheticEvent | WheelEventData) => void;
   onScroll?: (event?: ScrollSyntheticEvent | ScrollEventData) => void;
+  onAnimationStart?: (event?: AnimationSyntheticEvent | AnimationEventData) => void;
+  onAnimationEnd?: (event?: AnimationSyntheticEvent | AnimationEventData) => void;
+  onLayout?: (event: LayoutSyntheticEvent | LayoutEventData) => void;
 }
 
 /**
@@ -230,6 +334,41 @@ export interface ScrollEventData {
   deltaY?: number;
 }
 
+/**
+ * Keyframe animation payload (`animationstart` / `animationend`, non-bubbling).
+ * `elapsedTime` is in seconds, excluding `animation-delay`.
+ */
+export interface AnimationEventData {
+  animationName: string;
+  elapsedTime: number;
+}
+
+/**
+ * Node rect after Bevy UI layout (`layout` events, non-bubbling), in logical
+ * pixels with the origin at the top-left of the root's UI target.
+ */
+export interface LayoutEventData {
+  x: number;
+  y: number;
+  width: number;
+  height: number;
+}
+
+/**
+ * `<image>` finished loading (`load` events, non-bubbling); size in image pixels.
+ */
+export interface ImageLoadEventData {
+  width: number;
+  height: number;
+}
+
+/**
+ * `<image>` failed to fetch or decode (`error` events, non-bubbling).
+ */
+export interface ImageErrorEventData {
+  message: string;
+}
+
 /**
  * Synthetic extras attached by the reconciler for bubbling / `stopPropagation`.
  * Payload fields are spread onto the same object so handlers can read `event.key` / `event.x`.
@@ -245,6 +384,10 @@ export type PointerSyntheticEvent = PointerEventData & SyntheticEventExtras;
 export type KeyboardSyntheticEvent = KeyboardEventData & SyntheticEventExtras;
 export type WheelSyntheticEvent = WheelEventData & SyntheticEventExtras;
 export type ScrollSyntheticEvent = ScrollEventData & SyntheticEventExtras;
+export type AnimationSyntheticEvent = AnimationEventData & SyntheticEventExtras;
+export type LayoutSyntheticEvent = LayoutEventData & SyntheticEventExtras;
+export type ImageLoadSyntheticEvent = ImageLoadEventData & SyntheticEventExtras;
+export type ImageErrorSyntheticEvent = ImageErrorEventData & SyntheticEventExtras;
 
 /**
  * Props for the <button> element (ButtonBundle with interaction)
@@ -304,14 +447,73 @@ export interface TextInputInternalProps extends NodeProps {
 export interface TextProps {
   children?: ReactNode;
   style?: BevyStyle;
+  onLayout?: (event: LayoutSyntheticEvent | LayoutEventData) => void;
+}
+
+/**
+ * Texture atlas frame (`atlas` prop on `<image>`), optionally animated on the host.
+ */
+export interface AtlasProp {
+  /** `TextureAtlasLayout` asset path, e.g. `"ui/icons.atlas.ron"`. */
+  layout: string;
+  /** Frame to show. Default `0`. */
+  index?: number;
+  /** Frames to cycle: atlas indices, or a count of frames starting at `index`. */
+  frames?: number | number[];
+  /** Playback rate; animation runs only with `fps > 0` and two or more frames. */
+  fps?: number;
+  /** Repeat (default `true`); otherwise stop on the last frame. */
+  loop?: boolean;
 }
 
 /**
  * Props for the <image> element (ImageBundle)
  */
 export interface ImageProps {
+  /** Asset path, `http(s)://` URL or `data:image/...;base64,` URI. */
   src: string;
   style?: BevyStyle;
+  /** Show one frame of a texture atlas, or play a sprite sheet. */
+  atlas?: AtlasProp;
+  onLoad?: (event: ImageLoadSyntheticEvent | ImageLoadEventData) => void;
+  onError?: (event: ImageErrorSyntheticEvent | ImageErrorEventData) => void;
+  onLayout?: (event: LayoutSyntheticEvent | LayoutEventData) => void;
+}
+
+/**
+ * Props for the <viewport> element: a live view of a Bevy camera.
+ */
+export interface ViewportProps {
+  /**
+   * Camera to show: an `EntityId` or its `bits`. Its target becomes an image
+   * sized to this node; its own target comes back on unmount.
+   */
+  camera?: EntityId | number;
+  style?: BevyStyle;
+  onLayout?: (event: LayoutSyntheticEvent | LayoutEventData) => void;
+}
+
+/**
+ * Props for the <material> element: a `UiMaterial` registered in Rust by name.
+ */
+export interface MaterialProps extends NodeProps {
+  /** Name given to `ReactMaterialRegistry::register` / `register_react_material`. */
+  name: string;
+  /** Deserialized into the material's props struct; changes update it in place. */
+  props?: Record<string, unknown>;
+}
+
+/**
+ * Props for the <svg> element, rasterized by the host at the node's size.
+ * `style.color` sets `currentColor`; `style.tint` multiplies the result.
+ */
+export interface SvgProps {
+  /** `*.svg` asset path or `http(s)://` URL. */
+  src?: string;
+  /** Inline SVG document; wins over `src`. */
+  markup?: string;
+  style?: BevyStyle;
+  onLayout?: (event: LayoutSyntheticEvent | LayoutEventData) => void;
 }
 
 /**
@@ -324,12 +526,6 @@ export interface BevyInstance {
   children: BevyInstance[];
   /** Parent node id for event bubbling; unset for container children. */
   parentId?: number;
-  /**
-   * HostText children folded into this `bevy-text` node's `content`.
-   * Avoids a dual-entity bug where CreateNode paints glyphs on the host while
-   * UpdateText mutates a sibling HostText entity that never shows.
-   */
-  textSlots?: BevyTextInstance[];
 }
 
 /**
@@ -338,13 +534,12 @@ export interface BevyInstance {
 export interface BevyTextInstance {
   nodeId: number;
   text: string;
-  /** Present when this text is folded into a `bevy-text` host instead of its own entity. */
-  textHost?: BevyInstance;
 }
 
 /**
  * Internal JSX augmentation for host elements.
- * Users should import the exported components (Node, Button, Text, Image) instead.
+ * Users should import the exported components (Node, Button, Text, Image, Svg,
+ * Material, Viewport) instead.
  */
 type BevyIntrinsicElements = {
   "bevy-node": NodeProps & { ref?: Ref<BevyHostInstance> };
@@ -354,6 +549,9 @@ type BevyIntrinsicElements = {
   "bevy-checkbox": CheckboxHostProps;
   "bevy-text": TextProps;
   "bevy-image": ImageProps;
+  "bevy-svg": SvgProps;
+  "bevy-material": MaterialProps & { ref?: Ref<BevyHostInstance> };
+  "bevy-viewport": ViewportProps;
   "bevy-text-input": TextInputInternalProps;
 };
 
@@ -13,6 +13,9 @@ import {
   useResource,
   useQuery,
 } from "../src/bridge";
+import { measure } from "../src/entity";
+import { measureText } from "../src/measure";
+import { bevyStateChannel, setBevyState } from "../src/states";
 
 describe("bridge", () => {
   const g = globalThis as typeof globalThis & Record<string, unknown>;
@@ -97,6 +100,44 @@ describe("bridge", () => {
     resolve(calls[0].callId, "pong");
     return expect(p).resolves.toBe("pong");
   });
+
+  it("setBevyState calls the per-state command with the JSON value", async () => {
+    const promise = setBevyState("AppState", "InGame");
+    expect(calls).toHaveLength(1);
+    expect(calls[0].name).toBe("set_bevy_state:AppState");
+    expect(calls[0].argsJson).toBe('"InGame"');
+    hostResolveBridgeCall(calls[0].callId, null);
+    await expect(promise).resolves.toBeNull();
+  });
+
+  it("measureText calls measure_text with the text, style and width", async () => {
+    const promise = measureText("Iron sword", { fontSize: 16 }, 120);
+    expect(calls[0].name).toBe("measure_text");
+    expect(JSON.parse(calls[0].argsJson)).toEqual({
+      text: "Iron sword",
+      style: { fontSize: 16 },
+      maxWidth: 120,
+    });
+    hostResolveBridgeCall(calls[0].callId, { width: 80, height: 20 });
+    await expect(promise).resolves.toEqual({ width: 80, height: 20 });
+  });
+
+  it("measure resolves a ref's node id through measure_node", async () => {
+    const promise = measure({ current: { nodeId: 12 } });
+    expect(calls[0].name).toBe("measure_node");
+    expect(JSON.parse(calls[0].argsJson)).toEqual({ nodeId: 12 });
+    const rect = { x: 10, y: 20, width: 100, height: 40 };
+    hostResolveBridgeCall(calls[0].callId, rect);
+    await expect(promise).resolves.toEqual(rect);
+
+    await expect(measure({ current: null })).rejects.toThrow();
+    expect(calls).toHaveLength(1);
+  });
+
+  it("bevy state channels match the Rust naming", () => {
+    hostDispatchBridge(bevyStateChannel("AppState"), "Menu");
+    expect(getBridgeState("bevy_state:AppState")).toBe("Menu");
+  });
 });
 
 /** Mirrors examples/hud generated PLAYER_STATS_KEYS (Rust serde field order). */
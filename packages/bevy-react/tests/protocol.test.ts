@@ -96,6 +96,11 @@ const SAMPLE_OPS: BinaryOp[] = [
   { op: "RemoveChild", parentId: 1, childId: 3 },
   { op: "DestroyNode", nodeId: 3 },
   { op: "ClearContainer" },
+  {
+    op: "RegisterStyle",
+    name: "card",
+    styleJson: '{"padding":12}',
+  },
   { op: "Commit" },
 ];
 
@@ -138,6 +138,20 @@ describe("reconciler host config RPC sequences", () => {
     });
   });
 
+  it("signals __react_commit with the root id after each commit", () => {
+    const g = globalThis as typeof globalThis & Record<string, unknown>;
+    const commits: string[] = [];
+    g.__react_commit = (rootId: string) => commits.push(rootId);
+    try {
+      const { render } = createRenderer(mock);
+      render(React.createElement("bevy-node", { style: { width: 10 } }));
+      render(React.createElement("bevy-node", { style: { width: 20 } }));
+      expect(commits).toEqual([ROOT_ID, ROOT_ID]);
+    } finally {
+      delete g.__react_commit;
+    }
+  });
+
   it("unmounts by issuing remove_child + destroy_node", () => {
     const { render, unmount } = createRenderer(mock);
     render(
@@ -305,6 +319,40 @@ describe("reconciler host config RPC sequences", () => {
     }
   });
 
+  it("keeps mixed text children in order as children of the bevy-text", () => {
+    const { render } = createRenderer(mock);
+    const paragraph = (damage: number) =>
+      React.createElement(
+        "bevy-text",
+        null,
+        "Deal ",
+        React.createElement("bevy-text", { style: { color: "red" } }, damage),
+        " damage"
+      );
+    render(paragraph(50));
+
+    const root = mock.calls.find(
+      (c) =>
+        c.op === "create_node" && c.type === "bevy-text" && !c.propsJson.includes("red")
+    );
+    const texts = mock.calls.filter((c) => c.op === "create_text");
+    expect(texts.map((c) => c.op === "create_text" && c.content)).toEqual(["Deal ", " damage"]);
+    if (!root || root.op !== "create_node") {
+      throw new Error("expected paragraph create_node");
+    }
+    const appended = mock.calls
+      .filter((c) => c.op === "append_child" && c.parentId === root.nodeId)
+      .map((c) => (c.op === "append_child" ? c.childId : -1));
+    expect(appended).toHaveLength(3);
+    expect(appended[0]).toBe(texts[0].nodeId);
+    expect(appended[2]).toBe(texts[1].nodeId);
+
+    mock.reset();
+    render(paragraph(75));
+    expect(mock.ops()).toEqual(["update_node"]);
+    expect(mock.ops()).not.toContain("update_text");
+  });
+
   it("reorders keyed children via append_child (move to end)", () => {
     const { render } = createRenderer(mock);
     render(
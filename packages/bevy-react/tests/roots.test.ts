@@ -0,0 +1,53 @@
+/**
+ * Root lifecycle: suspend / resume without unmounting.
+ */
+import { afterEach, describe, expect, it } from "vitest";
+import {
+  ensureRoot,
+  getRoot,
+  isRootSuspended,
+  resumeRoot,
+  subscribeRootLifecycle,
+  suspendRoot,
+  unmountRoot,
+} from "../src/roots";
+
+const ROOT_ID = "lifecycle-test";
+
+describe("root lifecycle", () => {
+  afterEach(() => {
+    unmountRoot(ROOT_ID);
+  });
+
+  it("suspend and resume keep the root mounted", () => {
+    const state = ensureRoot(ROOT_ID);
+    expect(isRootSuspended(ROOT_ID)).toBe(false);
+
+    suspendRoot(ROOT_ID);
+    expect(isRootSuspended(ROOT_ID)).toBe(true);
+    expect(getRoot(ROOT_ID)).toBe(state);
+
+    resumeRoot(ROOT_ID);
+    expect(isRootSuspended(ROOT_ID)).toBe(false);
+    expect(getRoot(ROOT_ID)).toBe(state);
+  });
+
+  it("notifies subscribers only on transitions", () => {
+    ensureRoot(ROOT_ID);
+    const seen: boolean[] = [];
+    const unsubscribe = subscribeRootLifecycle(ROOT_ID, (s) => seen.push(s));
+
+    suspendRoot(ROOT_ID);
+    suspendRoot(ROOT_ID);
+    resumeRoot(ROOT_ID);
+    unsubscribe();
+    suspendRoot(ROOT_ID);
+
+    expect(seen).toEqual([true, false]);
+  });
+
+  it("ignores unknown roots", () => {
+    suspendRoot("missing-root");
+    expect(isRootSuspended("missing-root")).toBe(false);
+  });
+});
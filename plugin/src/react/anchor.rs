@@ -0,0 +1,370 @@
+//! World-anchored UI — the host-side `anchor` prop.
+//!
+//! `anchor={{ entity, offset: [0, 2, 0] }}` pins a node to a world entity's
+//! projected screen position. [`update_react_anchors`] writes `Node.left/top`
+//! every frame, so nameplates and markers follow their entity without React
+//! re-rendering.
+
+use bevy::prelude::*;
+use bevy::transform::helper::TransformHelper;
+use bevy::ui::{ComputedUiTargetCamera, UiGlobalTransform};
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Pins a UI node to `entity`'s projected position (from the `anchor` prop).
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct ReactAnchor {
+    pub entity: Entity,
+    /// World-space offset added to the entity's translation (e.g. above a head).
+    pub offset: Vec3,
+    /// Point of the node placed on the projection, as a fraction of its size;
+    /// `(0.5, 0.5)` centres it, `(0.5, 1.0)` sits it on top of the point.
+    pub pivot: Vec2,
+    /// Hide the node while the point is off-screen or behind the camera.
+    pub hide_offscreen: bool,
+    /// Camera to project through; defaults to the node's UI target camera.
+    pub camera: Option<Entity>,
+}
+
+/// Set while [`update_react_anchors`] has hidden the node, so it only restores
+/// visibility it took away. Holds the node's own visibility to put back.
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct ReactAnchorHidden {
+    previous: Option<Visibility>,
+}
+
+impl ReactAnchorHidden {
+    /// Visibility to restore: a restyle that overwrote `Hidden` while the node
+    /// was hidden wins over what was saved when hiding.
+    fn restored(&self, current: Option<&Visibility>) -> Option<Visibility> {
+        match current {
+            Some(Visibility::Hidden) => self.previous,
+            other => other.copied(),
+        }
+    }
+}
+
+/// `anchor` prop as sent by React. Entities are `Entity::to_bits` numbers or
+/// `EntityId` objects (`{ bits }`).
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AnchorProps {
+    entity: Value,
+    #[serde(default)]
+    offset: Option<[f32; 3]>,
+    #[serde(default)]
+    pivot: Option<[f32; 2]>,
+    #[serde(default)]
+    hide_offscreen: Option<bool>,
+    #[serde(default)]
+    camera: Option<Value>,
+}
+
+pub(crate) fn entity_from_prop(value: &Value) -> Option<Entity> {
+    let bits = match value {
+        Value::Object(map) => map.get("bits")?.as_f64()?,
+        other => other.as_f64()?,
+    };
+    Entity::try_from_bits(bits as u64)
+}
+
+/// Parse the `anchor` prop; `None` when absent, `null` or malformed.
+pub fn parse_anchor(props: &Value) -> Option<ReactAnchor> {
+    let props = AnchorProps::deserialize(props.get("anchor")?).ok()?;
+    Some(ReactAnchor {
+        entity: entity_from_prop(&props.entity)?,
+        offset: props.offset.map(Vec3::from).unwrap_or(Vec3::ZERO),
+        pivot: props.pivot.map(Vec2::from).unwrap_or(Vec2::splat(0.5)),
+        hide_offscreen: props.hide_offscreen.unwrap_or(true),
+        camera: props.camera.as_ref().and_then(entity_from_prop),
+    })
+}
+
+/// Insert or clear [`ReactAnchor`] from React props (create / update paths).
+pub fn sync_anchor(commands: &mut Commands, entity: Entity, props: &Value) {
+    match parse_anchor(props) {
+        Some(anchor) => {
+            commands.entity(entity).insert(anchor);
+        }
+        None => {
+            commands.queue(move |world: &mut World| {
+                let Ok(mut entity_mut) = world.get_entity_mut(entity) else {
+                    return;
+                };
+                if entity_mut.take::<ReactAnchor>().is_none() {
+                    return;
+                }
+                if let Some(hidden) = entity_mut.take::<ReactAnchorHidden>() {
+                    match hidden.restored(entity_mut.get::<Visibility>()) {
+                        Some(visibility) => {
+                            entity_mut.insert(visibility);
+                        }
+                        None => {
+                            entity_mut.remove::<Visibility>();
+                        }
+                    }
+                }
+            });
+        }
+    }
+}
+
+/// Project `world` into logical viewport pixels (origin top-left).
+///
+/// Mirrors [`Camera::world_to_viewport`] on raw inputs so it can run without a
+/// render target. Returns `None` behind the camera or past its far plane.
+pub fn project_to_viewport(
+    clip_from_view: Mat4,
+    camera_transform: &GlobalTransform,
+    viewport: Rect,
+    world: Vec3,
+) -> Option<Vec2> {
+    let view = camera_transform.affine().inverse().transform_point3(world);
+    let ndc = clip_from_view.project_point3(view);
+    if ndc.is_nan() || !(0.0..=1.0).contains(&ndc.z) {
+        return None;
+    }
+    let ndc = Vec2::new(ndc.x, -ndc.y);
+    Some((ndc + Vec2::ONE) / 2.0 * viewport.size() + viewport.min)
+}
+
+/// `left` / `top` that place `pivot` of a `node_size` node on `point`, relative
+/// to a parent whose top-left corner is at `parent_origin` (all logical pixels).
+pub fn anchored_position(point: Vec2, node_size: Vec2, pivot: Vec2, parent_origin: Vec2) -> Vec2 {
+    point - parent_origin - node_size * pivot
+}
+
+/// Position anchored nodes over their entities. Runs in `PostUpdate` after
+/// `resolve_calc_lengths` and before UI layout, so it has the last word on
+/// `left` / `top` and `Visibility` over styles applied in `Update`; transforms
+/// are recomputed so the node does not trail a frame.
+#[allow(clippy::type_complexity)]
+pub fn update_react_anchors(
+    mut anchored: Query<(
+        Entity,
+        &ReactAnchor,
+        &mut Node,
+        &ComputedNode,
+        Option<&ComputedUiTargetCamera>,
+        Option<&ChildOf>,
+        Option<&Visibility>,
+        Option<&ReactAnchorHidden>,
+    )>,
+    parents: Query<(&ComputedNode, &UiGlobalTransform)>,
+    cameras: Query<&Camera>,
+    transforms: TransformHelper,
+    mut commands: Commands,
+) {
+    for (entity, anchor, mut node, computed, target_camera, child_of, visibility, hidden) in
+        &mut anchored
+    {
+        let point = anchor
+            .camera
+            .or_else(|| target_camera.and_then(|t| t.get()))
+            .and_then(|camera_entity| {
+                let camera = cameras.get(camera_entity).ok()?;
+                let viewport = camera.logical_viewport_rect()?;
+                let camera_transform = transforms.compute_global_transform(camera_entity).ok()?;
+                let target = transforms.compute_global_transform(anchor.entity).ok()?;
+                let point = project_to_viewport(
+                    camera.clip_from_view(),
+                    &camera_transform,
+                    viewport,
+                    target.translation() + anchor.offset,
+                )?;
+                Some((point, viewport.contains(point)))
+            });
+
+        if let Some((point, _)) = point {
+            let parent_origin = child_of
+                .and_then(|c| parents.get(c.parent()).ok())
+                .map(|(parent, transform)| {
+                    (transform.translation - parent.size / 2.0) * parent.inverse_scale_factor
+                })
+                .unwrap_or(Vec2::ZERO);
+            let node_size = computed.size * computed.inverse_scale_factor;
+            let position = anchored_position(point, node_size, anchor.pivot, parent_origin);
+
+            // Only touch `Node` when the position moved, to keep layout change detection quiet.
+            let (left, top) = (Val::Px(position.x), Val::Px(position.y));
+            if node.position_type != PositionType::Absolute || node.left != left || node.top != top
+            {
+                node.position_type = PositionType::Absolute;
+                node.left = left;
+                node.top = top;
+            }
+        }
+
+        // Check the actual `Visibility`, not just the marker: a restyle while hidden
+        // rewrites it, and the node must not show at a stale position.
+        let visible = point.is_some_and(|(_, on_screen)| on_screen);
+        if anchor.hide_offscreen && !visible {
+            if visibility != Some(&Visibility::Hidden) {
+                let previous = visibility.copied();
+                commands
+                    .entity(entity)
+                    .insert((Visibility::Hidden, ReactAnchorHidden { previous }));
+            }
+        } else if let Some(hidden) = hidden {
+            let mut entity_commands = commands.entity(entity);
+            entity_commands.remove::<ReactAnchorHidden>();
+            match hidden.restored(visibility) {
+                Some(visibility) => {
+                    entity_commands.insert(visibility);
+                }
+                None => {
+                    entity_commands.remove::<Visibility>();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::camera::{CameraProjection, PerspectiveProjection};
+    use serde_json::json;
+
+    use super::*;
+    use crate::react::message_tests::{ROOT_ID, context_nodes, flush, setup_app};
+    use crate::react::systems::process_react_messages;
+
+    const VIEWPORT: Rect = Rect {
+        min: Vec2::ZERO,
+        max: Vec2::new(800.0, 600.0),
+    };
+
+    fn projection() -> Mat4 {
+        PerspectiveProjection {
+            aspect_ratio: VIEWPORT.width() / VIEWPORT.height(),
+            ..default()
+        }
+        .get_clip_from_view()
+    }
+
+    #[test]
+    fn test_point_ahead_projects_to_viewport_center() {
+        let camera = GlobalTransform::IDENTITY;
+        let point = project_to_viewport(projection(), &camera, VIEWPORT, Vec3::new(0.0, 0.0, -10.0));
+        let point = point.unwrap();
+        assert!((point - VIEWPORT.center()).length() < 1e-3, "{point:?}");
+    }
+
+    #[test]
+    fn test_projection_follows_camera_transform() {
+        let camera = GlobalTransform::from_translation(Vec3::new(5.0, 0.0, 0.0));
+        let right = project_to_viewport(projection(), &camera, VIEWPORT, Vec3::new(6.0, 1.0, -10.0))
+            .unwrap();
+        assert!(right.x > VIEWPORT.center().x, "x grows to the right");
+        assert!(right.y < VIEWPORT.center().y, "y grows downwards");
+    }
+
+    #[test]
+    fn test_point_behind_camera_is_rejected() {
+        let camera = GlobalTransform::IDENTITY;
+        let behind = project_to_viewport(projection(), &camera, VIEWPORT, Vec3::new(0.0, 0.0, 10.0));
+        assert_eq!(behind, None);
+    }
+
+    #[test]
+    fn test_anchored_position_applies_pivot_and_parent_origin() {
+        let position = anchored_position(
+            Vec2::new(400.0, 300.0),
+            Vec2::new(100.0, 20.0),
+            Vec2::new(0.5, 1.0),
+            Vec2::new(50.0, 0.0),
+        );
+        assert_eq!(position, Vec2::new(300.0, 280.0));
+    }
+
+    #[test]
+    fn test_parse_anchor_accepts_bits_and_entity_objects() {
+        let target = Entity::from_raw_u32(7).unwrap();
+        let bits = target.to_bits();
+
+        let anchor =
+            parse_anchor(&json!({"anchor": {"entity": bits, "offset": [0, 2, 0]}})).unwrap();
+        assert_eq!(anchor.entity, target);
+        assert_eq!(anchor.offset, Vec3::new(0.0, 2.0, 0.0));
+        assert_eq!(anchor.pivot, Vec2::splat(0.5));
+        assert!(anchor.hide_offscreen);
+
+        let anchor = parse_anchor(&json!({
+            "anchor": {"entity": {"bits": bits, "index": 7}, "hideOffscreen": false}
+        }))
+        .unwrap();
+        assert_eq!(anchor.entity, target);
+        assert!(!anchor.hide_offscreen);
+
+        assert_eq!(parse_anchor(&json!({"anchor": null})), None);
+        assert_eq!(parse_anchor(&json!({"style": {}})), None);
+    }
+
+    #[test]
+    fn test_missing_target_hides_node_and_clearing_anchor_restores_it() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_systems(Update, update_react_anchors);
+
+        let node = app
+            .world_mut()
+            .spawn((
+                Node::default(),
+                ReactAnchor {
+                    entity: Entity::from_raw_u32(999).unwrap(),
+                    offset: Vec3::ZERO,
+                    pivot: Vec2::splat(0.5),
+                    hide_offscreen: true,
+                    camera: None,
+                },
+            ))
+            .id();
+        app.update();
+        assert_eq!(app.world().get::<Visibility>(node), Some(&Visibility::Hidden));
+        assert!(app.world().get::<ReactAnchorHidden>(node).is_some());
+
+        let mut commands = app.world_mut().commands();
+        sync_anchor(&mut commands, node, &json!({}));
+        app.world_mut().flush();
+        assert!(app.world().get::<ReactAnchor>(node).is_none());
+        assert_eq!(
+            app.world().get::<Visibility>(node),
+            Some(&Visibility::Inherited)
+        );
+    }
+
+    #[test]
+    fn test_style_update_keeps_offscreen_node_hidden() {
+        let (mut app, client) = setup_app();
+        app.add_systems(Update, update_react_anchors.after(process_react_messages));
+
+        let bits = Entity::from_raw_u32(999).unwrap().to_bits();
+        let node_id = client.create_node(
+            ROOT_ID.to_string(),
+            "bevy-node".into(),
+            format!(r#"{{"anchor":{{"entity":{bits}}},"style":{{"width":10}}}}"#),
+        );
+        flush(&mut app);
+        let node = context_nodes(&app)[&node_id];
+        let visibility = |app: &App| app.world().get::<Visibility>(node).copied();
+        assert_eq!(visibility(&app), Some(Visibility::Hidden));
+
+        // The restyle drops `Visibility`; the anchor must hide the node again.
+        client.update_node(
+            ROOT_ID.to_string(),
+            node_id,
+            format!(r#"{{"anchor":{{"entity":{bits}}},"style":{{"width":20}}}}"#),
+        );
+        flush(&mut app);
+        assert_eq!(visibility(&app), Some(Visibility::Hidden));
+
+        // Clearing the anchor restores the restyled node's own visibility.
+        client.update_node(
+            ROOT_ID.to_string(),
+            node_id,
+            r#"{"style":{"width":20}}"#.into(),
+        );
+        flush(&mut app);
+        assert_eq!(visibility(&app), None);
+    }
+}
@@ -0,0 +1,669 @@
+//! Host-side keyframe animations — the `animation` style prop.
+//!
+//! Keyframes are registered by name in [`ReactKeyframes`], from Rust or once
+//! from JS (`registerKeyframes`). [`advance_react_animations`] steps every
+//! node's animations and stores the sampled style in its [`ReactStyleState`];
+//! `apply_interaction_styles` layers that over the node's style. Spinners and
+//! pulses therefore run without timers or React re-renders.
+
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use serde_json::{Value, json};
+
+use crate::react::event_queue::ReactEventQueue;
+use crate::react::style::{
+    CssScalar, CssValue, StyleProps, TimingFunction, color_to_css, merge_style_props,
+    next_css_token, parse_color, parse_timing_function, parse_val, split_css_list, style_opacity,
+    style_transform, transform_to_css, val_to_css,
+};
+use crate::react::systems::{
+    KeyframeRun, ReactNode, ReactRoot, ReactStyleState, StyleLerp, find_root_id,
+};
+
+/// Style at one point of a [`Keyframes`] timeline (`offset` in `0..=1`).
+#[derive(Clone, Debug)]
+pub struct Keyframe {
+    pub offset: f32,
+    pub style: StyleProps,
+}
+
+/// A named `@keyframes` timeline, sorted by offset.
+#[derive(Clone, Debug, Default)]
+pub struct Keyframes {
+    frames: Vec<Keyframe>,
+}
+
+impl Keyframes {
+    /// Parse a CSS-like object: `{ "from": {...}, "50%": {...}, "to": {...} }`.
+    /// A selector may list several offsets (`"0%, 100%"`).
+    pub fn from_json(value: &Value) -> Option<Self> {
+        let mut frames = Vec::new();
+        for (selector, style) in value.as_object()? {
+            let mut style: StyleProps = match serde_json::from_value(style.clone()) {
+                Ok(style) => style,
+                Err(e) => {
+                    log::warn!("Invalid keyframe '{}': {}", selector, e);
+                    continue;
+                }
+            };
+            style.finalize_parse();
+            for offset in selector.split(',').filter_map(parse_keyframe_offset) {
+                frames.push(Keyframe {
+                    offset,
+                    style: style.clone(),
+                });
+            }
+        }
+        if frames.is_empty() {
+            return None;
+        }
+        frames.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+        Some(Self { frames })
+    }
+
+    /// [`Keyframes::from_json`] on a JSON string.
+    pub fn parse(json: &str) -> Option<Self> {
+        Self::from_json(&serde_json::from_str(json).ok()?)
+    }
+
+    pub fn frames(&self) -> &[Keyframe] {
+        &self.frames
+    }
+
+    /// Style at `progress`. Each property eases between the keyframes that set
+    /// it; properties that cannot interpolate take the last keyframe reached.
+    pub fn sample(&self, progress: f32, timing: TimingFunction) -> StyleProps {
+        let mut out = StyleProps::default();
+        for (i, frame) in self.frames.iter().enumerate() {
+            if i == 0 || frame.offset <= progress {
+                out = merge_style_props(&out, &frame.style);
+            }
+        }
+
+        for (get, set) in KEYFRAME_COLORS {
+            let color = self.interpolate(progress, timing, |s| {
+                get(s).as_deref().and_then(parse_color)
+            });
+            if let Some(color) = color {
+                *set(&mut out) = Some(color_to_css(color));
+            }
+        }
+
+        if let Some(opacity) = self.interpolate(progress, timing, style_opacity) {
+            out.opacity = Some(CssScalar(opacity.to_string()));
+        }
+        if let Some(transform) = self.interpolate(progress, timing, style_transform) {
+            out.transform = Some(transform_to_css(&transform));
+        }
+        for (get, set) in KEYFRAME_LENGTHS {
+            let length = self.interpolate(progress, timing, |s| {
+                get(s).as_ref().and_then(|v| single_length(&v.0))
+            });
+            if let Some(length) = length {
+                *set(&mut out) = Some(CssValue(val_to_css(length)));
+            }
+        }
+        out
+    }
+
+    /// Interpolate one property between the keyframes around `progress` that set it.
+    fn interpolate<T: StyleLerp>(
+        &self,
+        progress: f32,
+        timing: TimingFunction,
+        get: impl Fn(&StyleProps) -> Option<T>,
+    ) -> Option<T> {
+        let mut before = None;
+        let mut after = None;
+        for frame in &self.frames {
+            let Some(value) = get(&frame.style) else {
+                continue;
+            };
+            if frame.offset <= progress {
+                before = Some((frame.offset, value));
+            } else if after.is_none() {
+                after = Some((frame.offset, value));
+            }
+        }
+        match (before, after) {
+            (Some((from_offset, from)), Some((to_offset, to))) => {
+                let t = (progress - from_offset) / (to_offset - from_offset);
+                Some(from.style_lerp(&to, timing.sample(t)))
+            }
+            (Some((_, value)), None) | (None, Some((_, value))) => Some(value),
+            (None, None) => None,
+        }
+    }
+}
+
+/// Read / write accessors for one `StyleProps` field.
+type StyleField<T> = (
+    fn(&StyleProps) -> &Option<T>,
+    fn(&mut StyleProps) -> &mut Option<T>,
+);
+
+macro_rules! style_field {
+    ($name:ident) => {
+        (|s| &s.$name, |s| &mut s.$name)
+    };
+}
+
+const KEYFRAME_COLORS: &[StyleField<String>] = &[
+    style_field!(background_color),
+    style_field!(border_color),
+    style_field!(color),
+];
+
+/// Single-value lengths keyframes interpolate (shorthand lists switch discretely).
+const KEYFRAME_LENGTHS: &[StyleField<CssValue>] = &[
+    style_field!(width),
+    style_field!(height),
+    style_field!(min_width),
+    style_field!(min_height),
+    style_field!(max_width),
+    style_field!(max_height),
+    style_field!(top),
+    style_field!(right),
+    style_field!(bottom),
+    style_field!(left),
+    style_field!(padding),
+    style_field!(margin),
+    style_field!(gap),
+    style_field!(border_radius),
+    style_field!(font_size),
+];
+
+fn single_length(value: &str) -> Option<Val> {
+    let value = value.trim();
+    (!value.is_empty() && !value.contains(char::is_whitespace)).then(|| parse_val(value))
+}
+
+fn parse_keyframe_offset(selector: &str) -> Option<f32> {
+    match selector.trim() {
+        "from" => Some(0.0),
+        "to" => Some(1.0),
+        other => {
+            let pct = other.strip_suffix('%')?.trim().parse::<f32>().ok()?;
+            Some((pct / 100.0).clamp(0.0, 1.0))
+        }
+    }
+}
+
+/// Keyframe timelines by name, looked up by the `animation` style prop.
+#[derive(Resource, Clone, Debug, Default)]
+pub struct ReactKeyframes {
+    keyframes: HashMap<String, Keyframes>,
+}
+
+impl ReactKeyframes {
+    /// Register (or replace) a timeline.
+    pub fn insert(&mut self, name: impl Into<String>, keyframes: Keyframes) {
+        self.keyframes.insert(name.into(), keyframes);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Keyframes> {
+        self.keyframes.get(name)
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<Keyframes> {
+        self.keyframes.remove(name)
+    }
+}
+
+/// CSS `animation-direction`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum AnimationDirection {
+    #[default]
+    Normal,
+    Reverse,
+    Alternate,
+    AlternateReverse,
+}
+
+/// CSS `animation-fill-mode`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum AnimationFillMode {
+    #[default]
+    None,
+    Forwards,
+    Backwards,
+    Both,
+}
+
+/// One entry of the `animation` shorthand.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnimationSpec {
+    pub name: String,
+    pub duration: f32,
+    pub delay: f32,
+    pub timing: TimingFunction,
+    /// `f32::INFINITY` for `infinite`.
+    pub iterations: f32,
+    pub direction: AnimationDirection,
+    pub fill_mode: AnimationFillMode,
+    pub paused: bool,
+}
+
+impl Default for AnimationSpec {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            duration: 0.0,
+            delay: 0.0,
+            // Unlike host transitions, animations follow the CSS default.
+            timing: TimingFunction::EASE,
+            iterations: 1.0,
+            direction: AnimationDirection::Normal,
+            fill_mode: AnimationFillMode::None,
+            paused: false,
+        }
+    }
+}
+
+/// Where an animation is after `elapsed` seconds.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AnimationPhase {
+    /// Still in the delay.
+    Before,
+    /// Running; keyframe progress `0..=1` after direction is applied.
+    Active(f32),
+    /// Finished; progress of the final frame.
+    After(f32),
+}
+
+impl AnimationSpec {
+    pub fn phase(&self, elapsed: f32) -> AnimationPhase {
+        let active = elapsed - self.delay;
+        if active < 0.0 {
+            return AnimationPhase::Before;
+        }
+        if self.duration <= 0.0 || active >= self.active_duration() {
+            // The last iteration ends at its end, or partway for fractional counts.
+            // Zero-length infinite runs end after their first iteration.
+            let (iteration, fraction) = if self.iterations <= 0.0 {
+                (0.0, 0.0)
+            } else if !self.iterations.is_finite() {
+                (0.0, 1.0)
+            } else {
+                let iteration = self.iterations.ceil() - 1.0;
+                (iteration, self.iterations - iteration)
+            };
+            return AnimationPhase::After(self.directed(iteration, fraction));
+        }
+        let overall = active / self.duration;
+        AnimationPhase::Active(self.directed(overall.floor(), overall.fract()))
+    }
+
+    /// Seconds from the end of the delay to the end of the last iteration;
+    /// `0` when nothing runs, infinite for endless runs.
+    pub fn active_duration(&self) -> f32 {
+        if self.duration <= 0.0 || self.iterations <= 0.0 {
+            0.0
+        } else {
+            self.duration * self.iterations
+        }
+    }
+
+    /// Progress to sample for `phase`, or `None` when the fill mode shows nothing.
+    pub fn sampled_progress(&self, phase: AnimationPhase) -> Option<f32> {
+        use AnimationFillMode::*;
+        match phase {
+            AnimationPhase::Before => {
+                matches!(self.fill_mode, Backwards | Both).then(|| self.directed(0.0, 0.0))
+            }
+            AnimationPhase::Active(progress) => Some(progress),
+            AnimationPhase::After(progress) => {
+                matches!(self.fill_mode, Forwards | Both).then_some(progress)
+            }
+        }
+    }
+
+    fn directed(&self, iteration: f32, progress: f32) -> f32 {
+        let odd = iteration as u64 % 2 == 1;
+        let reversed = match self.direction {
+            AnimationDirection::Normal => false,
+            AnimationDirection::Reverse => true,
+            AnimationDirection::Alternate => odd,
+            AnimationDirection::AlternateReverse => !odd,
+        };
+        if reversed { 1.0 - progress } else { progress }
+    }
+}
+
+/// Parse the CSS `animation` shorthand (comma-separated for several animations).
+///
+/// Like CSS, the first time is the duration and the second the delay; the
+/// first unrecognised identifier is the keyframes name.
+pub fn parse_animation(value: &str) -> Vec<AnimationSpec> {
+    let mut specs = Vec::new();
+    for part in split_css_list(value) {
+        let mut spec = AnimationSpec::default();
+        let mut times = 0;
+        let mut rest = part;
+        loop {
+            let (token, next) = next_css_token(rest);
+            if token.is_empty() {
+                break;
+            }
+            rest = next;
+            let lower = token.to_ascii_lowercase();
+            match lower.as_str() {
+                "infinite" => spec.iterations = f32::INFINITY,
+                "normal" => spec.direction = AnimationDirection::Normal,
+                "reverse" => spec.direction = AnimationDirection::Reverse,
+                "alternate" => spec.direction = AnimationDirection::Alternate,
+                "alternate-reverse" => spec.direction = AnimationDirection::AlternateReverse,
+                "none" if spec.name.is_empty() => {}
+                "forwards" => spec.fill_mode = AnimationFillMode::Forwards,
+                "backwards" => spec.fill_mode = AnimationFillMode::Backwards,
+                "both" => spec.fill_mode = AnimationFillMode::Both,
+                "running" => spec.paused = false,
+                "paused" => spec.paused = true,
+                _ if let Some(secs) = parse_time(&lower) => {
+                    match times {
+                        0 => spec.duration = secs,
+                        1 => spec.delay = secs,
+                        _ => log::warn!("Extra animation time '{}' ignored", token),
+                    }
+                    times += 1;
+                }
+                _ if lower.parse::<f32>().is_ok() => {
+                    spec.iterations = lower.parse::<f32>().unwrap_or(1.0).max(0.0);
+                }
+                _ => match parse_timing_function(&lower) {
+                    Some(timing) => spec.timing = timing,
+                    None if spec.name.is_empty() => spec.name = token.to_string(),
+                    None => log::warn!("Invalid animation token '{}'", token),
+                },
+            }
+        }
+        if !spec.name.is_empty() {
+            specs.push(spec);
+        }
+    }
+    specs
+}
+
+/// `200ms` / `1.5s`; bare numbers are iteration counts, not times.
+fn parse_time(token: &str) -> Option<f32> {
+    if let Some(ms) = token.strip_suffix("ms") {
+        return ms.parse::<f32>().ok().map(|ms| ms / 1000.0);
+    }
+    token.strip_suffix('s')?.parse::<f32>().ok()
+}
+
+/// Animations from `animation`, with `animationPlayState` applied per entry.
+pub fn style_animations(props: &StyleProps) -> Vec<AnimationSpec> {
+    let mut specs = props
+        .animation
+        .as_deref()
+        .map(parse_animation)
+        .unwrap_or_default();
+    if let Some(states) = props.animation_play_state.as_deref() {
+        let states: Vec<&str> = states.split(',').map(str::trim).collect();
+        for (i, spec) in specs.iter_mut().enumerate() {
+            // Shorter lists repeat, as in CSS.
+            spec.paused = states[i % states.len()].eq_ignore_ascii_case("paused");
+        }
+    }
+    specs
+}
+
+/// Step keyframe animations, fire `animationstart` / `animationend`, and store
+/// the sampled overlay for `apply_interaction_styles`. Runs just before it.
+pub fn advance_react_animations(
+    time: Res<Time>,
+    keyframes: Option<Res<ReactKeyframes>>,
+    event_queue: Option<Res<ReactEventQueue>>,
+    mut nodes: Query<(Entity, &ReactNode, &mut ReactStyleState)>,
+    parents: Query<&ChildOf>,
+    roots: Query<&ReactRoot>,
+) {
+    let dt = time.delta_secs();
+
+    for (entity, react_node, mut state) in &mut nodes {
+        if state.animations.is_empty() && state.anim.keyframes.is_empty() {
+            continue;
+        }
+        let state = &mut *state;
+        let runs = &mut state.anim.keyframes;
+
+        // A run restarts only when the name at its position changes, as in CSS.
+        runs.truncate(state.animations.len());
+        for (i, spec) in state.animations.iter().enumerate() {
+            match runs.get_mut(i) {
+                Some(run) if run.name == spec.name => {}
+                Some(run) => *run = KeyframeRun::new(&spec.name),
+                None => runs.push(KeyframeRun::new(&spec.name)),
+            }
+        }
+
+        let mut overlay: Option<StyleProps> = None;
+        let mut events = Vec::new();
+        for (spec, run) in state.animations.iter().zip(runs.iter_mut()) {
+            if !spec.paused {
+                run.elapsed += dt;
+            }
+            let phase = spec.phase(run.elapsed);
+            if !run.started && phase != AnimationPhase::Before {
+                run.started = true;
+                events.push(("animationstart", &spec.name, 0.0));
+            }
+            if !run.ended && matches!(phase, AnimationPhase::After(_)) {
+                run.ended = true;
+                events.push(("animationend", &spec.name, spec.active_duration()));
+            }
+
+            let Some(progress) = spec.sampled_progress(phase) else {
+                continue;
+            };
+            let Some(timeline) = keyframes.as_ref().and_then(|k| k.get(&spec.name)) else {
+                continue;
+            };
+            let sampled = timeline.sample(progress, spec.timing);
+            overlay = Some(match overlay {
+                Some(previous) => merge_style_props(&previous, &sampled),
+                None => sampled,
+            });
+        }
+        state.anim.keyframe_overlay = overlay;
+
+        if events.is_empty() {
+            continue;
+        }
+        let (Some(queue), Some(root_id)) =
+            (event_queue.as_ref(), find_root_id(entity, &parents, &roots))
+        else {
+            continue;
+        };
+        for (event_type, name, elapsed) in events {
+            queue.push_event(
+                root_id.clone(),
+                react_node.node_id,
+                event_type,
+                json!({ "animationName": name, "elapsedTime": elapsed }),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::react::style::parse_props;
+
+    const SPIN: &str =
+        r#"{"from":{"transform":"rotate(0deg)"},"to":{"transform":"rotate(360deg)"}}"#;
+
+    #[test]
+    fn test_parse_animation_shorthand() {
+        let specs = parse_animation(
+            "spin 1s linear infinite, shake 200ms ease-in-out 100ms 3 alternate both paused",
+        );
+        assert_eq!(specs.len(), 2);
+        assert_eq!(specs[0].name, "spin");
+        assert_eq!(specs[0].duration, 1.0);
+        assert_eq!(specs[0].timing, TimingFunction::Linear);
+        assert!(specs[0].iterations.is_infinite());
+
+        let shake = &specs[1];
+        assert!((shake.duration - 0.2).abs() < 1e-5);
+        assert!((shake.delay - 0.1).abs() < 1e-5);
+        assert_eq!(shake.iterations, 3.0);
+        assert_eq!(shake.direction, AnimationDirection::Alternate);
+        assert_eq!(shake.fill_mode, AnimationFillMode::Both);
+        assert!(shake.paused);
+
+        assert!(parse_animation("none").is_empty());
+    }
+
+    #[test]
+    fn test_keyframes_interpolate_each_property_between_its_frames() {
+        let keyframes = Keyframes::parse(
+            r##"{"0%, 100%":{"backgroundColor":"#000000","width":0},"50%":{"width":100,"display":"none"}}"##,
+        )
+        .unwrap();
+        assert_eq!(keyframes.frames().len(), 3);
+
+        let quarter = keyframes.sample(0.25, TimingFunction::Linear);
+        assert_eq!(quarter.width.as_ref().map(|w| w.0.as_str()), Some("50px"));
+        assert_eq!(quarter.display, None);
+        assert_eq!(
+            quarter.background_color.as_deref(),
+            Some("rgba(0, 0, 0, 1)")
+        );
+
+        let past_half = keyframes.sample(0.75, TimingFunction::Linear);
+        assert_eq!(past_half.width.as_ref().map(|w| w.0.as_str()), Some("50px"));
+        assert_eq!(past_half.display.as_deref(), Some("none"));
+
+        let spin = Keyframes::parse(SPIN).unwrap();
+        let half = style_transform(&spin.sample(0.5, TimingFunction::Linear)).unwrap();
+        assert!((half.rotation - std::f32::consts::PI).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_phase_applies_direction_iterations_and_fill() {
+        let spec = AnimationSpec {
+            name: "x".into(),
+            duration: 1.0,
+            delay: 0.5,
+            timing: TimingFunction::Linear,
+            iterations: 2.0,
+            direction: AnimationDirection::Alternate,
+            fill_mode: AnimationFillMode::Forwards,
+            paused: false,
+        };
+        assert_eq!(spec.phase(0.2), AnimationPhase::Before);
+        assert_eq!(spec.sampled_progress(AnimationPhase::Before), None);
+        assert_eq!(spec.phase(0.75), AnimationPhase::Active(0.25));
+        // Second iteration runs backwards.
+        assert_eq!(spec.phase(1.75), AnimationPhase::Active(0.75));
+        assert_eq!(spec.phase(3.0), AnimationPhase::After(0.0));
+        assert_eq!(spec.sampled_progress(spec.phase(3.0)), Some(0.0));
+
+        let once = AnimationSpec {
+            iterations: 1.5,
+            fill_mode: AnimationFillMode::None,
+            ..spec
+        };
+        assert_eq!(once.phase(3.0), AnimationPhase::After(0.5));
+        assert_eq!(once.sampled_progress(once.phase(3.0)), None);
+    }
+
+    #[test]
+    fn test_zero_duration_infinite_animation_ends_at_last_frame() {
+        let spec = parse_animation("pulse infinite forwards").remove(0);
+        assert_eq!(spec.duration, 0.0);
+        assert!(spec.iterations.is_infinite());
+        assert_eq!(spec.phase(0.0), AnimationPhase::After(1.0));
+        assert_eq!(spec.sampled_progress(spec.phase(0.0)), Some(1.0));
+        assert_eq!(spec.active_duration(), 0.0);
+
+        let reverse = AnimationSpec {
+            direction: AnimationDirection::Reverse,
+            ..spec
+        };
+        assert_eq!(reverse.phase(0.0), AnimationPhase::After(0.0));
+
+        // Endless runs with a length never end.
+        let endless = AnimationSpec {
+            duration: 1.0,
+            ..reverse
+        };
+        assert!(matches!(endless.phase(1e6), AnimationPhase::Active(_)));
+    }
+
+    #[test]
+    fn test_system_fires_start_and_end_and_clears_overlay() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<ReactKeyframes>()
+            .init_resource::<ReactEventQueue>()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )))
+            .add_systems(Update, advance_react_animations);
+        app.world_mut()
+            .resource_mut::<ReactKeyframes>()
+            .insert("spin", Keyframes::parse(SPIN).unwrap());
+
+        let root = app.world_mut().spawn(ReactRoot::new()).id();
+        let style = parse_props(r#"{"style":{"animation":"spin 250ms linear"}}"#)
+            .style
+            .unwrap();
+        let node = app
+            .world_mut()
+            .spawn((
+                ReactNode { node_id: 7 },
+                ReactStyleState::from_props(&style),
+            ))
+            .id();
+        app.world_mut().entity_mut(root).add_child(node);
+
+        app.update();
+        app.update();
+        let state = app.world().get::<ReactStyleState>(node).unwrap();
+        assert!(state.anim.keyframe_overlay.is_some());
+        let events = app.world().resource::<ReactEventQueue>().drain();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, "animationstart");
+        assert_eq!(events[0].node_id, 7);
+
+        for _ in 0..3 {
+            app.update();
+        }
+        let state = app.world().get::<ReactStyleState>(node).unwrap();
+        assert!(
+            state.anim.keyframe_overlay.is_none(),
+            "fill-mode none drops the overlay"
+        );
+        let events = app.world().resource::<ReactEventQueue>().drain();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, "animationend");
+        assert!(events[0].payload_json.contains(r#""animationName":"spin""#));
+
+        // A zero-length endless run fills forwards and ends with `elapsedTime: 0`.
+        let style = parse_props(r#"{"style":{"animation":"spin infinite forwards"}}"#)
+            .style
+            .unwrap();
+        app.world_mut()
+            .entity_mut(node)
+            .insert(ReactStyleState::from_props(&style));
+        app.update();
+        let state = app.world().get::<ReactStyleState>(node).unwrap();
+        assert!(state.anim.keyframe_overlay.is_some());
+        let events = app.world().resource::<ReactEventQueue>().drain();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[1].event_type, "animationend");
+        assert!(
+            events[1].payload_json.contains(r#""elapsedTime":0.0"#),
+            "{}",
+            events[1].payload_json
+        );
+    }
+}
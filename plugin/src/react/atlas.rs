@@ -0,0 +1,350 @@
+//! Texture atlases and sprite-sheet animation on `<image>`.
+//!
+//! `atlas={{ layout, index }}` sets [`ImageNode::texture_atlas`] from a
+//! `*.atlas.ron` layout loaded by [`ReactAtlasLayoutLoader`]. With `frames`
+//! and `fps` the host cycles the atlas index itself
+//! ([`advance_react_atlas_animations`]), so animated icons never re-render
+//! from JS.
+
+use bevy::asset::{AssetLoader, LoadContext, io::Reader};
+use bevy::prelude::*;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Loads a [`TextureAtlasLayout`] from RON (`*.atlas.ron`), either a uniform
+/// grid or explicit `(min, max)` pixel rects:
+///
+/// ```ron
+/// (tile_size: (32, 32), columns: 8, rows: 4, padding: (0, 0), offset: (0, 0))
+/// (size: (64, 32), textures: [((0, 0), (32, 32)), ((32, 0), (64, 32))])
+/// ```
+#[derive(Default)]
+pub struct ReactAtlasLayoutLoader;
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct AtlasLayoutFile {
+    tile_size: (u32, u32),
+    columns: u32,
+    rows: u32,
+    padding: (u32, u32),
+    offset: (u32, u32),
+    size: Option<(u32, u32)>,
+    textures: Vec<((u32, u32), (u32, u32))>,
+}
+
+impl AtlasLayoutFile {
+    fn into_layout(self) -> TextureAtlasLayout {
+        if self.tile_size != (0, 0) {
+            let uvec = |(x, y): (u32, u32)| UVec2::new(x, y);
+            return TextureAtlasLayout::from_grid(
+                uvec(self.tile_size),
+                self.columns.max(1),
+                self.rows.max(1),
+                Some(uvec(self.padding)),
+                Some(uvec(self.offset)),
+            );
+        }
+        let textures: Vec<URect> = self
+            .textures
+            .into_iter()
+            .map(|((x0, y0), (x1, y1))| URect::new(x0, y0, x1, y1))
+            .collect();
+        let size = match self.size {
+            Some((w, h)) => UVec2::new(w, h),
+            None => textures
+                .iter()
+                .fold(UVec2::ZERO, |size, rect| size.max(rect.max)),
+        };
+        TextureAtlasLayout { size, textures }
+    }
+}
+
+/// Parse `*.atlas.ron` source (see [`ReactAtlasLayoutLoader`]).
+pub fn parse_atlas_layout(source: &[u8]) -> Result<TextureAtlasLayout, ron::error::SpannedError> {
+    ron::de::from_bytes::<AtlasLayoutFile>(source).map(AtlasLayoutFile::into_layout)
+}
+
+impl AssetLoader for ReactAtlasLayoutLoader {
+    type Asset = TextureAtlasLayout;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        parse_atlas_layout(&bytes)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["atlas.ron"]
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum FramesProp {
+    Count(usize),
+    List(Vec<usize>),
+}
+
+#[derive(Deserialize)]
+struct AtlasProps {
+    layout: String,
+    #[serde(default)]
+    index: usize,
+    frames: Option<FramesProp>,
+    fps: Option<f32>,
+    #[serde(rename = "loop")]
+    looping: Option<bool>,
+}
+
+/// Parsed `atlas` prop of an `<image>`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReactImageAtlas {
+    /// [`TextureAtlasLayout`] asset path.
+    pub layout: String,
+    /// Frame shown when not animating.
+    pub index: usize,
+    /// Atlas indices to cycle; empty or a single frame means no animation.
+    pub frames: Vec<usize>,
+    pub fps: f32,
+    pub looping: bool,
+}
+
+impl ReactImageAtlas {
+    /// Frame animation for this atlas, when it has several frames and a positive `fps`.
+    pub fn animation(&self) -> Option<ReactAtlasAnimation> {
+        (self.frames.len() > 1 && self.fps > 0.0).then(|| ReactAtlasAnimation {
+            frames: self.frames.clone(),
+            fps: self.fps,
+            looping: self.looping,
+            elapsed: 0.0,
+        })
+    }
+}
+
+/// Parse the `atlas` prop from React props JSON.
+pub fn parse_image_atlas(props: &Value) -> Option<ReactImageAtlas> {
+    let props = AtlasProps::deserialize(props.get("atlas")?).ok()?;
+    let frames = match props.frames {
+        Some(FramesProp::Count(count)) => (props.index..props.index + count).collect(),
+        Some(FramesProp::List(frames)) => frames,
+        None => Vec::new(),
+    };
+    Some(ReactImageAtlas {
+        layout: props.layout,
+        index: frames.first().copied().unwrap_or(props.index),
+        frames,
+        fps: props.fps.unwrap_or(0.0),
+        looping: props.looping.unwrap_or(true),
+    })
+}
+
+/// Host-driven sprite-sheet playback of an image's atlas index.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct ReactAtlasAnimation {
+    frames: Vec<usize>,
+    fps: f32,
+    looping: bool,
+    elapsed: f32,
+}
+
+impl ReactAtlasAnimation {
+    /// Atlas index to show at the current time.
+    pub fn frame(&self) -> usize {
+        let step = (self.elapsed * self.fps) as usize;
+        let last = self.frames.len() - 1;
+        let i = if self.looping {
+            step % self.frames.len()
+        } else {
+            step.min(last)
+        };
+        self.frames[i]
+    }
+
+    /// Whether a non-looping animation has reached its last frame.
+    pub fn finished(&self) -> bool {
+        !self.looping && (self.elapsed * self.fps) as usize >= self.frames.len() - 1
+    }
+
+    fn same_settings(&self, other: &Self) -> bool {
+        self.frames == other.frames && self.fps == other.fps && self.looping == other.looping
+    }
+
+    fn advance(&mut self, dt: f32) {
+        let period = self.frames.len() as f32 / self.fps;
+        self.elapsed += dt;
+        if self.looping {
+            self.elapsed %= period;
+        } else {
+            self.elapsed = self.elapsed.min(period);
+        }
+    }
+}
+
+/// [`TextureAtlas`] for an image's `atlas` prop (loads the layout).
+pub(crate) fn image_texture_atlas(
+    atlas: &ReactImageAtlas,
+    asset_server: &AssetServer,
+) -> TextureAtlas {
+    TextureAtlas {
+        layout: asset_server.load(&atlas.layout),
+        index: atlas.index,
+    }
+}
+
+/// Insert or clear [`ReactAtlasAnimation`] on a prop update. Playback keeps
+/// its position while frames, `fps` and `loop` stay the same.
+pub(crate) fn sync_atlas_animation(commands: &mut Commands, entity: Entity, props: &Value) {
+    let animation = parse_image_atlas(props).and_then(|atlas| atlas.animation());
+    commands.queue(move |world: &mut World| {
+        let Ok(mut entity_mut) = world.get_entity_mut(entity) else {
+            return;
+        };
+        match animation {
+            Some(animation) => {
+                if entity_mut
+                    .get::<ReactAtlasAnimation>()
+                    .is_some_and(|current| current.same_settings(&animation))
+                {
+                    return;
+                }
+                entity_mut.insert(animation);
+            }
+            None => {
+                entity_mut.remove::<ReactAtlasAnimation>();
+            }
+        }
+    });
+}
+
+/// Step atlas animations and write the current frame into [`ImageNode`].
+pub fn advance_react_atlas_animations(
+    time: Res<Time>,
+    mut images: Query<(&mut ReactAtlasAnimation, &mut ImageNode)>,
+) {
+    let dt = time.delta_secs();
+    for (mut animation, mut image) in &mut images {
+        if !animation.finished() {
+            animation.advance(dt);
+        }
+        let frame = animation.frame();
+        // Only the index moves: skip change detection so `apply_react_opacity`
+        // does not take an already faded color as the image's new base.
+        if let Some(atlas) = image.bypass_change_detection().texture_atlas.as_mut()
+            && atlas.index != frame
+        {
+            atlas.index = frame;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_atlas_layout_grid_and_rects() {
+        let grid = parse_atlas_layout(b"(tile_size: (16, 16), columns: 4, rows: 2)").unwrap();
+        assert_eq!(grid.len(), 8);
+        assert_eq!(grid.size, UVec2::new(64, 32));
+        assert_eq!(grid.textures[5], URect::new(16, 16, 32, 32));
+
+        let rects =
+            parse_atlas_layout(b"(textures: [((0, 0), (32, 32)), ((32, 0), (48, 24))])").unwrap();
+        assert_eq!(rects.size, UVec2::new(48, 32));
+        assert_eq!(rects.textures[1], URect::new(32, 0, 48, 24));
+
+        assert!(parse_atlas_layout(b"(tile_size: 16)").is_err());
+    }
+
+    #[test]
+    fn test_parse_image_atlas_frames() {
+        let still =
+            parse_image_atlas(&json!({"atlas": {"layout": "ui/icons.atlas.ron", "index": 3}}))
+                .unwrap();
+        assert_eq!(still.index, 3);
+        assert!(still.animation().is_none());
+
+        let counted = parse_image_atlas(
+            &json!({"atlas": {"layout": "fx.atlas.ron", "index": 4, "frames": 3, "fps": 12}}),
+        )
+        .unwrap();
+        assert_eq!(counted.frames, vec![4, 5, 6]);
+        assert!(counted.looping && counted.animation().is_some());
+
+        let listed = parse_image_atlas(&json!({
+            "atlas": {"layout": "fx.atlas.ron", "frames": [2, 0, 1], "fps": 8, "loop": false}
+        }))
+        .unwrap();
+        assert_eq!(listed.index, 2);
+        assert!(!listed.looping);
+
+        assert!(parse_image_atlas(&json!({"atlas": {"index": 1}})).is_none());
+        assert!(parse_image_atlas(&json!({})).is_none());
+    }
+
+    #[test]
+    fn test_atlas_animation_advances_image_index() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )))
+            .add_systems(Update, advance_react_atlas_animations);
+        let atlas = ReactImageAtlas {
+            layout: "fx.atlas.ron".to_string(),
+            index: 4,
+            frames: vec![4, 5, 6],
+            fps: 10.0,
+            looping: true,
+        };
+        let image = ImageNode {
+            texture_atlas: Some(TextureAtlas {
+                layout: Handle::default(),
+                index: atlas.index,
+            }),
+            ..default()
+        };
+        let entity = app
+            .world_mut()
+            .spawn((image, atlas.animation().unwrap()))
+            .id();
+        let index = |app: &App| {
+            app.world()
+                .get::<ImageNode>(entity)
+                .and_then(|image| image.texture_atlas.as_ref())
+                .unwrap()
+                .index
+        };
+
+        // The first update has a zero delta.
+        app.update();
+        let mut seen = Vec::new();
+        for _ in 0..4 {
+            app.update();
+            seen.push(index(&app));
+        }
+        assert_eq!(seen, vec![5, 6, 4, 5]);
+
+        let mut once = atlas.animation().unwrap();
+        once.looping = false;
+        app.world_mut().entity_mut(entity).insert(once);
+        for _ in 0..5 {
+            app.update();
+        }
+        assert_eq!(index(&app), 6);
+    }
+}
@@ -0,0 +1,513 @@
+//! CSS length expressions — `calc()`, `min()`, `max()`, `clamp()` and the
+//! `rem` / `em` / `vmin` / `vmax` units.
+//!
+//! [`parse_css_length`] folds whatever Bevy can express into one [`Val`]
+//! (`calc(2 * 8px + 4px)` → `Val::Px(20.)`, `clamp(10vw, 50vw, 20vw)` → `Val::Vw(20.)`).
+//! Expressions mixing units (`calc(100% - 48px)`, `clamp(12px, 2vw, 20px)`)
+//! and anything in `rem` stay a [`CalcExpr`], resolved host-side each frame
+//! against the parent's size and [`ReactRootFontSize`] by
+//! [`resolve_calc_lengths`](crate::react::resolve_calc_lengths).
+
+use bevy::prelude::*;
+
+/// Root font size (px) that `rem` and `em` lengths are relative to. Default 16.
+///
+/// Read per app while resolving lengths, so changing it restyles existing nodes:
+/// `app.insert_resource(ReactRootFontSize(18.0))`.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct ReactRootFontSize(pub f32);
+
+impl Default for ReactRootFontSize {
+    fn default() -> Self {
+        Self(16.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalcUnit {
+    Px,
+    Percent,
+    Vw,
+    Vh,
+    VMin,
+    VMax,
+    /// Root font size; `em` parses to this too (layout has no font-size inheritance).
+    Rem,
+}
+
+impl CalcUnit {
+    const ALL: [CalcUnit; 7] = [
+        Self::Px,
+        Self::Percent,
+        Self::Vw,
+        Self::Vh,
+        Self::VMin,
+        Self::VMax,
+        Self::Rem,
+    ];
+
+    /// `None` for `rem`, which Bevy has no `Val` for.
+    fn to_val(self, n: f32) -> Option<Val> {
+        Some(match self {
+            Self::Px => Val::Px(n),
+            Self::Percent => Val::Percent(n),
+            Self::Vw => Val::Vw(n),
+            Self::Vh => Val::Vh(n),
+            Self::VMin => Val::VMin(n),
+            Self::VMax => Val::VMax(n),
+            Self::Rem => return None,
+        })
+    }
+}
+
+/// What a `%`, the viewport units and `rem` resolve against, in logical px.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CalcContext {
+    pub percent_base: f32,
+    pub viewport: Vec2,
+    pub root_font_size: f32,
+}
+
+impl CalcContext {
+    /// No containing block, default window and root font size: for values
+    /// resolved outside layout.
+    pub const FALLBACK: Self = Self {
+        percent_base: 0.0,
+        viewport: Vec2::new(1280.0, 720.0),
+        root_font_size: 16.0,
+    };
+}
+
+/// A parsed length expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CalcExpr {
+    /// Unitless number (a multiplier, or px at the top level).
+    Number(f32),
+    Length(f32, CalcUnit),
+    Add(Box<CalcExpr>, Box<CalcExpr>),
+    Sub(Box<CalcExpr>, Box<CalcExpr>),
+    Mul(Box<CalcExpr>, Box<CalcExpr>),
+    Div(Box<CalcExpr>, Box<CalcExpr>),
+    Min(Vec<CalcExpr>),
+    Max(Vec<CalcExpr>),
+    Clamp(Box<CalcExpr>, Box<CalcExpr>, Box<CalcExpr>),
+}
+
+/// A length as Bevy can take it, or an expression the host resolves per frame.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CssLength {
+    Val(Val),
+    Calc(CalcExpr),
+}
+
+/// Parse a length: plain units (`8px`, `50%`, `1.5rem`, `2vmin`, numbers as px)
+/// or a `calc()` / `min()` / `max()` / `clamp()` expression.
+pub fn parse_css_length(value: &str) -> Option<CssLength> {
+    let mut parser = Parser {
+        input: value.trim(),
+        pos: 0,
+    };
+    let expr = parser.sum()?;
+    parser.skip_whitespace();
+    if parser.pos != parser.input.len() || !expr.is_valid() {
+        return None;
+    }
+    Some(match expr.fold() {
+        Some(val) => CssLength::Val(val),
+        None => CssLength::Calc(expr),
+    })
+}
+
+impl CalcExpr {
+    /// Evaluate to logical px.
+    pub fn eval(&self, ctx: &CalcContext) -> f32 {
+        match self {
+            Self::Number(n) => *n,
+            Self::Length(n, unit) => {
+                let base = match unit {
+                    CalcUnit::Px => return *n,
+                    CalcUnit::Percent => ctx.percent_base,
+                    CalcUnit::Vw => ctx.viewport.x,
+                    CalcUnit::Vh => ctx.viewport.y,
+                    CalcUnit::VMin => ctx.viewport.min_element(),
+                    CalcUnit::VMax => ctx.viewport.max_element(),
+                    CalcUnit::Rem => return n * ctx.root_font_size,
+                };
+                n * base / 100.0
+            }
+            Self::Add(a, b) => a.eval(ctx) + b.eval(ctx),
+            Self::Sub(a, b) => a.eval(ctx) - b.eval(ctx),
+            Self::Mul(a, b) => a.eval(ctx) * b.eval(ctx),
+            Self::Div(a, b) => {
+                let divisor = b.eval(ctx);
+                if divisor == 0.0 {
+                    0.0
+                } else {
+                    a.eval(ctx) / divisor
+                }
+            }
+            Self::Min(args) => args
+                .iter()
+                .map(|a| a.eval(ctx))
+                .fold(f32::INFINITY, f32::min),
+            Self::Max(args) => args
+                .iter()
+                .map(|a| a.eval(ctx))
+                .fold(f32::NEG_INFINITY, f32::max),
+            // CSS: the minimum wins over the maximum when they cross.
+            Self::Clamp(min, value, max) => value.eval(ctx).min(max.eval(ctx)).max(min.eval(ctx)),
+        }
+    }
+
+    /// Only px and `rem`: no containing block or viewport needed.
+    pub(crate) fn is_absolute(&self) -> bool {
+        let mut absolute = true;
+        self.visit_units(&mut |unit| absolute &= matches!(unit, CalcUnit::Px | CalcUnit::Rem));
+        absolute
+    }
+
+    fn is_number(&self) -> bool {
+        match self {
+            Self::Number(_) => true,
+            Self::Length(..) => false,
+            Self::Add(a, _) | Self::Sub(a, _) | Self::Div(a, _) => a.is_number(),
+            Self::Mul(a, b) => a.is_number() && b.is_number(),
+            Self::Min(args) | Self::Max(args) => args.first().is_some_and(Self::is_number),
+            Self::Clamp(_, value, _) => value.is_number(),
+        }
+    }
+
+    /// Type check: no `px * px`, no dividing by a length, no number + length.
+    fn is_valid(&self) -> bool {
+        match self {
+            Self::Number(_) | Self::Length(..) => true,
+            Self::Add(a, b) | Self::Sub(a, b) => {
+                a.is_valid() && b.is_valid() && a.is_number() == b.is_number()
+            }
+            Self::Mul(a, b) => a.is_valid() && b.is_valid() && (a.is_number() || b.is_number()),
+            Self::Div(a, b) => a.is_valid() && b.is_valid() && b.is_number(),
+            Self::Min(args) | Self::Max(args) => {
+                let kind = args.first().map(Self::is_number);
+                !args.is_empty()
+                    && args
+                        .iter()
+                        .all(|a| a.is_valid() && Some(a.is_number()) == kind)
+            }
+            Self::Clamp(min, value, max) => {
+                [min, value, max].iter().all(|a| a.is_valid())
+                    && min.is_number() == value.is_number()
+                    && max.is_number() == value.is_number()
+            }
+        }
+    }
+
+    /// Fold into one [`Val`] when the result has a single unit.
+    fn fold(&self) -> Option<Val> {
+        let terms = self.terms()?;
+        let mut used = CalcUnit::ALL
+            .into_iter()
+            .zip(terms.units)
+            .filter(|(_, n)| *n != 0.0);
+        match (used.next(), used.next()) {
+            (None, _) => Some(Val::Px(terms.number)),
+            (Some((unit, n)), None) if terms.number == 0.0 || unit == CalcUnit::Px => {
+                unit.to_val(n + terms.number)
+            }
+            _ => None,
+        }
+    }
+
+    /// Linear form, when the expression has one (no `min()` / `max()` across units).
+    fn terms(&self) -> Option<Terms> {
+        Some(match self {
+            Self::Number(n) => Terms {
+                number: *n,
+                ..default()
+            },
+            Self::Length(n, unit) => {
+                let mut terms = Terms::default();
+                terms.units[*unit as usize] = *n;
+                terms
+            }
+            Self::Add(a, b) => a.terms()?.add(&b.terms()?, 1.0),
+            Self::Sub(a, b) => a.terms()?.add(&b.terms()?, -1.0),
+            Self::Mul(a, b) if a.is_number() => b.terms()?.scale(a.terms()?.number),
+            Self::Mul(a, b) => a.terms()?.scale(b.terms()?.number),
+            Self::Div(a, b) => {
+                let divisor = b.terms()?.number;
+                a.terms()?
+                    .scale(if divisor == 0.0 { 0.0 } else { divisor.recip() })
+            }
+            Self::Min(_) | Self::Max(_) | Self::Clamp(..) => {
+                // Comparable only when every argument has the same single unit.
+                let ctx = CalcContext {
+                    percent_base: 100.0,
+                    viewport: Vec2::splat(100.0),
+                    root_font_size: 1.0,
+                };
+                let unit = self.single_unit()?;
+                let mut terms = Terms::default();
+                match unit {
+                    Some(unit) => terms.units[unit as usize] = self.eval(&ctx),
+                    None => terms.number = self.eval(&ctx),
+                }
+                terms
+            }
+        })
+    }
+
+    /// `Some(None)` for plain numbers, `Some(Some(unit))` when every length
+    /// shares one unit, `None` when units are mixed.
+    fn single_unit(&self) -> Option<Option<CalcUnit>> {
+        let mut units = Vec::new();
+        self.visit_units(&mut |unit| units.push(unit));
+        match units.split_first() {
+            None => Some(None),
+            Some((first, rest)) => rest.iter().all(|u| u == first).then_some(Some(*first)),
+        }
+    }
+
+    fn visit_units(&self, f: &mut dyn FnMut(CalcUnit)) {
+        match self {
+            Self::Number(_) => {}
+            Self::Length(_, unit) => f(*unit),
+            Self::Add(a, b) | Self::Sub(a, b) | Self::Mul(a, b) | Self::Div(a, b) => {
+                a.visit_units(f);
+                b.visit_units(f);
+            }
+            Self::Min(args) | Self::Max(args) => args.iter().for_each(|a| a.visit_units(f)),
+            Self::Clamp(min, value, max) => {
+                min.visit_units(f);
+                value.visit_units(f);
+                max.visit_units(f);
+            }
+        }
+    }
+}
+
+/// `number + Σ unitsᵢ`, indexed like [`CalcUnit::ALL`].
+#[derive(Debug, Clone, Copy, Default)]
+struct Terms {
+    number: f32,
+    units: [f32; 7],
+}
+
+impl Terms {
+    fn add(mut self, other: &Terms, sign: f32) -> Terms {
+        self.number += other.number * sign;
+        for (a, b) in self.units.iter_mut().zip(other.units) {
+            *a += b * sign;
+        }
+        self
+    }
+
+    fn scale(mut self, factor: f32) -> Terms {
+        self.number *= factor;
+        self.units.iter_mut().for_each(|n| *n *= factor);
+        self
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn rest(&self) -> &str {
+        &self.input[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn sum(&mut self) -> Option<CalcExpr> {
+        let mut expr = self.product()?;
+        loop {
+            if self.eat('+') {
+                expr = CalcExpr::Add(Box::new(expr), Box::new(self.product()?));
+            } else if self.eat('-') {
+                expr = CalcExpr::Sub(Box::new(expr), Box::new(self.product()?));
+            } else {
+                return Some(expr);
+            }
+        }
+    }
+
+    fn product(&mut self) -> Option<CalcExpr> {
+        let mut expr = self.factor()?;
+        loop {
+            if self.eat('*') {
+                expr = CalcExpr::Mul(Box::new(expr), Box::new(self.factor()?));
+            } else if self.eat('/') {
+                expr = CalcExpr::Div(Box::new(expr), Box::new(self.factor()?));
+            } else {
+                return Some(expr);
+            }
+        }
+    }
+
+    fn factor(&mut self) -> Option<CalcExpr> {
+        self.skip_whitespace();
+        if self.eat('(') {
+            let expr = self.sum()?;
+            return self.eat(')').then_some(expr);
+        }
+        let name_len = self
+            .rest()
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(self.rest().len());
+        if name_len > 0 && self.rest()[name_len..].starts_with('(') {
+            let name = self.rest()[..name_len].to_ascii_lowercase();
+            self.pos += name_len + 1;
+            return self.function(&name);
+        }
+        self.dimension()
+    }
+
+    fn function(&mut self, name: &str) -> Option<CalcExpr> {
+        let mut args = vec![self.sum()?];
+        while self.eat(',') {
+            args.push(self.sum()?);
+        }
+        if !self.eat(')') {
+            return None;
+        }
+        match (name, args.len()) {
+            ("calc", 1) => args.pop(),
+            ("min", _) => Some(CalcExpr::Min(args)),
+            ("max", _) => Some(CalcExpr::Max(args)),
+            ("clamp", 3) => {
+                let max = args.pop()?;
+                let value = args.pop()?;
+                let min = args.pop()?;
+                Some(CalcExpr::Clamp(
+                    Box::new(min),
+                    Box::new(value),
+                    Box::new(max),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// `-1.5rem`, `50%`, `8px`, `2`.
+    fn dimension(&mut self) -> Option<CalcExpr> {
+        let rest = self.rest();
+        let number_len = rest
+            .char_indices()
+            .find(|&(i, c)| !(c.is_ascii_digit() || c == '.' || (i == 0 && (c == '-' || c == '+'))))
+            .map_or(rest.len(), |(i, _)| i);
+        let n: f32 = rest[..number_len].parse().ok()?;
+        let unit_len = rest[number_len..]
+            .find(|c: char| !(c.is_ascii_alphabetic() || c == '%'))
+            .unwrap_or(rest.len() - number_len);
+        let unit = rest[number_len..number_len + unit_len].to_ascii_lowercase();
+        self.pos += number_len + unit_len;
+
+        let (n, unit) = match unit.as_str() {
+            "" => return Some(CalcExpr::Number(n)),
+            "px" => (n, CalcUnit::Px),
+            "%" => (n, CalcUnit::Percent),
+            "vw" => (n, CalcUnit::Vw),
+            "vh" => (n, CalcUnit::Vh),
+            "vmin" => (n, CalcUnit::VMin),
+            "vmax" => (n, CalcUnit::VMax),
+            // No font-size inheritance in layout, so `em` is root-relative too.
+            "rem" | "em" => (n, CalcUnit::Rem),
+            _ => return None,
+        };
+        Some(CalcExpr::Length(n, unit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn val(value: &str) -> Val {
+        match parse_css_length(value) {
+            Some(CssLength::Val(val)) => val,
+            other => panic!("'{value}' did not fold: {other:?}"),
+        }
+    }
+
+    fn calc(value: &str) -> CalcExpr {
+        match parse_css_length(value) {
+            Some(CssLength::Calc(expr)) => expr,
+            other => panic!("'{value}' should stay an expression: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_single_unit_expressions_fold_to_val() {
+        assert_eq!(val("10vmin"), Val::VMin(10.0));
+        assert_eq!(val("5VMAX"), Val::VMax(5.0));
+        assert_eq!(val("calc(2 * 8px + 4px)"), Val::Px(20.0));
+        assert_eq!(val("calc(100% / 4)"), Val::Percent(25.0));
+        assert_eq!(val("calc(50% - (10% * 2))"), Val::Percent(30.0));
+        assert_eq!(val("calc(1rem - 1rem + 8px)"), Val::Px(8.0));
+        assert_eq!(val("clamp(10vw, 50vw, 20vw)"), Val::Vw(20.0));
+        assert_eq!(val("calc(100% - 0px)"), Val::Percent(100.0));
+    }
+
+    #[test]
+    fn test_mixed_units_resolve_against_context() {
+        let ctx = CalcContext {
+            percent_base: 400.0,
+            viewport: Vec2::new(1000.0, 500.0),
+            root_font_size: 16.0,
+        };
+        assert_eq!(calc("calc(100% - 48px)").eval(&ctx), 352.0);
+        assert_eq!(calc("clamp(12px, 2vw, 16px)").eval(&ctx), 16.0);
+        assert_eq!(calc("clamp(12px, 0.5vw, 16px)").eval(&ctx), 12.0);
+        assert_eq!(calc("max(50%, 10vh + 2rem)").eval(&ctx), 200.0);
+        assert_eq!(calc("min(100%, 90vmin)").eval(&ctx), 400.0);
+        let column = calc("calc((100% - 2 * 16px) / 3)").eval(&ctx);
+        assert!((column - 122.67).abs() < 0.01, "{column}");
+    }
+
+    #[test]
+    fn test_rem_resolves_against_root_font_size() {
+        let ctx = |root_font_size| CalcContext {
+            root_font_size,
+            ..CalcContext::FALLBACK
+        };
+        assert_eq!(calc("1.5rem").eval(&ctx(16.0)), 24.0);
+        assert_eq!(calc("1.5rem").eval(&ctx(20.0)), 30.0);
+        assert_eq!(calc("2EM"), calc("2rem"), "em is root-relative");
+        assert_eq!(calc("calc(1rem + 8px)").eval(&ctx(18.0)), 26.0);
+        assert_eq!(calc("min(10px, 2rem)").eval(&ctx(4.0)), 8.0);
+        assert_eq!(calc("min(1rem, 3rem)").eval(&ctx(10.0)), 10.0);
+        assert_eq!(calc("clamp(1rem, 2rem, 3rem)").eval(&ctx(10.0)), 20.0);
+    }
+
+    #[test]
+    fn test_invalid_expressions_are_rejected() {
+        for value in [
+            "calc(10px * 2px)",
+            "calc(10px / 2px)",
+            "calc(10px + 2)",
+            "calc(10px",
+            "clamp(1px, 2px)",
+            "min(1px, 2)",
+            "foo(1px)",
+            "10pt",
+            "calc(1px) 2px",
+        ] {
+            assert!(parse_css_length(value).is_none(), "{value}");
+        }
+    }
+}
@@ -0,0 +1,319 @@
+//! `<viewport camera>` host element: a live camera view inside the React tree.
+//!
+//! The node owns an [`Image`] render target sized to its laid-out content box
+//! in physical pixels. [`update_react_camera_viewports`] creates or resizes it
+//! after UI layout, points the referenced [`Camera`] at it, and shows it via
+//! [`ImageNode`]. The camera's own target and `is_active` are restored when
+//! the node lets go of it (another `camera`, or the node despawning).
+
+use bevy::camera::{ImageRenderTarget, RenderTarget};
+use bevy::math::FloatOrd;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureFormat};
+use serde_json::Value;
+
+use crate::react::anchor::entity_from_prop;
+
+/// Largest render target edge in physical pixels.
+const MAX_VIEWPORT_SIZE: u32 = 8192;
+
+/// Camera state to restore when a viewport releases it.
+#[derive(Debug, Clone)]
+struct BoundCamera {
+    entity: Entity,
+    target: RenderTarget,
+    is_active: bool,
+}
+
+/// A `<viewport>` node: the camera it shows and its render target.
+#[derive(Component, Debug, Clone, Default)]
+pub struct ReactCameraViewport {
+    camera: Option<Entity>,
+    image: Option<Handle<Image>>,
+    size: UVec2,
+    bound: Option<BoundCamera>,
+}
+
+impl ReactCameraViewport {
+    pub fn new(camera: Option<Entity>) -> Self {
+        Self {
+            camera,
+            ..default()
+        }
+    }
+
+    /// Camera referenced by the `camera` prop.
+    pub fn camera(&self) -> Option<Entity> {
+        self.camera
+    }
+
+    /// Render target image, once the node has been laid out.
+    pub fn image(&self) -> Option<&Handle<Image>> {
+        self.image.as_ref()
+    }
+
+    /// Render target size in physical pixels.
+    pub fn size(&self) -> UVec2 {
+        self.size
+    }
+}
+
+/// Parse the `camera` prop (`Entity::to_bits` or `{ bits }`).
+pub fn parse_viewport_camera(props: &Value) -> Option<Entity> {
+    entity_from_prop(props.get("camera")?)
+}
+
+/// Point an existing viewport at a new `camera` on prop updates. Props without
+/// a `camera` key are left alone (React sends `null` when it is cleared).
+pub(crate) fn sync_camera_viewport(commands: &mut Commands, entity: Entity, props: &Value) {
+    if props.get("camera").is_none() {
+        return;
+    }
+    let camera = parse_viewport_camera(props);
+    commands.queue(move |world: &mut World| {
+        if let Some(mut viewport) = world.get_mut::<ReactCameraViewport>(entity)
+            && viewport.camera != camera
+        {
+            viewport.camera = camera;
+        }
+    });
+}
+
+fn release_camera(bound: BoundCamera, cameras: &mut Query<&mut Camera>) {
+    if let Ok(mut camera) = cameras.get_mut(bound.entity) {
+        camera.target = bound.target;
+        camera.is_active = bound.is_active;
+    }
+}
+
+/// Size each viewport's render target to its node and bind it to the camera.
+/// Runs after `UiSystems::Layout`.
+pub fn update_react_camera_viewports(
+    mut images: ResMut<Assets<Image>>,
+    mut viewports: Query<(&mut ReactCameraViewport, &mut ImageNode, &ComputedNode)>,
+    mut cameras: Query<&mut Camera>,
+) {
+    for (mut viewport, mut image_node, computed) in &mut viewports {
+        if viewport
+            .bound
+            .as_ref()
+            .is_some_and(|bound| Some(bound.entity) != viewport.camera)
+            && let Some(bound) = viewport.bound.take()
+        {
+            release_camera(bound, &mut cameras);
+        }
+        let Some(camera_entity) = viewport.camera else {
+            continue;
+        };
+
+        let inset = computed.content_inset();
+        let content = computed.size - Vec2::new(inset.left + inset.right, inset.top + inset.bottom);
+        let size = content
+            .round()
+            .max(Vec2::ZERO)
+            .as_uvec2()
+            .min(UVec2::splat(MAX_VIEWPORT_SIZE));
+        if size.x == 0 || size.y == 0 {
+            continue;
+        }
+
+        let handle = match viewport.image.clone() {
+            Some(handle) => {
+                if viewport.size != size
+                    && let Some(image) = images.get_mut(&handle)
+                {
+                    image.resize(Extent3d {
+                        width: size.x,
+                        height: size.y,
+                        depth_or_array_layers: 1,
+                    });
+                }
+                handle
+            }
+            None => {
+                let image =
+                    Image::new_target_texture(size.x, size.y, TextureFormat::Bgra8UnormSrgb);
+                let handle = images.add(image);
+                viewport.image = Some(handle.clone());
+                handle
+            }
+        };
+        if viewport.size != size {
+            viewport.size = size;
+        }
+        if image_node.image != handle {
+            image_node.image = handle.clone();
+        }
+
+        let Ok(mut camera) = cameras.get_mut(camera_entity) else {
+            continue;
+        };
+        if viewport.bound.is_none() {
+            viewport.bound = Some(BoundCamera {
+                entity: camera_entity,
+                target: camera.target.clone(),
+                is_active: camera.is_active,
+            });
+        }
+        let scale_factor = FloatOrd(computed.inverse_scale_factor.recip());
+        let targets_image = matches!(
+            &camera.target,
+            RenderTarget::Image(target) if target.handle == handle && target.scale_factor == scale_factor
+        );
+        if !targets_image {
+            camera.target = RenderTarget::Image(ImageRenderTarget {
+                handle,
+                scale_factor,
+            });
+        }
+        if !camera.is_active {
+            camera.is_active = true;
+        }
+    }
+}
+
+/// Observer: hand the camera back when a viewport goes away.
+pub(crate) fn on_react_camera_viewport_removed(
+    remove: On<Remove, ReactCameraViewport>,
+    viewports: Query<&ReactCameraViewport>,
+    mut cameras: Query<&mut Camera>,
+) {
+    if let Ok(viewport) = viewports.get(remove.entity)
+        && let Some(bound) = viewport.bound.clone()
+    {
+        release_camera(bound, &mut cameras);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::asset::AssetPlugin;
+    use serde_json::json;
+
+    use super::*;
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Image>()
+            .add_observer(on_react_camera_viewport_removed)
+            .add_systems(Update, update_react_camera_viewports);
+        app
+    }
+
+    fn laid_out(size: Vec2) -> ComputedNode {
+        ComputedNode {
+            size,
+            inverse_scale_factor: 0.5,
+            ..default()
+        }
+    }
+
+    #[test]
+    fn test_parse_viewport_camera() {
+        let camera = Entity::from_raw_u32(7).unwrap();
+        let bits = camera.to_bits();
+        assert_eq!(
+            parse_viewport_camera(&json!({"camera": bits})),
+            Some(camera)
+        );
+        assert_eq!(
+            parse_viewport_camera(&json!({"camera": {"bits": bits}})),
+            Some(camera)
+        );
+        assert!(parse_viewport_camera(&json!({"camera": null})).is_none());
+    }
+
+    #[test]
+    fn test_viewport_targets_camera_and_follows_size() {
+        let mut app = setup_app();
+        let camera = app
+            .world_mut()
+            .spawn(Camera {
+                is_active: false,
+                ..default()
+            })
+            .id();
+        let node = app
+            .world_mut()
+            .spawn((
+                ReactCameraViewport::new(Some(camera)),
+                ImageNode::default(),
+                laid_out(Vec2::new(200.0, 100.0)),
+            ))
+            .id();
+
+        app.update();
+        let viewport = app.world().get::<ReactCameraViewport>(node).unwrap();
+        let handle = viewport.image().unwrap().clone();
+        assert_eq!(viewport.size(), UVec2::new(200, 100));
+        assert_eq!(app.world().get::<ImageNode>(node).unwrap().image, handle);
+        let images = app.world().resource::<Assets<Image>>();
+        assert_eq!(images.get(&handle).unwrap().size(), UVec2::new(200, 100));
+        let cam = app.world().get::<Camera>(camera).unwrap();
+        assert!(cam.is_active);
+        match &cam.target {
+            RenderTarget::Image(target) => {
+                assert_eq!(target.handle, handle);
+                assert_eq!(target.scale_factor, FloatOrd(2.0));
+            }
+            other => panic!("unexpected target {other:?}"),
+        }
+
+        app.world_mut().get_mut::<ComputedNode>(node).unwrap().size = Vec2::new(320.0, 240.0);
+        app.update();
+        let viewport = app.world().get::<ReactCameraViewport>(node).unwrap();
+        assert_eq!(viewport.image(), Some(&handle));
+        let images = app.world().resource::<Assets<Image>>();
+        assert_eq!(images.get(&handle).unwrap().size(), UVec2::new(320, 240));
+
+        // Despawning restores the camera and frees the target image.
+        let image_id = handle.id();
+        drop(handle);
+        app.world_mut().entity_mut(node).despawn();
+        app.update();
+        let cam = app.world().get::<Camera>(camera).unwrap();
+        assert!(!cam.is_active);
+        assert!(matches!(cam.target, RenderTarget::Window(_)));
+        assert!(
+            app.world()
+                .resource::<Assets<Image>>()
+                .get(image_id)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_viewport_releases_previous_camera() {
+        let mut app = setup_app();
+        let first = app.world_mut().spawn(Camera::default()).id();
+        let second = app.world_mut().spawn(Camera::default()).id();
+        let node = app
+            .world_mut()
+            .spawn((
+                ReactCameraViewport::new(Some(first)),
+                ImageNode::default(),
+                laid_out(Vec2::new(64.0, 64.0)),
+            ))
+            .id();
+        app.update();
+        assert!(matches!(
+            app.world().get::<Camera>(first).unwrap().target,
+            RenderTarget::Image(_)
+        ));
+
+        app.world_mut()
+            .get_mut::<ReactCameraViewport>(node)
+            .unwrap()
+            .camera = Some(second);
+        app.update();
+        assert!(matches!(
+            app.world().get::<Camera>(first).unwrap().target,
+            RenderTarget::Window(_)
+        ));
+        assert!(matches!(
+            app.world().get::<Camera>(second).unwrap().target,
+            RenderTarget::Image(_)
+        ));
+    }
+}
@@ -48,6 +48,19 @@ pub enum ReactClientProto {
     ClearContainer { root_id: String },
     /// Signal that a batch of operations is complete
     Complete,
+    /// React finished a commit for `root_id`; ops since the previous commit belong to it
+    Commit { root_id: String },
+    /// The root's module imported and its export's `render` was called
+    RootMounted { root_id: String },
+    /// Importing or rendering the root's export threw
+    RootFailed { root_id: String, error: String },
+    /// Register keyframes for the `animation` style prop (JSON object by offset)
+    RegisterKeyframes {
+        name: String,
+        keyframes_json: String,
+    },
+    /// Register (or replace) a named style class referenced by `className`
+    RegisterStyle { name: String, style_json: String },
 }
 
 /// Thread-safe receiver wrapper for the Bevy system
@@ -207,6 +220,35 @@ impl ReactClient {
         self.send(ReactClientProto::Complete);
     }
 
+    /// Signal the end of a React commit for a root
+    pub fn commit(&self, root_id: String) {
+        log::trace!("ReactClient::commit root={}", root_id);
+        self.send(ReactClientProto::Commit { root_id });
+    }
+
+    /// Report that a root's export rendered
+    pub fn root_mounted(&self, root_id: String) {
+        self.send(ReactClientProto::RootMounted { root_id });
+    }
+
+    /// Report that a root failed to import or render
+    pub fn root_failed(&self, root_id: String, error: String) {
+        self.send(ReactClientProto::RootFailed { root_id, error });
+    }
+
+    /// Register (or replace) a named keyframes timeline
+    pub fn register_keyframes(&self, name: String, keyframes_json: String) {
+        self.send(ReactClientProto::RegisterKeyframes {
+            name,
+            keyframes_json,
+        });
+    }
+
+    /// Register (or replace) a style class
+    pub fn register_style(&self, name: String, style_json: String) {
+        self.send(ReactClientProto::RegisterStyle { name, style_json });
+    }
+
     /// Decode a BRRP binary batch and enqueue the resulting RPC messages.
     ///
     /// Used by the `binary_ops` feature path (`__react_commit_ops`). Per-op
@@ -215,7 +257,17 @@ impl ReactClient {
     /// Advances [`NODE_ID_COUNTER`] past any create ids so a later enum-path
     /// allocation cannot collide with JS-allocated binary ids.
     pub fn commit_binary_ops(&self, bytes: &[u8]) -> Result<(), crate::react::proto::DecodeError> {
-        let msgs = crate::react::proto::decode_protos(bytes)?;
+        let (root_id, ops) = crate::react::proto::decode_batch(bytes)?;
+        // Frame-level commits are root-scoped here so the host can attribute them.
+        let msgs: Vec<ReactClientProto> = ops
+            .into_iter()
+            .map(|op| match op {
+                crate::react::proto::BinaryOp::Commit => ReactClientProto::Commit {
+                    root_id: root_id.clone(),
+                },
+                op => op.into_proto(&root_id),
+            })
+            .collect();
         for msg in &msgs {
             if let Some(id) = proto_node_id(msg) {
                 bump_node_id_counter_to_at_least(id);
@@ -102,6 +102,13 @@ impl ReactEventQueue {
             .unwrap_or(true)
     }
 
+    /// Keep only events for which `keep` returns `true`.
+    pub fn retain(&self, mut keep: impl FnMut(&ReactEvent) -> bool) {
+        if let Ok(mut q) = self.inner.lock() {
+            q.events.retain(|event| keep(event));
+        }
+    }
+
     pub fn drain(&self) -> Vec<ReactEvent> {
         self.inner
             .lock()
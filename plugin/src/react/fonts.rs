@@ -0,0 +1,322 @@
+//! Font families for `fontFamily` / `fontWeight` / `fontStyle`.
+//!
+//! Games register families in [`ReactFontRegistry`], one face per weight and
+//! style. A `fontFamily` list (`"Inter, Noto Sans, sans-serif"`) picks the
+//! first family with a face, using CSS font matching for the weight and style,
+//! and follows each family's fallback chain. Unregistered names that look like
+//! asset paths load directly; anything unresolved falls back to
+//! [`ReactRootFont`](crate::react::ReactRootFont) /
+//! [`ReactDefaultFont`](crate::react::ReactDefaultFont).
+//!
+//! ```ignore
+//! app.insert_resource(ReactFontRegistry::default().with_family(
+//!     "Inter",
+//!     ReactFontFamily::new()
+//!         .regular("fonts/Inter-Regular.ttf")
+//!         .bold("fonts/Inter-Bold.ttf")
+//!         .italic("fonts/Inter-Italic.ttf")
+//!         .fallback("Noto Sans"),
+//! ));
+//! ```
+
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+use crate::react::style::{StyleProps, style_font_families, style_font_style, style_font_weight};
+
+/// CSS `font-style`; `oblique` is treated as `italic`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum FontStyle {
+    #[default]
+    Normal,
+    Italic,
+}
+
+/// Where a face comes from: an asset path until [`load_react_fonts`] runs.
+#[derive(Debug, Clone)]
+pub enum FontSource {
+    Path(String),
+    Handle(Handle<Font>),
+}
+
+impl From<&str> for FontSource {
+    fn from(path: &str) -> Self {
+        Self::Path(path.to_string())
+    }
+}
+
+impl From<String> for FontSource {
+    fn from(path: String) -> Self {
+        Self::Path(path)
+    }
+}
+
+impl From<Handle<Font>> for FontSource {
+    fn from(handle: Handle<Font>) -> Self {
+        Self::Handle(handle)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FontFace {
+    weight: u16,
+    style: FontStyle,
+    source: FontSource,
+}
+
+/// One family's faces and the families tried when it has none.
+#[derive(Debug, Clone, Default)]
+pub struct ReactFontFamily {
+    faces: Vec<FontFace>,
+    fallbacks: Vec<String>,
+}
+
+impl ReactFontFamily {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add (or replace) the face for `weight` (1–1000) and `style`.
+    pub fn face(mut self, weight: u16, style: FontStyle, font: impl Into<FontSource>) -> Self {
+        self.faces
+            .retain(|face| face.weight != weight || face.style != style);
+        self.faces.push(FontFace {
+            weight,
+            style,
+            source: font.into(),
+        });
+        self
+    }
+
+    pub fn regular(self, font: impl Into<FontSource>) -> Self {
+        self.face(400, FontStyle::Normal, font)
+    }
+
+    pub fn bold(self, font: impl Into<FontSource>) -> Self {
+        self.face(700, FontStyle::Normal, font)
+    }
+
+    pub fn italic(self, font: impl Into<FontSource>) -> Self {
+        self.face(400, FontStyle::Italic, font)
+    }
+
+    pub fn bold_italic(self, font: impl Into<FontSource>) -> Self {
+        self.face(700, FontStyle::Italic, font)
+    }
+
+    /// Family tried next when this one cannot be resolved.
+    pub fn fallback(mut self, family: impl Into<String>) -> Self {
+        self.fallbacks.push(family.into());
+        self
+    }
+
+    /// Closest face by CSS font matching: style first, then weight.
+    fn matching_face(&self, weight: u16, style: FontStyle) -> Option<&FontFace> {
+        let styled: Vec<&FontFace> = self.faces.iter().filter(|f| f.style == style).collect();
+        let candidates = if styled.is_empty() {
+            self.faces.iter().collect()
+        } else {
+            styled
+        };
+        candidates
+            .into_iter()
+            .min_by_key(|face| weight_distance(weight, face.weight))
+    }
+}
+
+/// Preference order of `candidate` for a requested weight (CSS Fonts §5.2):
+/// at 400–500 try heavier up to 500, then lighter, then heavier; below 400
+/// lighter first; above 500 heavier first.
+fn weight_distance(target: u16, candidate: u16) -> (u8, u16) {
+    let up = candidate.saturating_sub(target);
+    let down = target.saturating_sub(candidate);
+    if candidate == target {
+        (0, 0)
+    } else if (400..=500).contains(&target) {
+        if candidate > target && candidate <= 500 {
+            (1, up)
+        } else if candidate < target {
+            (2, down)
+        } else {
+            (3, up)
+        }
+    } else if target < 400 {
+        if candidate < target {
+            (1, down)
+        } else {
+            (2, up)
+        }
+    } else if candidate > target {
+        (1, up)
+    } else {
+        (2, down)
+    }
+}
+
+/// Registered font families, by case-insensitive name. Generic names
+/// (`"sans-serif"`, `"monospace"`) can be registered like any other.
+///
+/// Styles resolve fonts when they are applied, so register families at
+/// startup.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ReactFontRegistry {
+    families: HashMap<String, ReactFontFamily>,
+}
+
+fn family_key(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+impl ReactFontRegistry {
+    pub fn insert(&mut self, name: &str, family: ReactFontFamily) {
+        self.families.insert(family_key(name), family);
+    }
+
+    pub fn with_family(mut self, name: &str, family: ReactFontFamily) -> Self {
+        self.insert(name, family);
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&ReactFontFamily> {
+        self.families.get(&family_key(name))
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<ReactFontFamily> {
+        self.families.remove(&family_key(name))
+    }
+
+    /// Font for a `fontFamily` list: the first registered family (or its
+    /// fallbacks) with a face, or an unregistered asset path.
+    pub fn resolve(
+        &self,
+        families: &[String],
+        weight: u16,
+        style: FontStyle,
+        asset_server: &AssetServer,
+    ) -> Option<Handle<Font>> {
+        let mut visited = Vec::new();
+        families.iter().find_map(|name| {
+            if self.families.contains_key(&family_key(name)) {
+                self.resolve_family(name, weight, style, asset_server, &mut visited)
+            } else if name.contains('/') || name.contains('.') {
+                Some(asset_server.load(name.clone()))
+            } else {
+                None
+            }
+        })
+    }
+
+    fn resolve_family(
+        &self,
+        name: &str,
+        weight: u16,
+        style: FontStyle,
+        asset_server: &AssetServer,
+        visited: &mut Vec<String>,
+    ) -> Option<Handle<Font>> {
+        let key = family_key(name);
+        if visited.contains(&key) {
+            return None;
+        }
+        let family = self.families.get(&key)?;
+        visited.push(key);
+        if let Some(face) = family.matching_face(weight, style) {
+            return Some(match &face.source {
+                FontSource::Handle(handle) => handle.clone(),
+                FontSource::Path(path) => asset_server.load(path.clone()),
+            });
+        }
+        family
+            .fallbacks
+            .iter()
+            .find_map(|next| self.resolve_family(next, weight, style, asset_server, visited))
+    }
+}
+
+/// Load registered face paths once, so resolving a style is a handle clone.
+pub fn load_react_fonts(mut registry: ResMut<ReactFontRegistry>, asset_server: Res<AssetServer>) {
+    if !registry.is_changed() {
+        return;
+    }
+    let registry = registry.bypass_change_detection();
+    for family in registry.families.values_mut() {
+        for face in &mut family.faces {
+            if let FontSource::Path(path) = &face.source {
+                face.source = FontSource::Handle(asset_server.load(path.clone()));
+            }
+        }
+    }
+}
+
+/// The font a style asks for through `fontFamily` / `fontWeight` /
+/// `fontStyle`, or `None` to use the root / plugin default.
+pub fn style_font(
+    props: &StyleProps,
+    registry: &ReactFontRegistry,
+    asset_server: &AssetServer,
+) -> Option<Handle<Font>> {
+    registry.resolve(
+        &style_font_families(props)?,
+        style_font_weight(props).unwrap_or(400),
+        style_font_style(props).unwrap_or_default(),
+        asset_server,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn family(weights: &[(u16, FontStyle)]) -> ReactFontFamily {
+        weights
+            .iter()
+            .fold(ReactFontFamily::new(), |family, &(weight, style)| {
+                family.face(weight, style, format!("{weight}-{style:?}.ttf"))
+            })
+    }
+
+    fn matched(family: &ReactFontFamily, weight: u16, style: FontStyle) -> (u16, FontStyle) {
+        let face = family.matching_face(weight, style).unwrap();
+        (face.weight, face.style)
+    }
+
+    #[test]
+    fn test_css_weight_and_style_matching() {
+        use FontStyle::*;
+        let inter = family(&[(300, Normal), (400, Normal), (700, Normal), (400, Italic)]);
+        assert_eq!(matched(&inter, 700, Normal), (700, Normal));
+        assert_eq!(matched(&inter, 500, Normal), (400, Normal));
+        assert_eq!(matched(&inter, 600, Normal), (700, Normal));
+        assert_eq!(matched(&inter, 200, Normal), (300, Normal));
+        assert_eq!(matched(&inter, 900, Normal), (700, Normal));
+        assert_eq!(matched(&inter, 700, Italic), (400, Italic));
+
+        let bold_only = family(&[(700, Normal)]);
+        assert_eq!(matched(&bold_only, 400, Italic), (700, Normal));
+    }
+
+    #[test]
+    fn test_family_list_and_fallback_chain() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Font>();
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let regular = asset_server.load::<Font>("fonts/Noto-Regular.ttf");
+
+        let registry = ReactFontRegistry::default()
+            .with_family("Inter", ReactFontFamily::new().fallback("Noto Sans"))
+            .with_family("Noto Sans", ReactFontFamily::new().regular(regular.clone()))
+            .with_family("Loop", ReactFontFamily::new().fallback("loop"));
+        let resolve = |families: &[&str]| {
+            let families: Vec<String> = families.iter().map(|f| f.to_string()).collect();
+            registry.resolve(&families, 700, FontStyle::Normal, &asset_server)
+        };
+
+        assert_eq!(resolve(&["inter", "sans-serif"]), Some(regular));
+        assert_eq!(resolve(&["Loop", "monospace"]), None);
+        assert_eq!(
+            resolve(&["Unknown", "fonts/Other.ttf"]).and_then(|h| h.path().cloned()),
+            Some("fonts/Other.ttf".into())
+        );
+    }
+}
@@ -0,0 +1,438 @@
+//! CSS grid line names and template areas.
+//!
+//! Bevy places grid items by line number only, so names are resolved on the
+//! host. A container's `[name]` lines and `gridTemplateAreas` become a
+//! [`ReactGridTemplate`]; items placed by name (`gridArea: "main"`,
+//! `gridColumn: "sidebar-start / content-end"`) carry a [`ReactGridItem`], and
+//! [`resolve_grid_placements`] writes the resolved `GridPlacement`s before
+//! layout.
+
+use bevy::prelude::*;
+
+use crate::react::style::{StyleProps, parse_grid_track_list};
+
+/// One side of a grid placement (`grid-row-start` etc.).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum GridLine {
+    #[default]
+    Auto,
+    /// 1-based line; negative counts from the end of the explicit grid.
+    Line(i16),
+    Span(u16),
+    /// `name` or `name 2`: the nth line called `name` (or `name-start` /
+    /// `name-end` on that side).
+    Named {
+        name: String,
+        nth: i16,
+    },
+}
+
+impl GridLine {
+    pub fn parse(value: &str) -> Self {
+        let tokens: Vec<&str> = value.split_whitespace().collect();
+        let is_span = |t: &&str| t.eq_ignore_ascii_case("span");
+        let number = tokens.iter().find_map(|t| t.parse::<i16>().ok());
+        let name = tokens
+            .iter()
+            .find(|t| !is_span(t) && t.parse::<i16>().is_err())
+            .map(|t| t.to_string());
+
+        if tokens.iter().any(is_span) {
+            if name.is_some() {
+                log::warn!("Named grid spans are not supported: '{}'", value);
+            }
+            return GridLine::Span(number.unwrap_or(1).max(1) as u16);
+        }
+        match (name, number) {
+            (Some(name), _) if name.eq_ignore_ascii_case("auto") => GridLine::Auto,
+            (Some(name), nth) => GridLine::Named {
+                name,
+                nth: nth.filter(|n| *n != 0).unwrap_or(1),
+            },
+            (None, Some(n)) if n != 0 => GridLine::Line(n),
+            _ => GridLine::Auto,
+        }
+    }
+
+    fn is_named(&self) -> bool {
+        matches!(self, GridLine::Named { .. })
+    }
+
+    /// Line number, looking names up on the container's `side` (`"start"` / `"end"`).
+    fn resolve(&self, names: Option<&GridLineNames>, side: &str) -> GridLine {
+        let GridLine::Named { name, nth } = self else {
+            return self.clone();
+        };
+        names
+            .and_then(|names| {
+                names
+                    .find(&format!("{name}-{side}"), *nth)
+                    .or_else(|| names.find(name, *nth))
+            })
+            .map_or(GridLine::Auto, GridLine::Line)
+    }
+}
+
+/// Placement from a start and end line.
+pub fn grid_placement(
+    start: &GridLine,
+    end: &GridLine,
+    names: Option<&GridLineNames>,
+) -> GridPlacement {
+    match (start.resolve(names, "start"), end.resolve(names, "end")) {
+        (GridLine::Line(s), GridLine::Line(e)) => GridPlacement::start_end(s, e),
+        (GridLine::Line(s), GridLine::Span(n)) => GridPlacement::start_span(s, n),
+        (GridLine::Line(s), _) => GridPlacement::start(s),
+        (GridLine::Span(n), GridLine::Line(e)) => GridPlacement::end_span(e, n),
+        (_, GridLine::Line(e)) => GridPlacement::end(e),
+        (GridLine::Span(n), _) | (_, GridLine::Span(n)) => GridPlacement::span(n),
+        _ => GridPlacement::auto(),
+    }
+}
+
+/// Parse CSS grid-row / grid-column placement (`2 / span 3`). Named lines
+/// need the container and are resolved by [`resolve_grid_placements`].
+pub fn parse_grid_placement(value: &str) -> GridPlacement {
+    let (start, end) = parse_line_pair(value);
+    grid_placement(&start, &end, None)
+}
+
+/// `gridRow` / `gridColumn` shorthand: `start / end`. A lone name is used for
+/// both sides, so `gridColumn: "main"` spans `main-start` to `main-end`.
+fn parse_line_pair(value: &str) -> (GridLine, GridLine) {
+    let mut parts = value.split('/').map(GridLine::parse);
+    let start = parts.next().unwrap_or_default();
+    let end = parts.next().unwrap_or_else(|| implied_end(&start));
+    (start, end)
+}
+
+fn implied_end(start: &GridLine) -> GridLine {
+    if start.is_named() {
+        start.clone()
+    } else {
+        GridLine::Auto
+    }
+}
+
+/// Line names along one grid axis, in source order.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GridLineNames(Vec<(String, i16)>);
+
+impl GridLineNames {
+    pub fn push(&mut self, name: impl Into<String>, line: i16) {
+        self.0.push((name.into(), line));
+    }
+
+    /// The `nth` line called `name`; negative `nth` counts from the last.
+    pub fn find(&self, name: &str, nth: i16) -> Option<i16> {
+        let mut lines = self
+            .0
+            .iter()
+            .filter(|(n, _)| n == name)
+            .map(|(_, line)| *line);
+        if nth > 0 {
+            lines.nth(nth as usize - 1)
+        } else {
+            lines.rev().nth(nth.unsigned_abs() as usize - 1)
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// A rectangle from `gridTemplateAreas`, as 1-based lines (end exclusive).
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridArea {
+    pub name: String,
+    pub rows: [i16; 2],
+    pub columns: [i16; 2],
+}
+
+/// Parse `gridTemplateAreas`: one quoted string per row, `.` for empty cells.
+/// Rows of different lengths invalidate the whole value; non-rectangular
+/// areas are dropped.
+pub fn parse_grid_template_areas(value: &str) -> Vec<GridArea> {
+    let rows: Vec<Vec<&str>> = value
+        .split(['"', '\''])
+        .skip(1)
+        .step_by(2)
+        .map(|row| row.split_whitespace().collect())
+        .collect();
+    let Some(width) = rows.first().map(Vec::len) else {
+        return Vec::new();
+    };
+    if rows.iter().any(|row| row.len() != width) {
+        log::warn!("gridTemplateAreas rows differ in length: '{}'", value);
+        return Vec::new();
+    }
+
+    let mut areas: Vec<GridArea> = Vec::new();
+    for (r, row) in rows.iter().enumerate() {
+        for (c, cell) in row.iter().enumerate() {
+            if cell.chars().all(|ch| ch == '.') {
+                continue;
+            }
+            let (r, c) = (r as i16 + 1, c as i16 + 1);
+            match areas.iter_mut().find(|area| area.name == *cell) {
+                Some(area) => {
+                    area.rows = [area.rows[0].min(r), area.rows[1].max(r + 1)];
+                    area.columns = [area.columns[0].min(c), area.columns[1].max(c + 1)];
+                }
+                None => areas.push(GridArea {
+                    name: cell.to_string(),
+                    rows: [r, r + 1],
+                    columns: [c, c + 1],
+                }),
+            }
+        }
+    }
+
+    areas.retain(|area| {
+        let cells = (area.rows[0]..area.rows[1])
+            .flat_map(|r| (area.columns[0]..area.columns[1]).map(move |c| (r, c)));
+        let rectangular = cells
+            .into_iter()
+            .all(|(r, c)| rows[r as usize - 1][c as usize - 1] == area.name);
+        if !rectangular {
+            log::warn!("Grid area '{}' is not a rectangle, ignoring", area.name);
+        }
+        rectangular
+    });
+    areas
+}
+
+/// Named lines of a grid container, from `[name]`s in its track lists and
+/// `gridTemplateAreas` (`area-start` / `area-end`).
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct ReactGridTemplate {
+    pub rows: GridLineNames,
+    pub columns: GridLineNames,
+}
+
+impl ReactGridTemplate {
+    /// `None` when the container names no lines or areas.
+    pub fn from_style(props: &StyleProps) -> Option<Self> {
+        let mut template = Self::default();
+        if let Some(rows) = &props.grid_template_rows {
+            for (name, line) in parse_grid_track_list(rows).line_names {
+                template.rows.push(name, line);
+            }
+        }
+        if let Some(columns) = &props.grid_template_columns {
+            for (name, line) in parse_grid_track_list(columns).line_names {
+                template.columns.push(name, line);
+            }
+        }
+        if let Some(areas) = &props.grid_template_areas {
+            for area in parse_grid_template_areas(areas) {
+                template
+                    .rows
+                    .push(format!("{}-start", area.name), area.rows[0]);
+                template
+                    .rows
+                    .push(format!("{}-end", area.name), area.rows[1]);
+                template
+                    .columns
+                    .push(format!("{}-start", area.name), area.columns[0]);
+                template
+                    .columns
+                    .push(format!("{}-end", area.name), area.columns[1]);
+            }
+        }
+        (!template.rows.is_empty() || !template.columns.is_empty()).then_some(template)
+    }
+}
+
+/// A grid item's four lines, from `gridArea`, `gridRow` / `gridColumn` and
+/// the `gridRowStart`-style longhands (later ones override).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GridItemLines {
+    pub row_start: GridLine,
+    pub row_end: GridLine,
+    pub column_start: GridLine,
+    pub column_end: GridLine,
+}
+
+impl GridItemLines {
+    /// `None` when the style places nothing.
+    pub fn from_style(props: &StyleProps) -> Option<Self> {
+        let mut lines = Self::default();
+        let mut placed = false;
+        if let Some(area) = &props.grid_area {
+            // row-start / column-start / row-end / column-end; a missing side
+            // repeats a named opposite side, as in CSS
+            let mut parts = area.0.split('/').map(GridLine::parse);
+            lines.row_start = parts.next().unwrap_or_default();
+            lines.column_start = parts
+                .next()
+                .unwrap_or_else(|| implied_end(&lines.row_start));
+            lines.row_end = parts
+                .next()
+                .unwrap_or_else(|| implied_end(&lines.row_start));
+            lines.column_end = parts
+                .next()
+                .unwrap_or_else(|| implied_end(&lines.column_start));
+            placed = true;
+        }
+        if let Some(row) = &props.grid_row {
+            (lines.row_start, lines.row_end) = parse_line_pair(row);
+            placed = true;
+        }
+        if let Some(column) = &props.grid_column {
+            (lines.column_start, lines.column_end) = parse_line_pair(column);
+            placed = true;
+        }
+        let longhands = [
+            (&props.grid_row_start, &mut lines.row_start),
+            (&props.grid_row_end, &mut lines.row_end),
+            (&props.grid_column_start, &mut lines.column_start),
+            (&props.grid_column_end, &mut lines.column_end),
+        ];
+        for (value, line) in longhands {
+            if let Some(value) = value {
+                *line = GridLine::parse(&value.0);
+                placed = true;
+            }
+        }
+        placed.then_some(lines)
+    }
+
+    pub fn has_names(&self) -> bool {
+        [
+            &self.row_start,
+            &self.row_end,
+            &self.column_start,
+            &self.column_end,
+        ]
+        .into_iter()
+        .any(GridLine::is_named)
+    }
+
+    pub fn row(&self, names: Option<&ReactGridTemplate>) -> GridPlacement {
+        grid_placement(&self.row_start, &self.row_end, names.map(|t| &t.rows))
+    }
+
+    pub fn column(&self, names: Option<&ReactGridTemplate>) -> GridPlacement {
+        grid_placement(
+            &self.column_start,
+            &self.column_end,
+            names.map(|t| &t.columns),
+        )
+    }
+}
+
+/// A grid item placed by line or area name, resolved against its parent's
+/// [`ReactGridTemplate`].
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct ReactGridItem(pub GridItemLines);
+
+impl ReactGridItem {
+    /// `None` unless the item names a line or area.
+    pub fn from_style(props: &StyleProps) -> Option<Self> {
+        GridItemLines::from_style(props)
+            .filter(GridItemLines::has_names)
+            .map(Self)
+    }
+}
+
+/// Insert or clear a node's [`ReactGridTemplate`] / [`ReactGridItem`] after
+/// its style is applied.
+pub(crate) fn sync_grid_names(commands: &mut Commands, entity: Entity, style: Option<&StyleProps>) {
+    let mut entity = commands.entity(entity);
+    match style.and_then(ReactGridTemplate::from_style) {
+        Some(template) => entity.insert(template),
+        None => entity.remove::<ReactGridTemplate>(),
+    };
+    match style.and_then(ReactGridItem::from_style) {
+        Some(item) => entity.insert(item),
+        None => entity.remove::<ReactGridItem>(),
+    };
+}
+
+/// Write named grid placements into `Node`. Runs in `PostUpdate` before UI
+/// layout; names the parent does not define place the item automatically.
+pub fn resolve_grid_placements(
+    mut items: Query<(&ReactGridItem, &mut Node, Option<&ChildOf>)>,
+    templates: Query<&ReactGridTemplate>,
+) {
+    for (item, mut node, child_of) in &mut items {
+        let names = child_of.and_then(|child_of| templates.get(child_of.parent()).ok());
+        let row = item.0.row(names);
+        let column = item.0.column(names);
+        // Keep change detection quiet while the placement is stable.
+        let current = node.bypass_change_detection();
+        if current.grid_row != row || current.grid_column != column {
+            node.grid_row = row;
+            node.grid_column = column;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::react::style::{json_to_style, parse_props};
+
+    fn style(json: &str) -> StyleProps {
+        parse_props(&format!(r#"{{"style":{json}}}"#))
+            .style
+            .unwrap()
+    }
+
+    #[test]
+    fn test_areas_and_named_lines_resolve() {
+        let template = ReactGridTemplate::from_style(&style(
+            r#"{
+                "gridTemplateColumns": "[full-start] 200px [content-start] 1fr [content-end] repeat(auto-fill, 40px) [full-end]",
+                "gridTemplateAreas": "\"header header\" \"side main\" \". main\""
+            }"#,
+        ))
+        .unwrap();
+
+        let item = |json: &str| ReactGridItem::from_style(&style(json)).unwrap();
+        let main = item(r#"{"gridArea":"main"}"#);
+        assert_eq!(main.0.row(Some(&template)), GridPlacement::start_end(2, 4));
+        assert_eq!(
+            main.0.column(Some(&template)),
+            GridPlacement::start_end(2, 3)
+        );
+
+        let header = item(r#"{"gridColumn":"header-start / span 2","gridRow":"header"}"#);
+        assert_eq!(
+            header.0.column(Some(&template)),
+            GridPlacement::start_span(1, 2)
+        );
+        assert_eq!(
+            header.0.row(Some(&template)),
+            GridPlacement::start_end(1, 2)
+        );
+
+        let full = item(r#"{"gridColumn":"content-start / full-end"}"#);
+        assert_eq!(
+            full.0.column(Some(&template)),
+            GridPlacement::start_end(2, -1)
+        );
+        assert_eq!(full.0.column(None), GridPlacement::auto());
+
+        assert!(ReactGridItem::from_style(&style(r#"{"gridColumn":"1 / 3"}"#)).is_none());
+    }
+
+    #[test]
+    fn test_invalid_areas_are_dropped() {
+        let areas = parse_grid_template_areas(r#""a a" "a b""#);
+        assert_eq!(
+            areas.iter().map(|a| a.name.as_str()).collect::<Vec<_>>(),
+            ["b"]
+        );
+        assert!(parse_grid_template_areas(r#""a a" "b""#).is_empty());
+    }
+
+    #[test]
+    fn test_numeric_grid_area_maps_without_names() {
+        let node = json_to_style(&style(r#"{"gridArea":"2 / 1 / 4 / span 3"}"#));
+        assert_eq!(node.grid_row, GridPlacement::start_end(2, 4));
+        assert_eq!(node.grid_column, GridPlacement::start_span(1, 3));
+    }
+}
@@ -11,7 +11,7 @@ use bevy::prelude::*;
 use boa_gc::{Finalize, Trace, empty_trace};
 
 use crate::js_bevy::JsClientResource;
-use crate::react::{ReactDirtyFlag, ReactScriptSource};
+use crate::react::{ReactDirtyFlag, ReactLoadedModules, ReactScriptSource};
 
 /// Marker for React roots that should re-execute when Vite HMR fires.
 #[derive(Component, Clone, Copy, Debug, Default)]
@@ -54,6 +54,7 @@ pub(crate) fn apply_react_hmr_reloads(
     mut commands: Commands,
     reload: Res<ReactReloadFlag>,
     js_client: Option<Res<JsClientResource>>,
+    mut loaded: ResMut<ReactLoadedModules>,
     mut roots: Query<(Entity, &mut ReactScriptSource), With<ReactHmrRoot>>,
 ) {
     if !reload.take() {
@@ -66,6 +67,7 @@ pub(crate) fn apply_react_hmr_reloads(
     if let Some(js_client) = js_client {
         js_client.clear_esm_module_cache();
     }
+    loaded.clear();
 
     for (entity, mut script) in &mut roots {
         let base = script
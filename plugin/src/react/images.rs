@@ -0,0 +1,514 @@
+//! Image `src` resolution for `<image>`: asset paths, `http(s)://` URLs and
+//! `data:` URIs, plus `onLoad` / `onError` events.
+//!
+//! Asset paths go straight to [`AssetServer::load`]. Remote URLs load through
+//! the `http` / `https` asset sources that [`ReactRemoteImagePlugin`]
+//! registers (a reader backed by the crate's `fetch` client), with the format
+//! guessed from the bytes. `data:` URIs are decoded in Rust into [`Image`]
+//! assets. [`ReactImageCache`] shares remote and `data:` images between the
+//! nodes showing them, so a list of identical avatars fetches or decodes once.
+
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use base64::Engine;
+use bevy::asset::io::{AssetReader, AssetReaderError, AssetSource, PathStream, Reader, VecReader};
+use bevy::asset::{LoadState, RenderAssetUsages};
+use bevy::image::{
+    CompressedImageFormats, ImageFormatSetting, ImageLoaderSettings, ImageSampler, ImageType,
+};
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use serde_json::{Value, json};
+
+use crate::react::event_queue::ReactEventQueue;
+use crate::react::systems::{ReactNode, ReactRoot, find_root_id};
+
+/// Registers the `http` and `https` asset sources used by remote image `src`s.
+///
+/// Like Bevy's `WebAssetPlugin`, it must be added **before** `AssetPlugin`
+/// (i.e. before `DefaultPlugins`); added later it logs an error and does
+/// nothing. Without it, remote images fail to load and report `onError`.
+///
+/// ```ignore
+/// App::new().add_plugins((ReactRemoteImagePlugin, DefaultPlugins, ReactPlugin));
+/// ```
+pub struct ReactRemoteImagePlugin;
+
+impl Plugin for ReactRemoteImagePlugin {
+    fn build(&self, app: &mut App) {
+        if app.world().contains_resource::<AssetServer>() {
+            log::error!(
+                "ReactRemoteImagePlugin must be added before AssetPlugin (DefaultPlugins); \
+                 remote image `src`s will not load"
+            );
+            return;
+        }
+        for scheme in ["http", "https"] {
+            app.register_asset_source(
+                scheme,
+                AssetSource::build().with_reader(move || Box::new(RemoteImageReader { scheme })),
+            );
+        }
+    }
+}
+
+/// [`AssetReader`] fetching `<scheme>://<path>` over HTTP.
+struct RemoteImageReader {
+    scheme: &'static str,
+}
+
+impl RemoteImageReader {
+    fn url(&self, path: &Path) -> String {
+        format!("{}://{}", self.scheme, path.to_string_lossy())
+    }
+}
+
+#[cfg(all(feature = "fetch", not(target_arch = "wasm32")))]
+fn fetch_image_bytes(url: String) -> Result<Vec<u8>, AssetReaderError> {
+    use std::sync::LazyLock;
+
+    static CLIENT: LazyLock<reqwest::blocking::Client> =
+        LazyLock::new(reqwest::blocking::Client::new);
+
+    let io_error = |err: reqwest::Error| {
+        AssetReaderError::Io(std::io::Error::other(format!("{url}: {err}")).into())
+    };
+    let response = CLIENT.get(&url).send().map_err(io_error)?;
+    let status = response.status();
+    if status == reqwest::StatusCode::NOT_FOUND {
+        return Err(AssetReaderError::NotFound(PathBuf::from(url)));
+    }
+    if !status.is_success() {
+        return Err(AssetReaderError::HttpError(status.as_u16()));
+    }
+    response
+        .bytes()
+        .map(|bytes| bytes.to_vec())
+        .map_err(io_error)
+}
+
+#[cfg(not(all(feature = "fetch", not(target_arch = "wasm32"))))]
+fn fetch_image_bytes(url: String) -> Result<Vec<u8>, AssetReaderError> {
+    Err(AssetReaderError::Io(
+        std::io::Error::other(format!("{url}: remote images need the `fetch` feature")).into(),
+    ))
+}
+
+impl AssetReader for RemoteImageReader {
+    async fn read<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        let url = self.url(path);
+        #[cfg(not(target_arch = "wasm32"))]
+        let bytes = blocking::unblock(move || fetch_image_bytes(url)).await?;
+        #[cfg(target_arch = "wasm32")]
+        let bytes = fetch_image_bytes(url)?;
+        Ok(VecReader::new(bytes))
+    }
+
+    async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        // No `.meta` round trip per image; loader settings come from the request.
+        Err::<VecReader, _>(AssetReaderError::NotFound(path.to_path_buf()))
+    }
+
+    async fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<Box<PathStream>, AssetReaderError> {
+        Err(AssetReaderError::NotFound(path.to_path_buf()))
+    }
+
+    async fn is_directory<'a>(&'a self, _path: &'a Path) -> Result<bool, AssetReaderError> {
+        Ok(false)
+    }
+}
+
+/// Where an image `src` points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageSrcKind {
+    /// Path in the app's asset sources.
+    Asset,
+    /// `http://` / `https://` URL.
+    Remote,
+    /// `data:` URI.
+    Data,
+}
+
+pub fn image_src_kind(src: &str) -> ImageSrcKind {
+    let scheme = src
+        .split_once(':')
+        .map(|(scheme, _)| scheme.to_ascii_lowercase());
+    match scheme.as_deref() {
+        Some("data") => ImageSrcKind::Data,
+        Some("http" | "https") if src.contains("://") => ImageSrcKind::Remote,
+        _ => ImageSrcKind::Asset,
+    }
+}
+
+/// Error decoding a `data:` image URI.
+#[derive(Debug)]
+pub struct DataUriError(String);
+
+impl std::fmt::Display for DataUriError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid data: URI image: {}", self.0)
+    }
+}
+
+impl std::error::Error for DataUriError {}
+
+/// Decode a `data:image/<type>;base64,<data>` URI into an [`Image`].
+/// Without a MIME type the format is guessed from the bytes.
+pub fn decode_data_uri(uri: &str) -> Result<Image, DataUriError> {
+    let rest = uri
+        .strip_prefix("data:")
+        .ok_or_else(|| DataUriError("missing `data:` prefix".to_string()))?;
+    let (header, payload) = rest
+        .split_once(',')
+        .ok_or_else(|| DataUriError("missing `,`".to_string()))?;
+    let mut params = header.split(';');
+    let mime = params.next().unwrap_or_default().trim();
+    if !params.any(|param| param.trim().eq_ignore_ascii_case("base64")) {
+        return Err(DataUriError(
+            "only base64 payloads are supported".to_string(),
+        ));
+    }
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(payload.trim())
+        .map_err(|err| DataUriError(err.to_string()))?;
+    let image_type = if mime.is_empty() {
+        let format = guess_image_format(&bytes)
+            .ok_or_else(|| DataUriError("unknown image format".to_string()))?;
+        ImageType::Format(format)
+    } else {
+        ImageType::MimeType(mime)
+    };
+    Image::from_buffer(
+        &bytes,
+        image_type,
+        CompressedImageFormats::NONE,
+        true,
+        ImageSampler::Default,
+        RenderAssetUsages::default(),
+    )
+    .map_err(|err| DataUriError(err.to_string()))
+}
+
+fn guess_image_format(bytes: &[u8]) -> Option<ImageFormat> {
+    const MAGIC: &[(&[u8], &str)] = &[
+        (b"\x89PNG", "image/png"),
+        (b"\xFF\xD8\xFF", "image/jpeg"),
+        (b"GIF8", "image/gif"),
+        (b"BM", "image/bmp"),
+    ];
+    if bytes.len() >= 12 && &bytes[..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+        return ImageFormat::from_mime_type("image/webp");
+    }
+    MAGIC
+        .iter()
+        .find(|(magic, _)| bytes.starts_with(magic))
+        .and_then(|(_, mime)| ImageFormat::from_mime_type(mime))
+}
+
+/// Remote and `data:` images by a hash of their `src`.
+///
+/// Only asset ids are kept: an entry lives as long as some node holds its
+/// image, and dead entries are dropped when a new source is cached.
+#[derive(Resource, Default)]
+pub struct ReactImageCache {
+    ids: Mutex<HashMap<u64, AssetId<Image>>>,
+}
+
+impl ReactImageCache {
+    /// Handle for `src`, loading (or decoding) it unless a node still shows it.
+    pub fn load(&self, src: &str, asset_server: &AssetServer) -> Handle<Image> {
+        let kind = image_src_kind(src);
+        if kind == ImageSrcKind::Asset {
+            return asset_server.load(src.to_string());
+        }
+        let Ok(mut ids) = self.ids.lock() else {
+            return asset_server.load(src.to_string());
+        };
+        let key = src_key(src);
+        if let Some(handle) = ids.get(&key).and_then(|id| asset_server.get_id_handle(*id)) {
+            return handle;
+        }
+        let handle = match kind {
+            ImageSrcKind::Data => {
+                let uri = src.to_string();
+                asset_server.add_async(async move { decode_data_uri(&uri) })
+            }
+            _ => asset_server.load_with_settings(
+                src.to_string(),
+                |settings: &mut ImageLoaderSettings| {
+                    settings.format = ImageFormatSetting::Guess;
+                },
+            ),
+        };
+        ids.retain(|_, id| asset_server.get_id_handle(*id).is_some());
+        ids.insert(key, handle.id());
+        handle
+    }
+
+    /// Handle for `src` if it is cached and still in use.
+    pub fn get(&self, src: &str, asset_server: &AssetServer) -> Option<Handle<Image>> {
+        let ids = self.ids.lock().ok()?;
+        asset_server.get_id_handle(*ids.get(&src_key(src))?)
+    }
+
+    /// Forget all cached sources; images still shown stay loaded.
+    pub fn clear(&self) {
+        if let Ok(mut ids) = self.ids.lock() {
+            ids.clear();
+        }
+    }
+}
+
+fn src_key(src: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    src.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Subscribes an `<image>` to `load` / `error` events; remembers the image
+/// already reported so each `src` fires once.
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct ReactImageListener {
+    reported: Option<AssetId<Image>>,
+}
+
+/// Insert or clear [`ReactImageListener`] from React props (create / update paths).
+pub(crate) fn sync_image_listener(commands: &mut Commands, entity: Entity, props: &Value) {
+    let subscribed = ["onLoad", "onError"]
+        .iter()
+        .any(|key| props.get(key).and_then(Value::as_bool) == Some(true));
+    if subscribed {
+        commands
+            .entity(entity)
+            .insert_if_new(ReactImageListener::default());
+    } else {
+        commands.entity(entity).remove::<ReactImageListener>();
+    }
+}
+
+/// Queue `load` (`{ width, height }` in image pixels) or `error` (`{ message }`)
+/// once a subscribed image finishes loading.
+pub fn emit_react_image_events(
+    asset_server: Res<AssetServer>,
+    images: Res<Assets<Image>>,
+    event_queue: Option<Res<ReactEventQueue>>,
+    mut listeners: Query<(Entity, &ReactNode, &ImageNode, &mut ReactImageListener)>,
+    parents: Query<&ChildOf>,
+    roots: Query<&ReactRoot>,
+) {
+    let Some(event_queue) = event_queue else {
+        return;
+    };
+    for (entity, node, image, mut listener) in &mut listeners {
+        let id = image.image.id();
+        if listener.reported == Some(id) {
+            continue;
+        }
+        let (event_type, payload) = match asset_server.get_load_state(id) {
+            Some(LoadState::Loaded) => {
+                let Some(loaded) = images.get(id) else {
+                    continue;
+                };
+                let size = loaded.size();
+                ("load", json!({ "width": size.x, "height": size.y }))
+            }
+            Some(LoadState::Failed(err)) => ("error", json!({ "message": err.to_string() })),
+            _ => continue,
+        };
+        let Some(root_id) = find_root_id(entity, &parents, &roots) else {
+            continue;
+        };
+        listener.reported = Some(id);
+        event_queue.push_event(root_id, node.node_id, event_type, payload);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::time::{Duration, Instant};
+
+    use bevy::image::ImageLoader;
+
+    use super::*;
+
+    /// 1×1 PNG.
+    const PNG_BASE64: &str = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNk+M9QDwADhgGAWjR9awAAAABJRU5ErkJggg==";
+
+    #[cfg(feature = "fetch")]
+    fn png_bytes() -> Vec<u8> {
+        base64::engine::general_purpose::STANDARD
+            .decode(PNG_BASE64)
+            .unwrap()
+    }
+
+    #[cfg(feature = "fetch")]
+    /// Local HTTP stand-in: serves the PNG for `/avatar*` and 404 otherwise.
+    fn serve_images() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else {
+                    continue;
+                };
+                let mut request = [0u8; 1024];
+                let len = stream.read(&mut request).unwrap_or(0);
+                let request = String::from_utf8_lossy(&request[..len]);
+                let path = request.split_whitespace().nth(1).unwrap_or_default();
+                let (status, body) = if path.starts_with("/avatar") {
+                    ("200 OK", png_bytes())
+                } else {
+                    ("404 Not Found", Vec::new())
+                };
+                let head = format!(
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    body.len()
+                );
+                let _ = stream.write_all(head.as_bytes());
+                let _ = stream.write_all(&body);
+            }
+        });
+        format!("http://{addr}")
+    }
+
+    #[test]
+    fn test_image_src_kinds_and_data_uris() {
+        assert_eq!(image_src_kind("ui/icon.png"), ImageSrcKind::Asset);
+        assert_eq!(image_src_kind("embedded://icon.png"), ImageSrcKind::Asset);
+        assert_eq!(
+            image_src_kind("HTTPS://cdn.example.com/a"),
+            ImageSrcKind::Remote
+        );
+        assert_eq!(
+            image_src_kind("data:image/png;base64,AA"),
+            ImageSrcKind::Data
+        );
+
+        let image = decode_data_uri(&format!("data:image/png;base64,{PNG_BASE64}")).unwrap();
+        assert_eq!(image.size(), UVec2::ONE);
+        assert!(decode_data_uri(&format!("data:;base64,{PNG_BASE64}")).is_ok());
+        assert!(decode_data_uri("data:image/png,rawbytes").is_err());
+        assert!(decode_data_uri("data:image/png;base64,!!").is_err());
+    }
+
+    #[test]
+    fn test_cache_releases_images_no_node_uses() {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            ImagePlugin::default(),
+        ))
+        .init_resource::<ReactImageCache>();
+        let src = format!("data:image/png;base64,{PNG_BASE64}");
+        let load = |app: &App| {
+            let world = app.world();
+            world
+                .resource::<ReactImageCache>()
+                .load(&src, world.resource::<AssetServer>())
+        };
+        let cached = |app: &App| {
+            let world = app.world();
+            world
+                .resource::<ReactImageCache>()
+                .get(&src, world.resource::<AssetServer>())
+        };
+
+        let handle = load(&app);
+        let node = app.world_mut().spawn(ImageNode::new(handle.clone())).id();
+        assert_eq!(load(&app).id(), handle.id());
+        let id = handle.id();
+        drop(handle);
+        app.update();
+        assert_eq!(cached(&app).map(|h| h.id()), Some(id));
+
+        app.world_mut().entity_mut(node).despawn();
+        app.update();
+        app.update();
+        assert!(cached(&app).is_none());
+        assert_ne!(load(&app).id(), id);
+    }
+
+    #[test]
+    #[cfg(feature = "fetch")]
+    fn test_remote_and_data_images_fire_load_and_error() {
+        let base = serve_images();
+        let mut app = App::new();
+        app.add_plugins((
+            ReactRemoteImagePlugin,
+            MinimalPlugins,
+            AssetPlugin::default(),
+            ImagePlugin::default(),
+        ))
+        // The real loader is registered once a render device exists.
+        .register_asset_loader(ImageLoader::new(CompressedImageFormats::NONE))
+        .init_resource::<ReactEventQueue>()
+        .init_resource::<ReactImageCache>()
+        .add_systems(Update, emit_react_image_events);
+
+        let root = app
+            .world_mut()
+            .spawn(ReactRoot {
+                id: "root-1".to_string(),
+            })
+            .id();
+        let sources = [
+            format!("{base}/avatar?user=7"),
+            format!("data:image/png;base64,{PNG_BASE64}"),
+            format!("{base}/missing.png"),
+        ];
+        for (node_id, src) in sources.iter().enumerate() {
+            let world = app.world_mut();
+            let handle = world
+                .resource::<ReactImageCache>()
+                .load(src, world.resource::<AssetServer>());
+            world.spawn((
+                ReactNode {
+                    node_id: node_id as u64,
+                },
+                ImageNode::new(handle),
+                ReactImageListener::default(),
+                ChildOf(root),
+            ));
+        }
+
+        let mut events = Vec::new();
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while events.len() < sources.len() && Instant::now() < deadline {
+            app.update();
+            events.extend(app.world().resource::<ReactEventQueue>().drain());
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        events.sort_by_key(|event| event.node_id);
+        let kinds: Vec<&str> = events.iter().map(|e| e.event_type.as_str()).collect();
+        assert_eq!(kinds, ["load", "load", "error"]);
+        let payload: serde_json::Value = serde_json::from_str(&events[0].payload_json).unwrap();
+        assert_eq!(payload, serde_json::json!({ "width": 1, "height": 1 }));
+
+        // Cached by URL: the same source reuses its handle and does not fire again.
+        let world = app.world();
+        let cache = world.resource::<ReactImageCache>();
+        assert!(
+            cache
+                .get(&sources[0], world.resource::<AssetServer>())
+                .is_some()
+        );
+        assert_eq!(
+            cache
+                .load(&sources[1], world.resource::<AssetServer>())
+                .id(),
+            cache
+                .load(&sources[1], world.resource::<AssetServer>())
+                .id()
+        );
+        app.update();
+        assert!(app.world().resource::<ReactEventQueue>().drain().is_empty());
+    }
+}
@@ -0,0 +1,332 @@
+//! Named [`UiMaterial`] registry — shader effects on React UI nodes.
+//!
+//! Games register a material type by name on [`ReactMaterialRegistry`] with a
+//! serde props struct and a function writing those props into the material
+//! (usually via [`ReactMaterialAppExt::register_react_material`]).
+//! `<Material name="cooldown" props={{ progress: 0.4 }} />` inserts a
+//! [`MaterialNode`] when created; later `props` are written into the same
+//! material asset instead of allocating a new one.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+
+use bevy::prelude::*;
+use bevy::ui_render::prelude::{MaterialNode, UiMaterial, UiMaterialPlugin};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+type MaterialApplyFn = Arc<dyn Fn(Entity, &Value, &mut World) -> Result<(), String> + Send + Sync>;
+type MaterialRemoveFn = Arc<dyn Fn(Entity, &mut World) + Send + Sync>;
+
+#[derive(Clone)]
+struct MaterialEntry {
+    apply: MaterialApplyFn,
+    remove: MaterialRemoveFn,
+}
+
+/// Registry of [`UiMaterial`] types that React can attach via `<Material name>`.
+#[derive(Resource, Clone, Default)]
+pub struct ReactMaterialRegistry {
+    entries: Arc<Mutex<HashMap<String, MaterialEntry>>>,
+}
+
+impl ReactMaterialRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `M` as `name`. Each node starts from `M::default()`; `update`
+    /// writes deserialized `props` into it on create and on every change.
+    ///
+    /// `Assets<M>` must exist: add [`UiMaterialPlugin<M>`] (or use
+    /// [`ReactMaterialAppExt::register_react_material`]).
+    pub fn register<M, P>(
+        &self,
+        name: impl Into<String>,
+        update: impl Fn(&P, &mut M) + Send + Sync + 'static,
+    ) -> &Self
+    where
+        M: UiMaterial + Default,
+        P: DeserializeOwned + 'static,
+    {
+        self.register_with(name, |_| M::default(), update)
+    }
+
+    /// Like [`register`](Self::register), with `init` building each node's
+    /// material (e.g. to load textures).
+    pub fn register_with<M, P>(
+        &self,
+        name: impl Into<String>,
+        init: impl Fn(&AssetServer) -> M + Send + Sync + 'static,
+        update: impl Fn(&P, &mut M) + Send + Sync + 'static,
+    ) -> &Self
+    where
+        M: UiMaterial,
+        P: DeserializeOwned + 'static,
+    {
+        let apply = move |entity: Entity, props: &Value, world: &mut World| {
+            let props: P = serde_json::from_value(props.clone()).map_err(|e| e.to_string())?;
+            let existing = world.get::<MaterialNode<M>>(entity).map(|node| node.id());
+            let asset_server = world.get_resource::<AssetServer>().cloned();
+            let Some(mut materials) = world.get_resource_mut::<Assets<M>>() else {
+                return Err(format!(
+                    "Assets<{}> missing; add UiMaterialPlugin",
+                    M::short_type_path()
+                ));
+            };
+            if let Some(material) = existing.and_then(|id| materials.get_mut(id)) {
+                update(&props, material);
+                return Ok(());
+            }
+            let asset_server = asset_server.ok_or("AssetServer missing")?;
+            let mut material = init(&asset_server);
+            update(&props, &mut material);
+            let handle = materials.add(material);
+            world.entity_mut(entity).insert(MaterialNode(handle));
+            Ok(())
+        };
+        let remove = |entity: Entity, world: &mut World| {
+            if let Ok(mut entity_mut) = world.get_entity_mut(entity) {
+                entity_mut.remove::<MaterialNode<M>>();
+            }
+        };
+        if let Ok(mut map) = self.entries.lock() {
+            map.insert(
+                name.into(),
+                MaterialEntry {
+                    apply: Arc::new(apply),
+                    remove: Arc::new(remove),
+                },
+            );
+        }
+        self
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.entries.lock().is_ok_and(|map| map.contains_key(name))
+    }
+
+    fn entry(&self, name: &str) -> Option<MaterialEntry> {
+        self.entries.lock().ok()?.get(name).cloned()
+    }
+}
+
+/// Marks a `bevy-material` host node.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct ReactMaterialHost;
+
+/// The `name` and `props` a `<material>` node was last applied with.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct ReactMaterial {
+    pub name: String,
+    pub props: Value,
+}
+
+/// Parse `name` / `props` from React props JSON (`props` defaults to `{}`).
+pub fn parse_react_material(props: &Value) -> Option<ReactMaterial> {
+    let name = props.get("name")?.as_str()?.to_string();
+    let props = match props.get("props") {
+        Some(Value::Null) | None => Value::Object(Default::default()),
+        Some(props) => props.clone(),
+    };
+    Some(ReactMaterial { name, props })
+}
+
+/// Apply a `<material>`'s props (create / update paths): swap the material when
+/// `name` changes, otherwise write changed `props` into the existing one.
+pub(crate) fn sync_react_material(commands: &mut Commands, entity: Entity, props: &Value) {
+    let material = parse_react_material(props);
+    commands.queue(move |world: &mut World| {
+        let Ok(entity_ref) = world.get_entity(entity) else {
+            return;
+        };
+        let current = entity_ref.get::<ReactMaterial>().cloned();
+        if current == material {
+            return;
+        }
+        let registry = world
+            .get_resource::<ReactMaterialRegistry>()
+            .cloned()
+            .unwrap_or_default();
+        if let Some(current) = current
+            && material.as_ref().is_none_or(|m| m.name != current.name)
+            && let Some(entry) = registry.entry(&current.name)
+        {
+            (entry.remove)(entity, world);
+        }
+        let Some(material) = material else {
+            world.entity_mut(entity).remove::<ReactMaterial>();
+            return;
+        };
+        match registry.entry(&material.name) {
+            Some(entry) => {
+                if let Err(err) = (entry.apply)(entity, &material.props, world) {
+                    log::warn!("Invalid props for React material {}: {err}", material.name);
+                }
+            }
+            None => log::warn!("Unknown React material name: {}", material.name),
+        }
+        world.entity_mut(entity).insert(material);
+    });
+}
+
+/// [`App`] helpers for React materials.
+///
+/// ```ignore
+/// #[derive(Deserialize)]
+/// struct CooldownProps { progress: f32 }
+///
+/// app.register_react_material("cooldown", |props: &CooldownProps, m: &mut CooldownMaterial| {
+///     m.progress = props.progress;
+/// });
+/// ```
+pub trait ReactMaterialAppExt {
+    /// Add [`UiMaterialPlugin<M>`] (once) and register `M` as `name`
+    /// (see [`ReactMaterialRegistry::register`]).
+    fn register_react_material<M, P>(
+        &mut self,
+        name: &str,
+        update: impl Fn(&P, &mut M) + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        M: UiMaterial + Default,
+        M::Data: PartialEq + Eq + Hash + Clone,
+        P: DeserializeOwned + 'static;
+}
+
+impl ReactMaterialAppExt for App {
+    fn register_react_material<M, P>(
+        &mut self,
+        name: &str,
+        update: impl Fn(&P, &mut M) + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        M: UiMaterial + Default,
+        M::Data: PartialEq + Eq + Hash + Clone,
+        P: DeserializeOwned + 'static,
+    {
+        if !self.is_plugin_added::<UiMaterialPlugin<M>>() {
+            self.add_plugins(UiMaterialPlugin::<M>::default());
+        }
+        self.init_resource::<ReactMaterialRegistry>();
+        self.world()
+            .resource::<ReactMaterialRegistry>()
+            .register(name, update);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::render::render_resource::AsBindGroup;
+    use bevy::shader::ShaderRef;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use super::*;
+    use crate::react::client::ReactClient;
+    use crate::react::message_tests::{ROOT_ID, context_nodes, flush, setup_app};
+
+    #[derive(Asset, TypePath, AsBindGroup, Clone, Default)]
+    struct CooldownMaterial {
+        #[uniform(0)]
+        progress: f32,
+    }
+
+    impl UiMaterial for CooldownMaterial {
+        fn fragment_shader() -> ShaderRef {
+            "shaders/cooldown.wgsl".into()
+        }
+    }
+
+    #[derive(Deserialize)]
+    struct CooldownProps {
+        progress: f32,
+    }
+
+    fn setup_material_app() -> (App, ReactClient) {
+        let (mut app, client) = setup_app();
+        let registry = ReactMaterialRegistry::new();
+        registry.register(
+            "cooldown",
+            |props: &CooldownProps, m: &mut CooldownMaterial| {
+                m.progress = props.progress;
+            },
+        );
+        app.init_asset::<CooldownMaterial>()
+            .insert_resource(registry);
+        (app, client)
+    }
+
+    fn progress(app: &App, entity: Entity) -> Option<f32> {
+        let node = app.world().get::<MaterialNode<CooldownMaterial>>(entity)?;
+        let materials = app.world().resource::<Assets<CooldownMaterial>>();
+        materials.get(&node.0).map(|m| m.progress)
+    }
+
+    #[test]
+    fn test_parse_react_material() {
+        let material =
+            parse_react_material(&json!({"name": "cooldown", "props": {"progress": 0.5}})).unwrap();
+        assert_eq!(material.name, "cooldown");
+        assert_eq!(material.props["progress"], 0.5);
+        let bare = parse_react_material(&json!({"name": "glow"})).unwrap();
+        assert!(bare.props.as_object().unwrap().is_empty());
+        assert!(parse_react_material(&json!({"props": {}})).is_none());
+    }
+
+    #[test]
+    fn test_material_props_update_uniforms_in_place() {
+        let (mut app, client) = setup_material_app();
+        let node_id = client.create_node(
+            ROOT_ID.to_string(),
+            "bevy-material".into(),
+            r#"{"name":"cooldown","props":{"progress":0.4}}"#.into(),
+        );
+        flush(&mut app);
+        let entity = context_nodes(&app)[&node_id];
+        assert_eq!(progress(&app, entity), Some(0.4));
+        let handle = app
+            .world()
+            .get::<MaterialNode<CooldownMaterial>>(entity)
+            .unwrap()
+            .0
+            .clone();
+
+        client.update_node(
+            ROOT_ID.to_string(),
+            node_id,
+            r#"{"name":"cooldown","props":{"progress":0.9}}"#.into(),
+        );
+        flush(&mut app);
+        assert_eq!(progress(&app, entity), Some(0.9));
+        let node = app.world().get::<MaterialNode<CooldownMaterial>>(entity);
+        assert_eq!(node.map(|node| &node.0), Some(&handle));
+        assert_eq!(app.world().resource::<Assets<CooldownMaterial>>().len(), 1);
+
+        // Props that do not deserialize leave the uniforms alone.
+        client.update_node(
+            ROOT_ID.to_string(),
+            node_id,
+            r#"{"name":"cooldown","props":{"progress":"full"}}"#.into(),
+        );
+        flush(&mut app);
+        assert_eq!(progress(&app, entity), Some(0.9));
+
+        // Switching to an unregistered name drops the old material.
+        client.update_node(
+            ROOT_ID.to_string(),
+            node_id,
+            r#"{"name":"dissolve","props":{}}"#.into(),
+        );
+        flush(&mut app);
+        assert!(progress(&app, entity).is_none());
+        assert_eq!(
+            app.world()
+                .get::<ReactMaterial>(entity)
+                .map(|m| m.name.as_str()),
+            Some("dissolve")
+        );
+    }
+}
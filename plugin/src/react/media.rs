@@ -0,0 +1,478 @@
+//! Media queries — `media` blocks in style props, evaluated on the host.
+//!
+//! ```json
+//! { "padding": 24, "media": { "(max-width: 1280px)": { "padding": 12 } } }
+//! ```
+//!
+//! Queries are matched against the node's render target (window, camera
+//! viewport or texture), falling back to the primary window's
+//! [`ReactViewport`]. Matching blocks cascade over the style in source order.
+//! Nodes are restyled by [`apply_composed_styles`](crate::react::apply_composed_styles)
+//! when a breakpoint flips, without a React render.
+
+use bevy::prelude::*;
+use bevy::ui::ComputedUiRenderTargetInfo;
+use bevy::window::PrimaryWindow;
+use serde::de::{MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
+
+use crate::react::ReactBridge;
+use crate::react::style::{StyleProps, cascade_style_props};
+
+/// Bridge channel carrying [`ReactViewport`] (`useViewport()`).
+pub const VIEWPORT_CHANNEL: &str = "viewport";
+
+/// Logical size and scale factor that media queries are evaluated against.
+///
+/// As a resource it tracks the primary window and is published on the
+/// [`VIEWPORT_CHANNEL`] bridge channel.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReactViewport {
+    pub width: f32,
+    pub height: f32,
+    pub scale_factor: f32,
+}
+
+impl Default for ReactViewport {
+    fn default() -> Self {
+        Self::new(1280.0, 720.0, 1.0)
+    }
+}
+
+impl ReactViewport {
+    pub fn new(width: f32, height: f32, scale_factor: f32) -> Self {
+        Self {
+            width,
+            height,
+            scale_factor,
+        }
+    }
+
+    /// Viewport of a UI node's render target, `None` until Bevy has computed it.
+    pub fn from_target(target: &ComputedUiRenderTargetInfo) -> Option<Self> {
+        if target.physical_size() == UVec2::ZERO {
+            return None;
+        }
+        let size = target.logical_size();
+        Some(Self::new(size.x, size.y, target.scale_factor()))
+    }
+
+    pub fn aspect_ratio(&self) -> f32 {
+        if self.height > 0.0 {
+            self.width / self.height
+        } else {
+            0.0
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Comparison {
+    Min,
+    Max,
+    Less,
+    Greater,
+    Equal,
+}
+
+impl Comparison {
+    fn test(self, actual: f32, expected: f32) -> bool {
+        match self {
+            Self::Min => actual >= expected,
+            Self::Max => actual <= expected,
+            Self::Less => actual < expected,
+            Self::Greater => actual > expected,
+            Self::Equal => (actual - expected).abs() < 0.01,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum MediaFeature {
+    Width(Comparison, f32),
+    Height(Comparison, f32),
+    AspectRatio(Comparison, f32),
+    /// Device pixels per CSS pixel (`dppx`).
+    Resolution(Comparison, f32),
+    Landscape(bool),
+}
+
+impl MediaFeature {
+    fn matches(&self, viewport: &ReactViewport) -> bool {
+        match *self {
+            Self::Width(cmp, px) => cmp.test(viewport.width, px),
+            Self::Height(cmp, px) => cmp.test(viewport.height, px),
+            Self::AspectRatio(cmp, ratio) => cmp.test(viewport.aspect_ratio(), ratio),
+            Self::Resolution(cmp, dppx) => cmp.test(viewport.scale_factor, dppx),
+            Self::Landscape(landscape) => (viewport.width >= viewport.height) == landscape,
+        }
+    }
+}
+
+/// One query of a comma-separated list: `[not] [all|screen] [and (feature)]*`.
+#[derive(Debug, Clone, PartialEq)]
+struct MediaCondition {
+    negated: bool,
+    /// Media types other than `all` / `screen` never match.
+    never: bool,
+    features: Vec<MediaFeature>,
+}
+
+impl MediaCondition {
+    fn matches(&self, viewport: &ReactViewport) -> bool {
+        let matched = !self.never && self.features.iter().all(|f| f.matches(viewport));
+        matched != self.negated
+    }
+}
+
+/// A parsed CSS media query list, e.g. `"(max-width: 1280px) and (orientation: landscape)"`.
+///
+/// Supports `width` / `height` / `aspect-ratio` / `resolution` with `min-` /
+/// `max-` prefixes or range syntax (`(width >= 1280px)`), `orientation`, the
+/// `all` / `screen` media types, `not`, and comma lists (any query matches).
+#[derive(Debug, Clone, PartialEq)]
+pub struct MediaQuery {
+    source: String,
+    conditions: Vec<MediaCondition>,
+}
+
+impl MediaQuery {
+    pub fn parse(source: &str) -> Option<Self> {
+        let conditions = source
+            .split(',')
+            .map(parse_condition)
+            .collect::<Option<Vec<_>>>()?;
+        Some(Self {
+            source: source.trim().to_string(),
+            conditions,
+        })
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn matches(&self, viewport: &ReactViewport) -> bool {
+        self.conditions.iter().any(|c| c.matches(viewport))
+    }
+}
+
+fn parse_condition(query: &str) -> Option<MediaCondition> {
+    let mut rest = query.trim();
+    let mut condition = MediaCondition {
+        negated: false,
+        never: false,
+        features: Vec::new(),
+    };
+    if let Some(after) = strip_keyword(rest, "not") {
+        condition.negated = true;
+        rest = after;
+    } else if let Some(after) = strip_keyword(rest, "only") {
+        rest = after;
+    }
+
+    let mut expect_feature = rest.starts_with('(');
+    if !expect_feature {
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        match rest[..end].to_ascii_lowercase().as_str() {
+            "all" | "screen" => {}
+            "print" | "speech" => condition.never = true,
+            _ => return None,
+        }
+        rest = rest[end..].trim_start();
+    }
+
+    while !rest.is_empty() {
+        if !expect_feature {
+            rest = strip_keyword(rest, "and")?;
+        }
+        let inner = rest.strip_prefix('(')?;
+        let end = inner.find(')')?;
+        condition.features.push(parse_feature(&inner[..end])?);
+        rest = inner[end + 1..].trim_start();
+        expect_feature = false;
+    }
+    Some(condition)
+}
+
+fn strip_keyword<'a>(input: &'a str, keyword: &str) -> Option<&'a str> {
+    let head = input.get(..keyword.len())?;
+    let tail = &input[keyword.len()..];
+    (head.eq_ignore_ascii_case(keyword) && tail.starts_with(char::is_whitespace))
+        .then(|| tail.trim_start())
+}
+
+fn parse_feature(feature: &str) -> Option<MediaFeature> {
+    let feature = feature.trim();
+    let (name, cmp, value) = if let Some((name, value)) = feature.split_once(':') {
+        let name = name.trim().to_ascii_lowercase();
+        let (cmp, name) = if let Some(name) = name.strip_prefix("min-") {
+            (Comparison::Min, name.to_string())
+        } else if let Some(name) = name.strip_prefix("max-") {
+            (Comparison::Max, name.to_string())
+        } else {
+            (Comparison::Equal, name)
+        };
+        (name, cmp, value.trim())
+    } else {
+        let (at, op, cmp) = [
+            (">=", Comparison::Min),
+            ("<=", Comparison::Max),
+            (">", Comparison::Greater),
+            ("<", Comparison::Less),
+            ("=", Comparison::Equal),
+        ]
+        .into_iter()
+        .find_map(|(op, cmp)| feature.find(op).map(|at| (at, op, cmp)))?;
+        let name = feature[..at].trim().to_ascii_lowercase();
+        (name, cmp, feature[at + op.len()..].trim())
+    };
+
+    match name.as_str() {
+        "width" => parse_length(value).map(|px| MediaFeature::Width(cmp, px)),
+        "height" => parse_length(value).map(|px| MediaFeature::Height(cmp, px)),
+        "aspect-ratio" => parse_ratio(value).map(|r| MediaFeature::AspectRatio(cmp, r)),
+        "resolution" => parse_resolution(value).map(|r| MediaFeature::Resolution(cmp, r)),
+        "orientation" if cmp == Comparison::Equal => match value.to_ascii_lowercase().as_str() {
+            "landscape" => Some(MediaFeature::Landscape(true)),
+            "portrait" => Some(MediaFeature::Landscape(false)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// `px` or unitless; `em` / `rem` are 16px as in browser media queries.
+fn parse_length(value: &str) -> Option<f32> {
+    let value = value.trim().to_ascii_lowercase();
+    if let Some(n) = value
+        .strip_suffix("rem")
+        .or_else(|| value.strip_suffix("em"))
+    {
+        return n.trim().parse::<f32>().ok().map(|n| n * 16.0);
+    }
+    value
+        .strip_suffix("px")
+        .unwrap_or(&value)
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// `16/9` or a plain number.
+fn parse_ratio(value: &str) -> Option<f32> {
+    match value.split_once('/') {
+        Some((w, h)) => {
+            let (w, h): (f32, f32) = (w.trim().parse().ok()?, h.trim().parse().ok()?);
+            (h > 0.0).then(|| w / h)
+        }
+        None => value.trim().parse().ok(),
+    }
+}
+
+/// `2dppx`, `2x` or `192dpi` → device pixels per CSS pixel.
+fn parse_resolution(value: &str) -> Option<f32> {
+    let value = value.trim().to_ascii_lowercase();
+    if let Some(n) = value
+        .strip_suffix("dppx")
+        .or_else(|| value.strip_suffix('x'))
+    {
+        return n.trim().parse().ok();
+    }
+    if let Some(n) = value.strip_suffix("dpi") {
+        return n.trim().parse::<f32>().ok().map(|n| n / 96.0);
+    }
+    None
+}
+
+/// The `media` block of a style: query → overrides, kept in source order.
+#[derive(Debug, Clone, Default)]
+pub struct MediaStyles {
+    pub blocks: Vec<(MediaQuery, StyleProps)>,
+}
+
+impl MediaStyles {
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+
+    /// Whether each block matches `viewport`, in order.
+    pub fn matches(&self, viewport: &ReactViewport) -> Vec<bool> {
+        self.blocks
+            .iter()
+            .map(|(query, _)| query.matches(viewport))
+            .collect()
+    }
+}
+
+impl<'de> Deserialize<'de> for MediaStyles {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct MediaVisitor;
+
+        impl<'de> Visitor<'de> for MediaVisitor {
+            type Value = MediaStyles;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an object of media query → style")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<MediaStyles, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut blocks = Vec::new();
+                while let Some((source, style)) = map.next_entry::<String, StyleProps>()? {
+                    match MediaQuery::parse(&source) {
+                        Some(query) => blocks.push((query, style)),
+                        None => log::warn!("Unsupported media query '{}'", source),
+                    }
+                }
+                Ok(MediaStyles { blocks })
+            }
+        }
+
+        deserializer.deserialize_map(MediaVisitor)
+    }
+}
+
+/// Cascade the blocks of `style.media` that match `viewport` over `style`.
+/// The result has no `media` left.
+pub fn resolve_media_styles(style: &StyleProps, viewport: &ReactViewport) -> StyleProps {
+    let mut resolved = style.clone();
+    resolved.media = MediaStyles::default();
+    for (query, block) in &style.media.blocks {
+        if query.matches(viewport) {
+            resolved = cascade_style_props(&resolved, block);
+        }
+    }
+    resolved
+}
+
+/// Keep [`ReactViewport`] in sync with the primary window and publish it to
+/// React when it changes (resize, scale factor, moving between monitors).
+pub fn update_react_viewport(
+    mut viewport: ResMut<ReactViewport>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    bridge: Res<ReactBridge>,
+) {
+    if let Ok(window) = windows.single() {
+        viewport.set_if_neq(ReactViewport::new(
+            window.width(),
+            window.height(),
+            window.scale_factor(),
+        ));
+    }
+    if viewport.is_changed() {
+        bridge.publish(VIEWPORT_CHANNEL, *viewport);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(query: &str, viewport: ReactViewport) -> bool {
+        MediaQuery::parse(query)
+            .unwrap_or_else(|| panic!("failed to parse '{query}'"))
+            .matches(&viewport)
+    }
+
+    #[test]
+    fn test_media_queries_match_size_ratio_and_resolution() {
+        let hd = ReactViewport::new(1280.0, 720.0, 1.0);
+        let deck = ReactViewport::new(1280.0, 800.0, 1.0);
+        let ultrawide = ReactViewport::new(3440.0, 1440.0, 2.0);
+
+        assert!(matches("(max-width: 1280px)", hd));
+        assert!(!matches("(max-width: 1280px)", ultrawide));
+        assert!(matches("(width >= 1920px)", ultrawide));
+        assert!(!matches("(width > 1280px)", hd));
+        assert!(matches("screen and (min-aspect-ratio: 21/9)", ultrawide));
+        assert!(!matches("(min-aspect-ratio: 16/9)", deck));
+        assert!(matches("(aspect-ratio: 16/10)", deck));
+        assert!(matches("(min-resolution: 2dppx)", ultrawide));
+        assert!(matches("(resolution < 192dpi)", hd));
+        assert!(matches("(orientation: landscape)", hd));
+        assert!(matches("(max-height: 600px), (max-width: 80em)", hd));
+        assert!(matches("not (min-width: 1920px)", deck));
+        assert!(!matches("print", hd));
+
+        assert!(MediaQuery::parse("(hover: hover)").is_none());
+        assert!(MediaQuery::parse("(max-width: wide)").is_none());
+        assert!(MediaQuery::parse("tv and (min-width: 1px)").is_none());
+    }
+
+    #[test]
+    fn test_media_blocks_cascade_in_source_order() {
+        let mut style: StyleProps = serde_json::from_str(
+            r#"{
+                "padding": 24,
+                "width": 400,
+                "media": {
+                    "(max-width: 1280px)": { "padding": 12, "width": 300 },
+                    "(max-width: 800px)": { "width": 200 }
+                }
+            }"#,
+        )
+        .unwrap();
+        style.finalize_parse();
+        assert_eq!(style.media.blocks.len(), 2);
+
+        let narrow = resolve_media_styles(&style, &ReactViewport::new(640.0, 480.0, 1.0));
+        assert_eq!(narrow.padding.as_ref().unwrap().0, "12px");
+        assert_eq!(narrow.width.as_ref().unwrap().0, "200px");
+        assert!(narrow.media.is_empty());
+
+        let wide = resolve_media_styles(&style, &ReactViewport::new(1920.0, 1080.0, 1.0));
+        assert_eq!(wide.padding.as_ref().unwrap().0, "24px");
+        assert_eq!(wide.width.as_ref().unwrap().0, "400px");
+    }
+
+    #[test]
+    fn test_media_blocks_keep_source_order_through_value() {
+        // Source order differs from key order: the 1280px block comes last and wins.
+        const STYLE: &str = r#"{
+            "width": 400,
+            "media": {
+                "(max-width: 800px)": { "width": 200 },
+                "(max-width: 1280px)": { "width": 300 }
+            }
+        }"#;
+        let narrow = ReactViewport::new(640.0, 480.0, 1.0);
+        let width = |style: &StyleProps| {
+            let resolved = resolve_media_styles(style, &narrow);
+            resolved.width.unwrap().0
+        };
+
+        let mut parsed: StyleProps = serde_json::from_str(STYLE).unwrap();
+        parsed.finalize_parse();
+        assert_eq!(width(&parsed), "300px");
+
+        let value: serde_json::Value = serde_json::from_str(STYLE).unwrap();
+        let mut from_value: StyleProps = serde_json::from_value(value).unwrap();
+        from_value.finalize_parse();
+        assert_eq!(width(&from_value), "300px");
+
+        let mut sheet = crate::react::ReactStyleSheet::default();
+        sheet.insert_json("card", STYLE).unwrap();
+        assert_eq!(width(sheet.get("card").unwrap().props()), "300px");
+        sheet.insert(
+            "card-value",
+            serde_json::json!({
+                "width": 400,
+                "media": {
+                    "(max-width: 800px)": { "width": 200 },
+                    "(max-width: 1280px)": { "width": 300 }
+                }
+            }),
+        );
+        assert_eq!(width(sheet.get("card-value").unwrap().props()), "300px");
+        assert!(sheet.insert_json("broken", "{").is_err());
+        assert!(!sheet.contains("broken"));
+    }
+}
@@ -1,4 +1,7 @@
 //! Epic A verification: drive `ReactClientProto` through a headless Bevy app.
+//!
+//! [`setup_app`], [`flush`] and [`context_nodes`] are shared with other
+//! modules' tests that need a root fed by a [`ReactClient`].
 
 use bevy::prelude::*;
 
@@ -7,9 +10,9 @@ use crate::react::systems::{
     process_react_messages, ReactContext, ReactMessageReceiver, ReactRoot, ReactRootMap,
 };
 
-const ROOT_ID: &str = "epic-a-root";
+pub(crate) const ROOT_ID: &str = "epic-a-root";
 
-fn setup_app() -> (App, ReactClient) {
+pub(crate) fn setup_app() -> (App, ReactClient) {
     let mut app = App::new();
     app.add_plugins((MinimalPlugins, AssetPlugin::default()))
         .init_resource::<ReactRootMap>()
@@ -48,7 +51,7 @@ fn setup_app() -> (App, ReactClient) {
     (app, client)
 }
 
-fn flush(app: &mut App) {
+pub(crate) fn flush(app: &mut App) {
     app.update();
 }
 
@@ -60,7 +63,7 @@ fn root_entity(app: &App) -> Entity {
         .expect("root mapped")
 }
 
-fn context_nodes(app: &App) -> bevy::platform::collections::HashMap<u64, Entity> {
+pub(crate) fn context_nodes(app: &App) -> bevy::platform::collections::HashMap<u64, Entity> {
     app.world()
         .get::<ReactContext>(root_entity(app))
         .expect("ReactContext")
@@ -194,3 +197,367 @@ fn destroy_unknown_node_is_noop() {
     flush(&mut app);
     assert!(context_nodes(&app).is_empty());
 }
+
+fn read_messages<M: Message + Clone>(app: &App) -> Vec<M> {
+    let messages = app.world().resource::<Messages<M>>();
+    messages.get_cursor().read(messages).cloned().collect()
+}
+
+#[test]
+fn lifecycle_messages_report_mount_commit_failure_and_unmount() {
+    use crate::react::systems::{
+        FocusedNode, ReactRootCommitted, ReactRootFailed, ReactRootMounted, ReactRootUnmounted,
+        on_react_root_removed,
+    };
+
+    let (mut app, client) = setup_app();
+    app.init_resource::<FocusedNode>()
+        .add_message::<ReactRootMounted>()
+        .add_message::<ReactRootCommitted>()
+        .add_message::<ReactRootFailed>()
+        .add_message::<ReactRootUnmounted>()
+        .add_observer(on_react_root_removed);
+    let root = root_entity(&app);
+
+    client.root_mounted(ROOT_ID.to_string());
+    let node = client.create_node(ROOT_ID.to_string(), "bevy-node".into(), "{}".into());
+    client.append_child(ROOT_ID.to_string(), 0, node);
+    client.commit(ROOT_ID.to_string());
+    client.update_node(
+        ROOT_ID.to_string(),
+        node,
+        r#"{"style":{"width":10}}"#.into(),
+    );
+    client.commit(ROOT_ID.to_string());
+    client.root_failed(ROOT_ID.to_string(), "boom".into());
+    flush(&mut app);
+
+    let mounted = read_messages::<ReactRootMounted>(&app);
+    assert_eq!(mounted.len(), 1);
+    assert_eq!(mounted[0].root, root);
+
+    let commits: Vec<usize> = read_messages::<ReactRootCommitted>(&app)
+        .into_iter()
+        .map(|m| m.ops)
+        .collect();
+    assert_eq!(commits, vec![2, 1], "ops are counted per commit");
+
+    let failed = read_messages::<ReactRootFailed>(&app);
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].error, "boom");
+
+    app.world_mut().entity_mut(root).despawn();
+    flush(&mut app);
+    let unmounted = read_messages::<ReactRootUnmounted>(&app);
+    assert_eq!(unmounted.len(), 1);
+    assert_eq!(unmounted[0].root_id, ROOT_ID);
+}
+
+#[test]
+fn binary_commit_is_attributed_to_frame_root() {
+    use crate::react::proto::{BinaryOp, encode_batch};
+    use crate::react::systems::ReactRootCommitted;
+
+    let (mut app, client) = setup_app();
+    app.add_message::<ReactRootCommitted>();
+
+    let bytes = encode_batch(
+        ROOT_ID,
+        &[
+            BinaryOp::CreateNode {
+                node_id: 9_000_001,
+                node_type: "bevy-node".into(),
+                props_json: "{}".into(),
+            },
+            BinaryOp::AppendChild {
+                parent_id: 0,
+                child_id: 9_000_001,
+            },
+            BinaryOp::Commit,
+        ],
+    )
+    .expect("encode");
+    client.commit_binary_ops(&bytes).expect("decode");
+    flush(&mut app);
+
+    let commits = read_messages::<ReactRootCommitted>(&app);
+    assert_eq!(commits.len(), 1);
+    assert_eq!(commits[0].root, root_entity(&app));
+    assert_eq!(commits[0].ops, 2);
+}
+
+#[test]
+fn register_keyframes_populates_registry_and_skips_invalid_json() {
+    let (mut app, client) = setup_app();
+
+    client.register_keyframes(
+        "pulse".to_string(),
+        r#"{"from":{"opacity":1},"50%":{"opacity":0.4},"to":{"opacity":1}}"#.to_string(),
+    );
+    client.register_keyframes("broken".to_string(), "not json".to_string());
+    flush(&mut app);
+
+    let keyframes = app.world().resource::<crate::react::ReactKeyframes>();
+    assert_eq!(keyframes.get("pulse").map(|k| k.frames().len()), Some(3));
+    assert!(keyframes.get("broken").is_none());
+}
+
+#[test]
+fn theme_variables_resolve_per_scope_and_restyle_on_change() {
+    let (mut app, client) = setup_app();
+    app.init_resource::<crate::react::ReactTheme>().add_systems(
+        Update,
+        crate::react::apply_composed_styles.after(process_react_messages),
+    );
+    app.world_mut()
+        .resource_mut::<crate::react::ReactTheme>()
+        .set("--accent", "#ff0000");
+
+    let scoped_id = client.create_node(
+        ROOT_ID.to_string(),
+        "bevy-node".into(),
+        r##"{"theme":{"--accent":"#0000ff"}}"##.into(),
+    );
+    let inner_id = client.create_node(
+        ROOT_ID.to_string(),
+        "bevy-node".into(),
+        r#"{"style":{"backgroundColor":"var(--accent)","width":"var(--size, 40)"}}"#.into(),
+    );
+    let plain_id = client.create_node(
+        ROOT_ID.to_string(),
+        "bevy-node".into(),
+        r#"{"style":{"backgroundColor":"var(--accent)"}}"#.into(),
+    );
+    client.append_child(ROOT_ID.to_string(), scoped_id, inner_id);
+    client.append_child(ROOT_ID.to_string(), 0, scoped_id);
+    client.append_child(ROOT_ID.to_string(), 0, plain_id);
+    flush(&mut app);
+
+    let nodes = context_nodes(&app);
+    let inner = nodes[&inner_id];
+    let plain = nodes[&plain_id];
+    let background = |app: &App, entity| app.world().get::<BackgroundColor>(entity).map(|c| c.0);
+    assert_eq!(background(&app, inner), Some(Color::srgb(0.0, 0.0, 1.0)));
+    assert_eq!(background(&app, plain), Some(Color::srgb(1.0, 0.0, 0.0)));
+    assert_eq!(app.world().get::<Node>(inner).unwrap().width, Val::Px(40.0));
+    assert_eq!(
+        app.world()
+            .get::<crate::react::ReactComposedStyle>(inner)
+            .unwrap()
+            .vars(),
+        ["accent", "size"]
+    );
+
+    // Global change restyles only nodes that read it, without a React update.
+    app.world_mut()
+        .resource_mut::<crate::react::ReactTheme>()
+        .set("accent", "#00ff00");
+    flush(&mut app);
+    assert_eq!(background(&app, plain), Some(Color::srgb(0.0, 1.0, 0.0)));
+    assert_eq!(background(&app, inner), Some(Color::srgb(0.0, 0.0, 1.0)));
+
+    // Dropping the subtree scope falls back to the global value.
+    client.update_node(ROOT_ID.to_string(), scoped_id, "{}".into());
+    flush(&mut app);
+    assert_eq!(background(&app, inner), Some(Color::srgb(0.0, 1.0, 0.0)));
+}
+
+#[test]
+fn theme_variables_reach_image_tint_and_svg_current_color() {
+    let (mut app, client) = setup_app();
+    app.init_asset::<Image>()
+        .init_resource::<crate::react::ReactTheme>()
+        .add_systems(
+            Update,
+            crate::react::apply_composed_styles.after(process_react_messages),
+        );
+    app.world_mut()
+        .resource_mut::<crate::react::ReactTheme>()
+        .set("--accent", "#ff0000");
+
+    let image_id = client.create_node(
+        ROOT_ID.to_string(),
+        "bevy-image".into(),
+        r#"{"src":"a.png","style":{"tint":"var(--accent)","objectFit":"fill"}}"#.into(),
+    );
+    let svg_id = client.create_node(
+        ROOT_ID.to_string(),
+        "bevy-svg".into(),
+        r#"{"markup":"<svg/>","style":{"color":"var(--accent)"}}"#.into(),
+    );
+    client.append_child(ROOT_ID.to_string(), 0, image_id);
+    client.append_child(ROOT_ID.to_string(), 0, svg_id);
+    flush(&mut app);
+
+    let nodes = context_nodes(&app);
+    let (image, svg) = (nodes[&image_id], nodes[&svg_id]);
+    let tint = |app: &App| app.world().get::<ImageNode>(image).unwrap().color;
+    let current_color = |app: &App| {
+        app.world()
+            .get::<crate::react::ReactSvg>(svg)
+            .unwrap()
+            .color()
+    };
+    assert_eq!(tint(&app), Color::srgb(1.0, 0.0, 0.0));
+    assert_eq!(
+        app.world().get::<ImageNode>(image).unwrap().image_mode,
+        NodeImageMode::Stretch
+    );
+    assert_eq!(current_color(&app), Some(Color::srgb(1.0, 0.0, 0.0)));
+
+    app.world_mut()
+        .resource_mut::<crate::react::ReactTheme>()
+        .set("accent", "#00ff00");
+    flush(&mut app);
+    assert_eq!(tint(&app), Color::srgb(0.0, 1.0, 0.0));
+    assert_eq!(current_color(&app), Some(Color::srgb(0.0, 1.0, 0.0)));
+
+    // A new source keeps the themed tint.
+    client.update_node(
+        ROOT_ID.to_string(),
+        image_id,
+        r#"{"src":"b.png","style":{"tint":"var(--accent)","objectFit":"fill"}}"#.into(),
+    );
+    flush(&mut app);
+    assert_eq!(tint(&app), Color::srgb(0.0, 1.0, 0.0));
+    assert_eq!(
+        app.world().get::<ImageNode>(image).unwrap().image_mode,
+        NodeImageMode::Stretch
+    );
+}
+
+#[test]
+fn style_classes_cascade_under_inline_style_and_restyle_on_reregister() {
+    let (mut app, client) = setup_app();
+    app.add_systems(
+        Update,
+        crate::react::apply_composed_styles.after(process_react_messages),
+    );
+
+    client.register_style(
+        "card".to_string(),
+        r##"{"width":100,"backgroundColor":"#ff0000","hover":{"width":120}}"##.to_string(),
+    );
+    client.register_style(
+        "wide".to_string(),
+        r#"{"width":200,"hover":{"height":10}}"#.to_string(),
+    );
+    let node_id = client.create_node(
+        ROOT_ID.to_string(),
+        "bevy-node".into(),
+        r#"{"className":"card wide","style":{"height":30}}"#.into(),
+    );
+    client.append_child(ROOT_ID.to_string(), 0, node_id);
+    flush(&mut app);
+
+    let entity = context_nodes(&app)[&node_id];
+    let node = app.world().get::<Node>(entity).unwrap();
+    assert_eq!(node.width, Val::Px(200.0), "later class wins");
+    assert_eq!(node.height, Val::Px(30.0), "inline style wins");
+    let background = app.world().get::<BackgroundColor>(entity).map(|c| c.0);
+    assert_eq!(background, Some(Color::srgb(1.0, 0.0, 0.0)));
+    let hover = app
+        .world()
+        .get::<crate::react::systems::ReactStyleState>(entity)
+        .and_then(|state| state.hover.clone())
+        .expect("hover blocks cascade");
+    assert_eq!(hover.width.map(|v| v.0).as_deref(), Some("120px"));
+    assert_eq!(hover.height.map(|v| v.0).as_deref(), Some("10px"));
+
+    // Re-registering from Rust restyles without a React update.
+    app.world_mut()
+        .resource_mut::<crate::react::ReactStyleSheet>()
+        .insert("card", serde_json::json!({ "backgroundColor": "#0000ff" }));
+    flush(&mut app);
+    let background = app.world().get::<BackgroundColor>(entity).map(|c| c.0);
+    assert_eq!(background, Some(Color::srgb(0.0, 0.0, 1.0)));
+}
+
+#[test]
+fn style_classes_reach_image_tint_and_object_fit() {
+    let (mut app, client) = setup_app();
+    app.init_asset::<Image>().add_systems(
+        Update,
+        crate::react::apply_composed_styles.after(process_react_messages),
+    );
+
+    client.register_style(
+        "thumb".to_string(),
+        r##"{"tint":"#ff0000","objectFit":"fill"}"##.to_string(),
+    );
+    let node_id = client.create_node(
+        ROOT_ID.to_string(),
+        "bevy-image".into(),
+        r#"{"src":"a.png","className":"thumb"}"#.into(),
+    );
+    client.append_child(ROOT_ID.to_string(), 0, node_id);
+    flush(&mut app);
+
+    let entity = context_nodes(&app)[&node_id];
+    assert!(
+        app.world()
+            .get::<crate::react::ReactComposedStyle>(entity)
+            .is_some()
+    );
+    let image = |app: &App| app.world().get::<ImageNode>(entity).unwrap().clone();
+    assert_eq!(image(&app).color, Color::srgb(1.0, 0.0, 0.0));
+    assert_eq!(image(&app).image_mode, NodeImageMode::Stretch);
+
+    app.world_mut()
+        .resource_mut::<crate::react::ReactStyleSheet>()
+        .insert("thumb", serde_json::json!({ "tint": "#0000ff" }));
+    flush(&mut app);
+    assert_eq!(image(&app).color, Color::srgb(0.0, 0.0, 1.0));
+    assert_eq!(image(&app).image_mode, NodeImageMode::Auto);
+}
+
+#[test]
+fn media_blocks_follow_viewport_without_react_updates() {
+    use crate::react::ReactViewport;
+
+    let (mut app, client) = setup_app();
+    app.insert_resource(ReactViewport::new(1920.0, 1080.0, 1.0));
+    app.add_systems(
+        Update,
+        crate::react::apply_composed_styles.after(process_react_messages),
+    );
+
+    client.register_style(
+        "panel".to_string(),
+        r#"{"width":400,"media":{"(max-width: 1280px)":{"width":300}}}"#.to_string(),
+    );
+    let node_id = client.create_node(
+        ROOT_ID.to_string(),
+        "bevy-node".into(),
+        r#"{"className":"panel","style":{"height":50,"media":{"(max-aspect-ratio: 16/10)":{"height":80}}}}"#
+            .into(),
+    );
+    // Blocks cascade in source order, which differs from their key order here.
+    let ordered_id = client.create_node(
+        ROOT_ID.to_string(),
+        "bevy-node".into(),
+        r#"{"style":{"width":"var(--w, 400)","media":{"(max-width: 800px)":{"width":200},"(max-width: 1280px)":{"width":300}}}}"#
+            .into(),
+    );
+    client.append_child(ROOT_ID.to_string(), 0, node_id);
+    client.append_child(ROOT_ID.to_string(), 0, ordered_id);
+    flush(&mut app);
+
+    let entity = context_nodes(&app)[&node_id];
+    let node = app.world().get::<Node>(entity).unwrap();
+    assert_eq!(node.width, Val::Px(400.0));
+    assert_eq!(node.height, Val::Px(50.0));
+
+    // Steam Deck: both the class and the inline breakpoint flip.
+    *app.world_mut().resource_mut::<ReactViewport>() = ReactViewport::new(1280.0, 800.0, 1.0);
+    flush(&mut app);
+    let node = app.world().get::<Node>(entity).unwrap();
+    assert_eq!(node.width, Val::Px(300.0));
+    assert_eq!(node.height, Val::Px(80.0));
+
+    *app.world_mut().resource_mut::<ReactViewport>() = ReactViewport::new(640.0, 480.0, 1.0);
+    flush(&mut app);
+    let ordered = context_nodes(&app)[&ordered_id];
+    let node = app.world().get::<Node>(ordered).unwrap();
+    assert_eq!(node.width, Val::Px(300.0));
+}
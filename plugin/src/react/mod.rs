@@ -36,8 +36,13 @@
 //! ```
 pub mod plugin;
 
+mod anchor;
+mod animation;
 mod asset_source;
+mod atlas;
 mod bridge;
+mod calc;
+mod camera_viewport;
 #[cfg(feature = "bridge-codegen")]
 pub mod bridge_codegen;
 mod client;
@@ -46,11 +51,21 @@ pub mod components_registry;
 pub mod devtools;
 mod embedded;
 mod event_queue;
+mod fonts;
+mod grid;
 mod hmr;
+mod images;
+mod materials;
+mod media;
 mod native_functions;
 pub mod proto;
+mod states;
 mod style;
+mod stylesheet;
+mod svg;
 mod systems;
+mod text_measure;
+mod theme;
 mod vite;
 mod widgets;
 mod shim;
@@ -63,7 +78,18 @@ pub use plugin::{ReactDefaultFontPlugin, ReactPlugin};
 pub use devtools::{ReactDevToolsBridge, ReactDevToolsPlugin, DEVTOOLS_WS_ADDR, DEVTOOLS_WS_PORT};
 #[cfg(feature = "egui")]
 pub use devtools::ReactNodeInspectorPlugin;
+pub use anchor::{
+    ReactAnchor, ReactAnchorHidden, anchored_position, project_to_viewport, update_react_anchors,
+};
+pub use animation::{
+    AnimationDirection, AnimationFillMode, AnimationPhase, AnimationSpec, Keyframe, Keyframes,
+    ReactKeyframes, advance_react_animations, parse_animation,
+};
 pub use asset_source::{ReactAssetBundle, ReactAssetSource, ReactJsModule};
+pub use atlas::{
+    ReactAtlasAnimation, ReactAtlasLayoutLoader, ReactImageAtlas, advance_react_atlas_animations,
+    parse_atlas_layout, parse_image_atlas,
+};
 pub use bridge::{
     BridgeCall, BridgeCallResult, BridgeCommandMeta, BridgeCommandSet, ReactBridge,
     flush_react_bridge, process_react_bridge_calls, sync_registered_query_stores,
@@ -75,13 +101,41 @@ pub use bridge_codegen::{
     emit_command_wrappers, emit_object_keys_const, emit_type_decl, sync_bridge_typescript,
     write_bridge_typescript,
 };
+pub use calc::{CalcContext, CalcExpr, CalcUnit, CssLength, ReactRootFontSize, parse_css_length};
+pub use camera_viewport::{
+    ReactCameraViewport, parse_viewport_camera, update_react_camera_viewports,
+};
 pub use client::*;
 pub use components_registry::{
     apply_react_bundles, BundleRegistry, ReactEntityMap,
 };
 pub use embedded::EmbeddedBundleSource;
 pub use event_queue::{FLUSH_EVENTS_SCRIPT, ReactEvent, ReactEventQueue};
+pub use fonts::{
+    FontSource, FontStyle, ReactFontFamily, ReactFontRegistry, load_react_fonts, style_font,
+};
 pub use native_functions::ReactJsExtension;
+pub use states::{ReactAppExt, ReactStateRoot, bevy_state_channel, set_bevy_state_command};
+pub use grid::{
+    GridArea, GridItemLines, GridLine, GridLineNames, ReactGridItem, ReactGridTemplate,
+    parse_grid_placement, parse_grid_template_areas, resolve_grid_placements,
+};
 pub use hmr::{ReactHmrRoot, ReactReloadFlag};
+pub use images::{
+    DataUriError, ImageSrcKind, ReactImageCache, ReactImageListener, ReactRemoteImagePlugin,
+    decode_data_uri, emit_react_image_events, image_src_kind,
+};
+pub use materials::{
+    ReactMaterial, ReactMaterialAppExt, ReactMaterialHost, ReactMaterialRegistry,
+    parse_react_material,
+};
+pub use media::{MediaQuery, ReactViewport, VIEWPORT_CHANNEL, update_react_viewport};
+pub use stylesheet::{ReactComposedStyle, ReactStyleSheet, StyleClass, apply_composed_styles};
+pub use svg::{
+    ReactSvg, ReactSvgDocument, ReactSvgLoader, ReactSvgSource, measure_react_svgs, parse_svg,
+    rasterize_react_svgs, rasterize_svg,
+};
 pub use systems::*;
+pub use text_measure::{MEASURE_TEXT_COMMAND, measure_text};
+pub use theme::ReactTheme;
 pub use vite::*;
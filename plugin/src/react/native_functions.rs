@@ -175,6 +175,98 @@ fn register_react_functions(
         ),
     )?;
 
+    // __react_commit(root_id: string) -> void
+    // End of a React commit on the per-op path (BRRP frames carry their own Commit op).
+    context.register_global_callable(
+        JsString::from("__react_commit"),
+        1,
+        NativeFunction::from_copy_closure_with_captures(
+            move |_this: &JsValue, args: &[JsValue], client: &ReactClient, _ctx: &mut Context| {
+                client.commit(root_id_arg(args));
+                Ok(JsValue::undefined())
+            },
+            react_client.clone(),
+        ),
+    )?;
+
+    // __react_root_mounted(root_id: string) -> void
+    context.register_global_callable(
+        JsString::from("__react_root_mounted"),
+        1,
+        NativeFunction::from_copy_closure_with_captures(
+            move |_this: &JsValue, args: &[JsValue], client: &ReactClient, _ctx: &mut Context| {
+                client.root_mounted(root_id_arg(args));
+                Ok(JsValue::undefined())
+            },
+            react_client.clone(),
+        ),
+    )?;
+
+    // __react_root_failed(root_id: string, message: string) -> void
+    context.register_global_callable(
+        JsString::from("__react_root_failed"),
+        2,
+        NativeFunction::from_copy_closure_with_captures(
+            move |_this: &JsValue, args: &[JsValue], client: &ReactClient, ctx: &mut Context| {
+                let error = args
+                    .get(1)
+                    .map(|v| v.to_string(ctx))
+                    .transpose()?
+                    .map(|s| s.to_std_string_escaped())
+                    .unwrap_or_default();
+                client.root_failed(root_id_arg(args), error);
+                Ok(JsValue::undefined())
+            },
+            react_client.clone(),
+        ),
+    )?;
+
+    // __react_register_keyframes(name: string, keyframes_json: string) -> void
+    context.register_global_callable(
+        JsString::from("__react_register_keyframes"),
+        2,
+        NativeFunction::from_copy_closure_with_captures(
+            move |_this: &JsValue, args: &[JsValue], client: &ReactClient, ctx: &mut Context| {
+                let mut string_arg = |i: usize| -> JsResult<String> {
+                    Ok(args
+                        .get(i)
+                        .map(|v| v.to_string(ctx))
+                        .transpose()?
+                        .map(|s| s.to_std_string_escaped())
+                        .unwrap_or_default())
+                };
+                let name = string_arg(0)?;
+                let keyframes_json = string_arg(1)?;
+                client.register_keyframes(name, keyframes_json);
+                Ok(JsValue::undefined())
+            },
+            react_client.clone(),
+        ),
+    )?;
+
+    // __react_register_style(name: string, style_json: string) -> void
+    context.register_global_callable(
+        JsString::from("__react_register_style"),
+        2,
+        NativeFunction::from_copy_closure_with_captures(
+            move |_this: &JsValue, args: &[JsValue], client: &ReactClient, ctx: &mut Context| {
+                let mut string_arg = |i: usize| -> JsResult<String> {
+                    Ok(args
+                        .get(i)
+                        .map(|v| v.to_string(ctx))
+                        .transpose()?
+                        .map(|s| s.to_std_string_escaped())
+                        .unwrap_or_default())
+                };
+                let name = string_arg(0)?;
+                let style_json = string_arg(1)?;
+                client.register_style(name, style_json);
+                Ok(JsValue::undefined())
+            },
+            react_client.clone(),
+        ),
+    )?;
+
     // __react_register_event_dispatcher(callback) -> void
     // Stores the JS callback on the global object for structured host→JS events.
     context.register_global_callable(
@@ -600,6 +692,14 @@ fn clear_container_fn(
     Ok(JsValue::undefined())
 }
 
+/// First argument as a root id (`"root"` when missing, matching the per-op natives).
+fn root_id_arg(args: &[JsValue]) -> String {
+    args.first()
+        .and_then(|v| v.as_string())
+        .map(|s| s.to_std_string_escaped())
+        .unwrap_or_else(|| "root".to_string())
+}
+
 /// __react_commit_ops(bytes: Uint8Array | ArrayBuffer)
 #[cfg(feature = "binary_ops")]
 fn commit_ops_fn(
@@ -3,20 +3,39 @@
 //! Provides infrastructure for React UI rendering using the js_bevy plugin.
 
 use bevy::prelude::*;
+use bevy::ui::UiSystems;
 
 use crate::js_bevy::{JsClientResource, JsEngineExtensionComponent};
 use crate::react::ReactClient;
+use crate::react::anchor::update_react_anchors;
+use crate::react::animation::{ReactKeyframes, advance_react_animations};
 use crate::react::asset_source::{
     ReactJsModule, ReactJsModuleLoader, reload_modified_react_assets, resolve_react_assets,
 };
+use crate::react::atlas::{ReactAtlasLayoutLoader, advance_react_atlas_animations};
 use crate::react::bridge::{ReactBridge, flush_react_bridge, process_react_bridge_calls};
+use crate::react::calc::ReactRootFontSize;
+use crate::react::camera_viewport::{
+    on_react_camera_viewport_removed, update_react_camera_viewports,
+};
 use crate::react::components_registry::{
     apply_react_bundles, BundleRegistry, ReactEntityMap,
 };
 use crate::react::event_queue::ReactEventQueue;
+use crate::react::fonts::{ReactFontRegistry, load_react_fonts};
+use crate::react::grid::resolve_grid_placements;
 use crate::react::hmr::{ReactReloadFlag, apply_react_hmr_reloads};
+use crate::react::images::{ReactImageCache, emit_react_image_events};
+use crate::react::materials::ReactMaterialRegistry;
+use crate::react::media::{ReactViewport, update_react_viewport};
 use crate::react::native_functions::ReactJsExtension;
+use crate::react::stylesheet::{ReactStyleSheet, apply_composed_styles};
+use crate::react::svg::{
+    ReactSvgDocument, ReactSvgLoader, measure_react_svgs, rasterize_react_svgs,
+};
 use crate::react::systems::*;
+use crate::react::text_measure::register_measure_text;
+use crate::react::theme::ReactTheme;
 use crate::react::widgets::add_widget_plugins;
 
 /// Loads an asset path into [`ReactDefaultFont`] at startup.
@@ -46,6 +65,15 @@ impl Plugin for ReactDefaultFontPlugin {
     }
 }
 
+/// Hosts React roots: the reconciler bridge, style, layout and input systems.
+///
+/// Add it after `DefaultPlugins`. Remote image `src`s also need
+/// [`ReactRemoteImagePlugin`](crate::react::ReactRemoteImagePlugin), added
+/// **before** `DefaultPlugins`:
+///
+/// ```ignore
+/// App::new().add_plugins((ReactRemoteImagePlugin, DefaultPlugins, JsPlugin, ReactPlugin));
+/// ```
 pub struct ReactPlugin;
 
 impl Plugin for ReactPlugin {
@@ -56,18 +84,82 @@ impl Plugin for ReactPlugin {
 
         app.init_asset::<ReactJsModule>()
             .init_asset_loader::<ReactJsModuleLoader>()
+            .init_asset_loader::<ReactAtlasLayoutLoader>()
+            .init_asset::<ReactSvgDocument>()
+            .init_asset_loader::<ReactSvgLoader>()
             .init_resource::<ReactRootMap>()
+            .init_resource::<ReactLoadedModules>()
             .init_resource::<FocusedNode>()
             .init_resource::<ReactEventQueue>()
             .init_resource::<ReactBridge>()
             .init_resource::<ReactReloadFlag>()
             .init_resource::<ReactDefaultFont>()
+            .init_resource::<ReactFontRegistry>()
+            .init_resource::<ReactImageCache>()
+            .init_resource::<ReactMaterialRegistry>()
             .init_resource::<BundleRegistry>()
             .init_resource::<ReactEntityMap>()
+            .init_resource::<ReactKeyframes>()
+            .init_resource::<ReactTheme>()
+            .init_resource::<ReactStyleSheet>()
+            .init_resource::<ReactViewport>()
+            .init_resource::<ReactRootFontSize>()
             .add_message::<RequestReactFocus>()
             .add_message::<RequestReactBlur>()
+            .add_message::<ReactRootMounted>()
+            .add_message::<ReactRootCommitted>()
+            .add_message::<ReactRootFailed>()
+            .add_message::<ReactRootUnmounted>()
             .add_observer(on_react_root_removed)
+            .add_observer(on_react_root_paused)
+            .add_observer(on_react_root_resumed)
+            .add_observer(on_react_texture_target_added)
+            .add_observer(on_react_texture_target_removed)
+            .add_observer(on_react_texture_surface_move)
+            .add_observer(on_react_texture_surface_press)
+            .add_observer(on_react_texture_surface_release)
+            .add_observer(on_react_texture_surface_out)
+            .add_observer(on_react_camera_viewport_removed)
             .add_systems(Startup, register_react_extension)
+            .add_systems(
+                PreUpdate,
+                (apply_react_texture_pointer, apply_react_cursor)
+                    .chain()
+                    .after(UiSystems::Focus),
+            )
+            .add_systems(
+                PostUpdate,
+                (
+                    resolve_calc_lengths,
+                    resolve_grid_placements,
+                    // Anchors own `left` / `top` and `Visibility`: they go after the
+                    // calc lengths here and after the `Update` restyles
+                    // (`apply_composed_styles`, `apply_interaction_styles`).
+                    update_react_anchors.after(resolve_calc_lengths),
+                    apply_react_transforms,
+                    apply_react_opacity,
+                )
+                    .before(UiSystems::Layout),
+            )
+            .add_systems(
+                PostUpdate,
+                (sync_text_spans, inherit_text_span_styles)
+                    .chain()
+                    .before(apply_react_opacity)
+                    .before(UiSystems::Content),
+            )
+            .add_systems(
+                PostUpdate,
+                (
+                    truncate_overflowing_text.after(UiSystems::PostLayout),
+                    emit_react_layout_events.after(UiSystems::Layout),
+                    measure_react_svgs
+                        .after(UiSystems::Content)
+                        .before(UiSystems::Layout),
+                    rasterize_react_svgs.after(UiSystems::Layout),
+                    update_react_camera_viewports.after(UiSystems::Layout),
+                ),
+            )
             .add_systems(
                 Update,
                 (
@@ -81,8 +173,11 @@ impl Plugin for ReactPlugin {
             .add_systems(
                 Update,
                 (
+                    load_react_fonts,
                     process_react_messages,
                     ApplyDeferred,
+                    update_react_viewport,
+                    apply_composed_styles,
                     apply_react_bundles,
                     process_react_bridge_calls,
                     handle_input_interactions,
@@ -90,6 +185,8 @@ impl Plugin for ReactPlugin {
                     handle_click_outside_blur,
                     handle_wheel_scroll,
                     apply_focus_requests,
+                    advance_react_animations,
+                    advance_react_atlas_animations,
                     apply_interaction_styles,
                     handle_keyboard_input,
                     flush_react_events,
@@ -97,7 +194,11 @@ impl Plugin for ReactPlugin {
                     inspect,
                 )
                     .chain(),
-            );
+            )
+            .add_systems(Update, emit_react_image_events.before(flush_react_events));
+
+        register_measure_text(app.world().resource::<ReactBridge>());
+        register_measure_node(app.world().resource::<ReactBridge>());
 
         #[cfg(feature = "devtools")]
         {
@@ -134,45 +235,99 @@ fn register_react_extension(
     commands.insert_resource(ReactMessageReceiver(receiver));
 }
 
+#[allow(clippy::type_complexity)]
 fn execute_react_scripts(
     mut commands: Commands,
     mut scripts: Query<
-        (Entity, &ReactRoot, &ReactScriptSource, Mut<ReactContext>),
+        (
+            Entity,
+            &ReactRoot,
+            &ReactScriptSource,
+            Option<&ReactRootExport>,
+            Mut<ReactContext>,
+        ),
         With<ReactDirtyFlag>,
     >,
     js_client: Option<Res<JsClientResource>>,
     mut root_map: ResMut<ReactRootMap>,
+    mut loaded: ResMut<ReactLoadedModules>,
 ) {
     let Some(js_client) = js_client else {
         return;
     };
 
-    for (entity, root, script, mut context) in scripts.iter_mut() {
-        js_client.load_esm_module(&script.module_name, &script.source_string);
-        js_client.execute(format!(
-            r#"
-                (async () => {{
-                    try {{
-                        const mod = await import('{module}');
-                        
-                        if (!mod.default) {{
-                            console.warn('Module does not have a default export', mod);
-                            throw new Error('Module does not have a default export');
-                        }}
-
-                        mod.default.render('{root_id}');
-                    }} catch (err) {{
-                        console.error("Failed to load Bevy React app:", err);
-                        if (err.stack) console.error(err.stack);
-                    }}
-                }})()
-            "#,
-            module = &script.module_name,
-            root_id = root.id,
-        ));
+    for (entity, root, script, export, mut context) in scripts.iter_mut() {
+        // Roots sharing a bundle evaluate it once; later roots only import it.
+        if loaded.mark_loaded(script) {
+            js_client.load_esm_module(&script.module_name, &script.source_string);
+        }
+        let export = export.cloned().unwrap_or_default();
+        js_client.execute(root_render_script(&script.module_name, &export, &root.id));
 
         context.root = Some(entity);
         root_map.roots.insert(root.id.clone(), entity);
         commands.entity(entity).remove::<ReactDirtyFlag>();
     }
 }
+
+/// Import `module` and call `render(root_id)` on the root's export.
+fn root_render_script(module: &str, export: &ReactRootExport, root_id: &str) -> String {
+    format!(
+        r#"
+            (async () => {{
+                try {{
+                    const mod = await import('{module}');
+                    const app = mod{accessor};
+
+                    if (!app || typeof app.render !== 'function') {{
+                        console.warn('Module export "{export}" is not a Bevy React app', mod);
+                        throw new Error('Module export "{export}" is not a Bevy React app');
+                    }}
+
+                    app.render('{root_id}');
+                    __react_root_mounted('{root_id}');
+                }} catch (err) {{
+                    console.error("Failed to load Bevy React app:", err);
+                    if (err.stack) console.error(err.stack);
+                    __react_root_failed('{root_id}', String(err));
+                }}
+            }})()
+        "#,
+        accessor = export.js_accessor(),
+        export = export.name().escape_default(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_script_uses_named_export() {
+        let script = root_render_script("app", &ReactRootExport::new("PauseMenu"), "root-1");
+        assert!(script.contains(r#"const app = mod["PauseMenu"];"#));
+        assert!(script.contains("app.render('root-1')"));
+    }
+
+    #[test]
+    fn test_render_script_defaults_to_default_export() {
+        let script = root_render_script("app", &ReactRootExport::default(), "root-1");
+        assert!(script.contains(r#"const app = mod["default"];"#));
+    }
+
+    #[test]
+    fn test_shared_module_is_loaded_once() {
+        let mut loaded = ReactLoadedModules::default();
+        let source = ReactScriptSource::from_string("screens", "export const A = {};");
+
+        assert!(loaded.mark_loaded(&source));
+        assert!(!loaded.mark_loaded(&source.clone()));
+        assert!(loaded.is_loaded("screens"));
+
+        let changed = ReactScriptSource::from_string("screens", "export const B = {};");
+        assert!(loaded.mark_loaded(&changed));
+
+        loaded.clear();
+        assert!(loaded.mark_loaded(&changed));
+    }
+}
@@ -41,6 +41,7 @@ const OP_UPDATE_TEXT: u8 = 0x07;
 const OP_DESTROY_NODE: u8 = 0x08;
 const OP_CLEAR_CONTAINER: u8 = 0x09;
 const OP_COMMIT: u8 = 0x0A;
+const OP_REGISTER_STYLE: u8 = 0x0B;
 
 /// One mutation inside a batch. `root_id` lives on the frame, not per op.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -81,6 +82,11 @@ pub enum BinaryOp {
     ClearContainer,
     /// Frame-aligned commit marker (maps to [`ReactClientProto::Complete`]).
     Commit,
+    /// Register a style class before the nodes that reference it (not root-scoped).
+    RegisterStyle {
+        name: String,
+        style_json: String,
+    },
 }
 
 /// Encoding failure (currently only empty root id is rejected up-front).
@@ -174,7 +180,17 @@ impl BinaryOp {
                 node_id: *node_id,
             },
             ReactClientProto::ClearContainer { .. } => Self::ClearContainer,
-            ReactClientProto::Complete => Self::Commit,
+            ReactClientProto::Complete | ReactClientProto::Commit { .. } => Self::Commit,
+            ReactClientProto::RegisterStyle { name, style_json } => Self::RegisterStyle {
+                name: name.clone(),
+                style_json: style_json.clone(),
+            },
+            // Lifecycle reports and registrations are host-side only; they have no wire opcode.
+            ReactClientProto::RootMounted { .. }
+            | ReactClientProto::RootFailed { .. }
+            | ReactClientProto::RegisterKeyframes { .. } => {
+                return None;
+            }
         })
     }
 
@@ -243,6 +259,9 @@ impl BinaryOp {
                 root_id: root_id.to_owned(),
             },
             Self::Commit => ReactClientProto::Complete,
+            Self::RegisterStyle { name, style_json } => {
+                ReactClientProto::RegisterStyle { name, style_json }
+            }
         }
     }
 }
@@ -390,8 +409,13 @@ fn proto_root_id(msg: &ReactClientProto) -> Option<&str> {
         | ReactClientProto::UpdateNode { root_id, .. }
         | ReactClientProto::UpdateText { root_id, .. }
         | ReactClientProto::DestroyNode { root_id, .. }
-        | ReactClientProto::ClearContainer { root_id } => Some(root_id.as_str()),
-        ReactClientProto::Complete => None,
+        | ReactClientProto::ClearContainer { root_id }
+        | ReactClientProto::Commit { root_id }
+        | ReactClientProto::RootMounted { root_id }
+        | ReactClientProto::RootFailed { root_id, .. } => Some(root_id.as_str()),
+        ReactClientProto::Complete
+        | ReactClientProto::RegisterKeyframes { .. }
+        | ReactClientProto::RegisterStyle { .. } => None,
     }
 }
 
@@ -427,6 +451,10 @@ fn collect_op_strings(op: &BinaryOp, intern: &mut StringInterner) {
         BinaryOp::UpdateNode { props_json, .. } => {
             intern.intern(props_json);
         }
+        BinaryOp::RegisterStyle { name, style_json } => {
+            intern.intern(name);
+            intern.intern(style_json);
+        }
         _ => {}
     }
 }
@@ -497,6 +525,11 @@ fn write_op_inline(out: &mut Vec<u8>, op: &BinaryOp) {
         BinaryOp::Commit => {
             out.push(OP_COMMIT);
         }
+        BinaryOp::RegisterStyle { name, style_json } => {
+            out.push(OP_REGISTER_STYLE);
+            write_inline_string(out, name);
+            write_inline_string(out, style_json);
+        }
     }
 }
 
@@ -566,6 +599,11 @@ fn write_op_with_table(out: &mut Vec<u8>, op: &BinaryOp, intern: &StringInterner
         BinaryOp::Commit => {
             out.push(OP_COMMIT);
         }
+        BinaryOp::RegisterStyle { name, style_json } => {
+            out.push(OP_REGISTER_STYLE);
+            write_string_ref(out, name, intern);
+            write_string_ref(out, style_json, intern);
+        }
     }
 }
 
@@ -624,6 +662,10 @@ fn read_op(cur: &mut Cursor<'_>, table: Option<&[String]>) -> Result<BinaryOp, D
         },
         OP_CLEAR_CONTAINER => BinaryOp::ClearContainer,
         OP_COMMIT => BinaryOp::Commit,
+        OP_REGISTER_STYLE => BinaryOp::RegisterStyle {
+            name: read_string_ref(cur, table)?,
+            style_json: read_string_ref(cur, table)?,
+        },
         other => return Err(DecodeError::UnknownOpcode(other)),
     })
 }
@@ -751,6 +793,10 @@ mod tests {
             },
             BinaryOp::DestroyNode { node_id: 3 },
             BinaryOp::ClearContainer,
+            BinaryOp::RegisterStyle {
+                name: "card".into(),
+                style_json: r#"{"padding":8}"#.into(),
+            },
             BinaryOp::Commit,
         ]
     }
@@ -0,0 +1,211 @@
+//! Bevy [`States`] integration: mount roots per state and expose state to React.
+//!
+//! - [`ReactAppExt::mount_react_in_state`] spawns a root on [`OnEnter`] and
+//!   despawns it on [`OnExit`]; [`on_react_root_removed`](crate::react::on_react_root_removed)
+//!   handles ECS + JS teardown.
+//! - [`ReactAppExt::register_react_state`] publishes the current state on the
+//!   `bevy_state:<name>` bridge channel (`useBevyState(name)`) and registers a
+//!   `set_bevy_state:<name>` command (`setBevyState(name, value)`).
+
+use bevy::prelude::*;
+use bevy::state::state::FreelyMutableState;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+
+use crate::react::ReactBridge;
+
+/// Marks a root spawned by [`ReactAppExt::mount_react_in_state`]; despawned when `S` exits.
+#[derive(Component, Clone, Debug)]
+pub struct ReactStateRoot<S: States>(pub S);
+
+/// Bridge channel carrying the current value of a registered state.
+pub fn bevy_state_channel(name: &str) -> String {
+    format!("bevy_state:{name}")
+}
+
+/// Bridge command that queues a transition for a registered state.
+pub fn set_bevy_state_command(name: &str) -> String {
+    format!("set_bevy_state:{name}")
+}
+
+/// [`App`] helpers for driving React roots from Bevy [`States`].
+///
+/// ```ignore
+/// #[derive(States, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// enum AppState { #[default] Menu, InGame }
+///
+/// app.add_plugins(ReactPlugin)
+///     .init_state::<AppState>()
+///     .register_react_state::<AppState>("AppState")
+///     .mount_react_in_state(AppState::Menu, || {
+///         ReactBundle::with_export(Node::default(), screens(), "MainMenu")
+///     });
+/// ```
+pub trait ReactAppExt {
+    /// Spawn `bundle()` when entering `state`; despawn it (and unmount in JS) on exit.
+    fn mount_react_in_state<S, B, F>(&mut self, state: S, bundle: F) -> &mut Self
+    where
+        S: States,
+        B: Bundle,
+        F: Fn() -> B + Send + Sync + 'static;
+
+    /// Expose `S` to React as `useBevyState(name)` / `setBevyState(name, value)`.
+    ///
+    /// `S` serializes to JSON for the store (unit enum variants become strings)
+    /// and deserializes from the `setBevyState` argument.
+    fn register_react_state<S>(&mut self, name: &str) -> &mut Self
+    where
+        S: FreelyMutableState + Serialize + DeserializeOwned;
+}
+
+impl ReactAppExt for App {
+    fn mount_react_in_state<S, B, F>(&mut self, state: S, bundle: F) -> &mut Self
+    where
+        S: States,
+        B: Bundle,
+        F: Fn() -> B + Send + Sync + 'static,
+    {
+        let marker = state.clone();
+        self.add_systems(OnEnter(state.clone()), move |mut commands: Commands| {
+            commands.spawn((bundle(), ReactStateRoot(marker.clone())));
+        });
+        self.add_systems(
+            OnExit(state.clone()),
+            move |mut commands: Commands, roots: Query<(Entity, &ReactStateRoot<S>)>| {
+                for (entity, root) in &roots {
+                    if root.0 == state
+                        && let Ok(mut entity_commands) = commands.get_entity(entity)
+                    {
+                        entity_commands.try_despawn();
+                    }
+                }
+            },
+        )
+    }
+
+    fn register_react_state<S>(&mut self, name: &str) -> &mut Self
+    where
+        S: FreelyMutableState + Serialize + DeserializeOwned,
+    {
+        self.init_resource::<ReactBridge>();
+        let bridge = self.world().resource::<ReactBridge>().clone();
+
+        // Unchanged JSON is not republished, so polling `State<S>` is cheap.
+        bridge.register_query_store_each_frame(bevy_state_channel(name), |world| {
+            world
+                .get_resource::<State<S>>()
+                .and_then(|state| serde_json::to_value(state.get()).ok())
+                .unwrap_or(Value::Null)
+        });
+
+        let state_name = name.to_string();
+        bridge.register(set_bevy_state_command(name), move |world, args| {
+            let next: S = match serde_json::from_value(args) {
+                Ok(next) => next,
+                Err(err) => {
+                    return json!({
+                        "error": format!("invalid value for state '{state_name}': {err}"),
+                    });
+                }
+            };
+            let Some(mut next_state) = world.get_resource_mut::<NextState<S>>() else {
+                return json!({
+                    "error": format!("state '{state_name}' is not initialized"),
+                });
+            };
+            next_state.set(next);
+            Value::Null
+        });
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::state::app::StatesPlugin;
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::react::{process_react_bridge_calls, sync_registered_query_stores};
+
+    #[derive(States, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    enum AppState {
+        #[default]
+        Menu,
+        InGame,
+    }
+
+    #[derive(Component)]
+    struct MenuScreen;
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .init_state::<AppState>()
+            .register_react_state::<AppState>("AppState")
+            .mount_react_in_state(AppState::Menu, || MenuScreen)
+            .add_systems(Update, (process_react_bridge_calls, sync_registered_query_stores));
+        app.update();
+        app
+    }
+
+    fn menu_screens(app: &mut App) -> usize {
+        app.world_mut()
+            .query_filtered::<Entity, With<MenuScreen>>()
+            .iter(app.world())
+            .count()
+    }
+
+    #[test]
+    fn test_state_root_mounts_on_enter_and_despawns_on_exit() {
+        let mut app = setup_app();
+        assert_eq!(menu_screens(&mut app), 1);
+
+        app.world_mut()
+            .resource_mut::<NextState<AppState>>()
+            .set(AppState::InGame);
+        app.update();
+        assert_eq!(menu_screens(&mut app), 0);
+
+        app.world_mut()
+            .resource_mut::<NextState<AppState>>()
+            .set(AppState::Menu);
+        app.update();
+        assert_eq!(menu_screens(&mut app), 1);
+    }
+
+    #[test]
+    fn test_state_store_and_set_command() {
+        let mut app = setup_app();
+        let bridge = app.world().resource::<ReactBridge>().clone();
+        assert_eq!(
+            bridge.get_state(&bevy_state_channel("AppState")),
+            Some(json!("Menu"))
+        );
+
+        bridge.enqueue_call(set_bevy_state_command("AppState"), json!("InGame"), Some(1));
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<State<AppState>>().get(), &AppState::InGame);
+        assert_eq!(
+            bridge.get_state(&bevy_state_channel("AppState")),
+            Some(json!("InGame"))
+        );
+        assert_eq!(menu_screens(&mut app), 0);
+    }
+
+    #[test]
+    fn test_set_command_rejects_unknown_variant() {
+        let mut app = setup_app();
+        let bridge = app.world().resource::<ReactBridge>().clone();
+
+        bridge.enqueue_call(set_bevy_state_command("AppState"), json!("Credits"), Some(7));
+        app.update();
+        let results = bridge.drain_call_results();
+        assert_eq!(results.len(), 1);
+        assert!(results[0].value.get("error").is_some());
+        assert_eq!(app.world().resource::<State<AppState>>().get(), &AppState::Menu);
+    }
+}
@@ -1,17 +1,26 @@
+use bevy::math::cubic_splines::CubicSegment;
+use bevy::math::curve::{EaseFunction, JumpAt};
 use bevy::prelude::*;
 use bevy::sprite::{BorderRect, TextureSlicer};
 use bevy::text::{Justify, LineBreak, LineHeight, TextLayout};
 use bevy::ui::widget::{NodeImageMode, TextShadow};
 use bevy::ui::{
     AlignContent, AlignItems, AlignSelf, BackgroundGradient, BorderColor, BorderRadius, BoxShadow,
-    ColorStop, Display, FlexDirection, FlexWrap, Gradient, GridAutoFlow, GridPlacement, GridTrack,
-    JustifyContent, JustifyItems, JustifySelf, LinearGradient, Overflow, OverflowAxis,
-    OverflowClipMargin, PositionType, RepeatedGridTrack, ShadowStyle, Val,
+    ColorStop, Display, FlexDirection, FlexWrap, Gradient, GridAutoFlow, GridTrack,
+    GridTrackRepetition, JustifyContent, JustifyItems, JustifySelf, LinearGradient,
+    MaxTrackSizingFunction, MinTrackSizingFunction, Overflow, OverflowAxis, OverflowClipMargin,
+    PositionType, RepeatedGridTrack, ShadowStyle, UiTransform, Val, Val2,
 };
 use serde::{Deserialize, Deserializer};
 use serde_json::Value;
 use std::collections::HashMap;
 
+use crate::react::calc::{CalcContext, CssLength, parse_css_length};
+use crate::react::fonts::FontStyle;
+use crate::react::grid::GridItemLines;
+use crate::react::media::MediaStyles;
+use crate::react::systems::WhiteSpace;
+
 /// A value that can be either a string or a number (for CSS-like length properties).
 /// Numbers are treated as pixel values (`"Npx"`).
 #[derive(Debug, Clone, Default)]
@@ -68,29 +77,155 @@ pub struct NodeProps {
     pub image: Option<String>,
     #[serde(default)]
     pub src: Option<String>,
+    /// Inline SVG for <svg> elements
+    #[serde(default)]
+    pub markup: Option<String>,
     /// Text content for <text> elements
     #[serde(default)]
     pub content: Option<String>,
 }
 
-/// Per-property transition durations (seconds), keyed by camelCase prop name.
+/// CSS `transition-timing-function`.
+///
+/// Host transitions default to [`TimingFunction::Linear`] (CSS defaults to `ease`)
+/// so transitions written before timing functions existed keep their curve.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum TimingFunction {
+    #[default]
+    Linear,
+    /// `cubic-bezier(x1, y1, x2, y2)` control points.
+    CubicBezier(Vec2, Vec2),
+    /// `steps(n, jump-*)`.
+    Steps(usize, JumpAt),
+}
+
+impl TimingFunction {
+    pub const EASE: Self = Self::CubicBezier(Vec2::new(0.25, 0.1), Vec2::new(0.25, 1.0));
+    pub const EASE_IN: Self = Self::CubicBezier(Vec2::new(0.42, 0.0), Vec2::ONE);
+    pub const EASE_OUT: Self = Self::CubicBezier(Vec2::ZERO, Vec2::new(0.58, 1.0));
+    pub const EASE_IN_OUT: Self = Self::CubicBezier(Vec2::new(0.42, 0.0), Vec2::new(0.58, 1.0));
+
+    /// Eased progress for linear progress `t` in `0..=1`.
+    pub fn sample(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match *self {
+            Self::Linear => t,
+            Self::CubicBezier(p1, p2) => CubicSegment::new_bezier_easing(p1, p2).ease(t),
+            Self::Steps(steps, jump) => EaseFunction::Steps(steps, jump).sample_clamped(t),
+        }
+    }
+}
+
+/// Parse `linear`, `ease*`, `step-start` / `step-end`, `cubic-bezier(...)` or `steps(...)`.
+pub fn parse_timing_function(value: &str) -> Option<TimingFunction> {
+    let value = value.trim().to_ascii_lowercase();
+    match value.as_str() {
+        "linear" => return Some(TimingFunction::Linear),
+        "ease" => return Some(TimingFunction::EASE),
+        "ease-in" => return Some(TimingFunction::EASE_IN),
+        "ease-out" => return Some(TimingFunction::EASE_OUT),
+        "ease-in-out" => return Some(TimingFunction::EASE_IN_OUT),
+        "step-start" => return Some(TimingFunction::Steps(1, JumpAt::Start)),
+        "step-end" => return Some(TimingFunction::Steps(1, JumpAt::End)),
+        _ => {}
+    }
+
+    let (name, args) = value.strip_suffix(')')?.split_once('(')?;
+    let args: Vec<&str> = args.split(',').map(str::trim).collect();
+    match (name.trim(), args.as_slice()) {
+        ("cubic-bezier", [x1, y1, x2, y2]) => {
+            let p1 = Vec2::new(x1.parse().ok()?, y1.parse().ok()?);
+            let p2 = Vec2::new(x2.parse().ok()?, y2.parse().ok()?);
+            Some(TimingFunction::CubicBezier(p1, p2))
+        }
+        ("steps", [steps, rest @ ..]) => {
+            let steps = steps.parse::<usize>().ok().filter(|n| *n > 0)?;
+            let jump = match rest.first().copied() {
+                None | Some("end") | Some("jump-end") => JumpAt::End,
+                Some("start") | Some("jump-start") => JumpAt::Start,
+                Some("jump-none") => JumpAt::None,
+                Some("jump-both") => JumpAt::Both,
+                Some(_) => return None,
+            };
+            Some(TimingFunction::Steps(steps, jump))
+        }
+        _ => None,
+    }
+}
+
+/// One property's transition: duration and delay in seconds plus easing.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TransitionSpec {
+    pub duration: f32,
+    pub delay: f32,
+    pub timing: TimingFunction,
+}
+
+impl TransitionSpec {
+    /// True when the value should jump straight to its target.
+    pub fn is_instant(&self) -> bool {
+        self.duration <= 0.0 && self.delay <= 0.0
+    }
+
+    /// Eased progress `0..=1` after `elapsed` seconds, holding at 0 during the delay.
+    pub fn progress(&self, elapsed: f32) -> f32 {
+        if elapsed < self.delay {
+            return 0.0;
+        }
+        if self.duration <= 0.0 {
+            return 1.0;
+        }
+        self.timing.sample((elapsed - self.delay) / self.duration)
+    }
+
+    /// Seconds until the transition settles.
+    pub fn total(&self) -> f32 {
+        self.delay + self.duration.max(0.0)
+    }
+}
+
+/// Per-property transitions, keyed by camelCase prop name (or `all`).
 ///
-/// Accepts CSS-like strings (`"backgroundColor 100ms"`) or objects
-/// (`{ backgroundColor: 100 }` with values in milliseconds).
+/// Accepts CSS-like strings (`"backgroundColor 100ms ease-out 50ms"`) or objects
+/// (`{ backgroundColor: 100 }` with values in milliseconds, or CSS strings).
+/// Longhands fall back to their shorthand (`paddingLeft` → `padding`), then `all`.
 #[derive(Debug, Clone, Default)]
 pub struct StyleTransitions {
-    pub durations: HashMap<String, f32>,
+    pub specs: HashMap<String, TransitionSpec>,
 }
 
 impl StyleTransitions {
     pub fn is_empty(&self) -> bool {
-        self.durations.is_empty()
+        self.specs.is_empty()
+    }
+
+    /// Transition for a camelCase prop, or `None` if it does not animate.
+    pub fn spec(&self, prop: &str) -> Option<TransitionSpec> {
+        self.specs
+            .get(prop)
+            .or_else(|| transition_shorthand(prop).and_then(|s| self.specs.get(s)))
+            .or_else(|| self.specs.get("all"))
+            .copied()
     }
 
     /// Duration in seconds for a camelCase prop, or `None` if unset.
     pub fn duration_secs(&self, prop: &str) -> Option<f32> {
-        self.durations.get(prop).copied()
+        self.spec(prop).map(|spec| spec.duration)
+    }
+}
+
+/// CSS shorthand a longhand transition inherits from.
+fn transition_shorthand(prop: &str) -> Option<&'static str> {
+    if prop.ends_with("Radius") && prop != "borderRadius" {
+        return Some("borderRadius");
     }
+    ["padding", "margin", "inset"]
+        .into_iter()
+        .find(|shorthand| prop.len() > shorthand.len() && prop.starts_with(shorthand))
+        .or(match prop {
+            "top" | "right" | "bottom" | "left" => Some("inset"),
+            _ => None,
+        })
 }
 
 impl<'de> Deserialize<'de> for StyleTransitions {
@@ -103,18 +238,18 @@ impl<'de> Deserialize<'de> for StyleTransitions {
     }
 }
 
-/// Parse a transition value (string or object) into durations in seconds.
+/// Parse a transition value (string or object) into per-property specs.
 pub fn parse_transition_value(value: &Value) -> StyleTransitions {
     match value {
         Value::String(s) => parse_transition_string(s),
         Value::Object(map) => {
-            let mut durations = HashMap::new();
+            let mut specs = HashMap::new();
             for (key, v) in map {
-                if let Some(secs) = transition_entry_to_secs(v) {
-                    durations.insert(key.clone(), secs);
+                if let Some(spec) = transition_entry_to_spec(v) {
+                    specs.insert(key.clone(), spec);
                 }
             }
-            StyleTransitions { durations }
+            StyleTransitions { specs }
         }
         Value::Null => StyleTransitions::default(),
         other => {
@@ -124,35 +259,59 @@ pub fn parse_transition_value(value: &Value) -> StyleTransitions {
     }
 }
 
-fn transition_entry_to_secs(value: &Value) -> Option<f32> {
+fn transition_entry_to_spec(value: &Value) -> Option<TransitionSpec> {
     match value {
-        Value::Number(n) => n.as_f64().map(|ms| (ms as f32) / 1000.0),
-        Value::String(s) => Some(parse_duration_to_secs(s)),
+        Value::Number(n) => n.as_f64().map(|ms| TransitionSpec {
+            duration: (ms as f32) / 1000.0,
+            ..default()
+        }),
+        Value::String(s) => Some(parse_transition_timing(s)),
         _ => None,
     }
 }
 
-/// Parse `"backgroundColor 100ms, opacity 200ms"` or `"all 100ms"`.
+/// Parse `"backgroundColor 100ms, opacity 200ms ease-in 50ms"` or `"all 100ms"`.
+///
+/// Like CSS, the first time is the duration and the second the delay.
 pub fn parse_transition_string(value: &str) -> StyleTransitions {
-    let mut durations = HashMap::new();
-    for part in value.split(',') {
-        let tokens: Vec<&str> = part.split_whitespace().collect();
-        match tokens.as_slice() {
-            [prop, dur] => {
-                durations.insert((*prop).to_string(), parse_duration_to_secs(dur));
-            }
-            [prop] => {
-                // Bare prop name with no duration — treat as instant
-                durations.insert((*prop).to_string(), 0.0);
-            }
-            _ => {
-                if !part.trim().is_empty() {
-                    log::warn!("Invalid transition segment '{}'", part.trim());
-                }
+    let mut specs = HashMap::new();
+    for part in split_css_list(value) {
+        let (prop, rest) = next_css_token(part);
+        if prop.is_empty() {
+            continue;
+        }
+        // A bare prop name with no duration is instant.
+        specs.insert(prop.to_string(), parse_transition_timing(rest));
+    }
+    StyleTransitions { specs }
+}
+
+/// Parse the `duration [timing] [delay]` tail of one transition (any order).
+fn parse_transition_timing(value: &str) -> TransitionSpec {
+    let mut spec = TransitionSpec::default();
+    let mut times = 0;
+    let mut rest = value;
+    loop {
+        let (token, next) = next_css_token(rest);
+        if token.is_empty() {
+            break;
+        }
+        rest = next;
+        if let Some(timing) = parse_timing_function(token) {
+            spec.timing = timing;
+        } else if token.starts_with(|c: char| c.is_ascii_digit() || c == '.' || c == '-') {
+            let secs = parse_duration_to_secs(token);
+            match times {
+                0 => spec.duration = secs,
+                1 => spec.delay = secs,
+                _ => log::warn!("Extra transition time '{}' ignored", token),
             }
+            times += 1;
+        } else {
+            log::warn!("Invalid transition token '{}'", token);
         }
     }
-    StyleTransitions { durations }
+    spec
 }
 
 fn parse_duration_to_secs(value: &str) -> f32 {
@@ -205,6 +364,8 @@ pub struct StyleProps {
     pub grid_column_end: Option<CssScalar>,
     pub grid_row_start: Option<CssScalar>,
     pub grid_row_end: Option<CssScalar>,
+    pub grid_area: Option<CssScalar>,
+    pub grid_template_areas: Option<String>,
 
     // Spacing
     pub margin: Option<CssValue>,
@@ -267,15 +428,41 @@ pub struct StyleProps {
     pub background_image: Option<String>,
     /// Alias for CSS `background-image` linear-gradient / Bevy `BackgroundGradient`
     pub background_gradient: Option<String>,
+    /// CSS `translate(...)` / `scale(...)` / `rotate(...)` list (Bevy `UiTransform`).
+    pub transform: Option<String>,
+    /// CSS `transform-origin` (`"center"`, `"top left"`, `"0 100%"`).
+    pub transform_origin: Option<String>,
+
+    // Animation
+    /// CSS `animation` shorthand over keyframes registered in `ReactKeyframes`.
+    pub animation: Option<String>,
+    /// `"running"` / `"paused"`, or a comma list matching `animation`.
+    pub animation_play_state: Option<String>,
 
     // Text styling
     pub color: Option<String>,
     pub font_size: Option<CssValue>,
+    /// Comma list of registered families or font asset paths.
     pub font_family: Option<String>,
+    /// `100`–`900`, `"normal"`, `"bold"`.
+    pub font_weight: Option<CssScalar>,
+    /// `"normal"` / `"italic"` / `"oblique"`.
+    pub font_style: Option<String>,
     pub text_align: Option<String>,
     pub line_height: Option<CssScalar>,
     /// Soft wrap mode: `word` / `character` / `word-or-character` / `nowrap`.
     pub line_break: Option<String>,
+    /// `normal` / `nowrap` / `pre` / `pre-wrap` / `pre-line` / `break-spaces`.
+    pub white_space: Option<String>,
+    /// `clip` / `ellipsis`.
+    pub text_overflow: Option<String>,
+    /// Visible line count; `-webkit-line-clamp` arrives as `WebkitLineClamp`.
+    #[serde(
+        alias = "WebkitLineClamp",
+        alias = "webkitLineClamp",
+        alias = "lineClamp"
+    )]
+    pub max_lines: Option<CssScalar>,
     /// CSS-like `offset-x offset-y [blur] [color]` (blur ignored; Bevy has no text blur).
     pub text_shadow: Option<String>,
 
@@ -291,6 +478,10 @@ pub struct StyleProps {
     /// pass clicks through to the world / map underneath.
     pub pointer_events: Option<String>,
 
+    /// CSS `cursor`: a keyword (`"pointer"`, `"text"`, `"grab"`, `"not-allowed"`, ...)
+    /// or `"url(path) x y, fallback"`. Shown on the window while the pointer is over the node.
+    pub cursor: Option<String>,
+
     /// Host-side hover overrides (applied from Bevy [`Interaction`], no React round-trip).
     pub hover: Option<Box<StyleProps>>,
     /// Host-side pressed / active overrides.
@@ -302,6 +493,9 @@ pub struct StyleProps {
     /// Host-side color/numeric transitions between interaction states.
     #[serde(default)]
     pub transition: StyleTransitions,
+    /// Responsive overrides keyed by media query, resolved against the viewport.
+    #[serde(default)]
+    pub media: MediaStyles,
 
     /// Unknown keys captured for warning (cleared after [`StyleProps::finalize_parse`]).
     #[serde(flatten)]
@@ -328,18 +522,22 @@ impl StyleProps {
         if let Some(ref mut checked) = self.checked {
             checked.finalize_parse();
         }
+        for (_, block) in &mut self.media.blocks {
+            block.finalize_parse();
+        }
     }
 
-    /// True when host-side interaction styling or transitions are present.
+    /// True when host-side interaction styling, transitions or animations are present.
     pub fn has_interaction_styles(&self) -> bool {
         self.hover.is_some()
             || self.pressed.is_some()
             || self.focused.is_some()
             || self.checked.is_some()
             || !self.transition.is_empty()
+            || self.animation.is_some()
     }
 
-    /// Clone without hover/pressed/focused/checked/transition/unknown (pure visual+layout props).
+    /// Clone without hover/pressed/focused/checked/transition/media/unknown (pure visual+layout props).
     pub fn without_interaction_meta(&self) -> StyleProps {
         let mut out = self.clone();
         out.hover = None;
@@ -347,6 +545,7 @@ impl StyleProps {
         out.focused = None;
         out.checked = None;
         out.transition = StyleTransitions::default();
+        out.media = MediaStyles::default();
         out.unknown.clear();
         out
     }
@@ -386,6 +585,8 @@ pub fn merge_style_props(base: &StyleProps, overlay: &StyleProps) -> StyleProps
         grid_column_end: overlay.grid_column_end.or(base.grid_column_end),
         grid_row_start: overlay.grid_row_start.or(base.grid_row_start),
         grid_row_end: overlay.grid_row_end.or(base.grid_row_end),
+        grid_area: overlay.grid_area.or(base.grid_area),
+        grid_template_areas: overlay.grid_template_areas.or(base.grid_template_areas),
         margin: overlay.margin.or(base.margin),
         margin_top: overlay.margin_top.or(base.margin_top),
         margin_right: overlay.margin_right.or(base.margin_right),
@@ -435,27 +636,72 @@ pub fn merge_style_props(base: &StyleProps, overlay: &StyleProps) -> StyleProps
         box_shadow: overlay.box_shadow.or(base.box_shadow),
         background_image: overlay.background_image.or(base.background_image),
         background_gradient: overlay.background_gradient.or(base.background_gradient),
+        transform: overlay.transform.or(base.transform),
+        transform_origin: overlay.transform_origin.or(base.transform_origin),
+        animation: overlay.animation.or(base.animation),
+        animation_play_state: overlay.animation_play_state.or(base.animation_play_state),
         color: overlay.color.or(base.color),
         font_size: overlay.font_size.or(base.font_size),
         font_family: overlay.font_family.or(base.font_family),
+        font_weight: overlay.font_weight.or(base.font_weight),
+        font_style: overlay.font_style.or(base.font_style),
         text_align: overlay.text_align.or(base.text_align),
         line_height: overlay.line_height.or(base.line_height),
         line_break: overlay.line_break.or(base.line_break),
+        white_space: overlay.white_space.or(base.white_space),
+        text_overflow: overlay.text_overflow.or(base.text_overflow),
+        max_lines: overlay.max_lines.or(base.max_lines),
         text_shadow: overlay.text_shadow.or(base.text_shadow),
         object_fit: overlay.object_fit.or(base.object_fit),
         image_slice: overlay.image_slice.or(base.image_slice),
         tint: overlay.tint.or(base.tint),
         tint_color: overlay.tint_color.or(base.tint_color),
         pointer_events: overlay.pointer_events.or(base.pointer_events),
+        cursor: overlay.cursor.or(base.cursor),
         hover: None,
         pressed: None,
         focused: None,
         checked: None,
         transition: StyleTransitions::default(),
+        media: MediaStyles::default(),
         unknown: HashMap::new(),
     }
 }
 
+/// Cascade `overlay` over `base` (style classes, then inline style). Unlike
+/// [`merge_style_props`] this keeps interaction meta: `hover` / `pressed` /
+/// `focused` / `checked` blocks cascade the same way, per-property
+/// transitions from both apply (`overlay` winning per property), and `media`
+/// blocks from both are kept, `overlay`'s last.
+pub fn cascade_style_props(base: &StyleProps, overlay: &StyleProps) -> StyleProps {
+    fn cascade_state(
+        base: &Option<Box<StyleProps>>,
+        overlay: &Option<Box<StyleProps>>,
+    ) -> Option<Box<StyleProps>> {
+        match (base, overlay) {
+            (Some(base), Some(overlay)) => Some(Box::new(cascade_style_props(base, overlay))),
+            (base, overlay) => overlay.clone().or_else(|| base.clone()),
+        }
+    }
+
+    let mut out = merge_style_props(base, overlay);
+    out.hover = cascade_state(&base.hover, &overlay.hover);
+    out.pressed = cascade_state(&base.pressed, &overlay.pressed);
+    out.focused = cascade_state(&base.focused, &overlay.focused);
+    out.checked = cascade_state(&base.checked, &overlay.checked);
+    out.transition = base.transition.clone();
+    out.transition.specs.extend(
+        overlay
+            .transition
+            .specs
+            .iter()
+            .map(|(prop, spec)| (prop.clone(), *spec)),
+    );
+    out.media.blocks = base.media.blocks.clone();
+    out.media.blocks.extend(overlay.media.blocks.iter().cloned());
+    out
+}
+
 /// Resolve base + checked + focused + hover + pressed.
 ///
 /// Order (later wins): base → checked → focused → hover → pressed.
@@ -512,8 +758,144 @@ pub fn parse_props(props_json: &str) -> NodeProps {
     node
 }
 
+/// Nesting limit for `var()` values that reference other variables (cycle guard).
+const MAX_VAR_DEPTH: usize = 16;
+
+/// Substitute `var(--name[, fallback])` references in a CSS value.
+///
+/// `lookup` receives the name without the leading `--`. Returns `None` when a
+/// reference has neither a value nor a fallback, which invalidates the whole
+/// declaration as in CSS.
+pub fn resolve_css_vars(value: &str, lookup: &dyn Fn(&str) -> Option<String>) -> Option<String> {
+    resolve_css_vars_at(value, lookup, 0)
+}
+
+fn resolve_css_vars_at(
+    value: &str,
+    lookup: &dyn Fn(&str) -> Option<String>,
+    depth: usize,
+) -> Option<String> {
+    if !value.contains("var(") {
+        return Some(value.to_string());
+    }
+    if depth > MAX_VAR_DEPTH {
+        log::warn!("Theme variable cycle in '{}'", value);
+        return None;
+    }
+
+    let mut out = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find("var(") {
+        out.push_str(&rest[..start]);
+        let args = &rest[start + 4..];
+        let Some(close) = closing_paren(args) else {
+            log::warn!("Unterminated var() in '{}'", value);
+            return None;
+        };
+        let (name, fallback) = match args[..close].split_once(',') {
+            Some((name, fallback)) => (name, Some(fallback.trim())),
+            None => (&args[..close], None),
+        };
+        let name = name.trim();
+        let raw = lookup(name.strip_prefix("--").unwrap_or(name))
+            .or_else(|| fallback.map(str::to_string))?;
+        out.push_str(&resolve_css_vars_at(&raw, lookup, depth + 1)?);
+        rest = &args[close + 1..];
+    }
+    out.push_str(rest);
+    Some(out)
+}
+
+/// Byte index of the `)` closing an argument list that starts at `args[0]`.
+fn closing_paren(args: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    for (i, c) in args.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => return Some(i),
+            ')' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Variable names (without `--`) referenced by `var()` anywhere in a style JSON
+/// value, including fallbacks and nested overrides. Sorted and deduplicated.
+pub fn style_var_names(style: &Value) -> Vec<String> {
+    fn collect(value: &Value, out: &mut Vec<String>) {
+        match value {
+            Value::String(s) => {
+                for (start, _) in s.match_indices("var(") {
+                    let args = &s[start + 4..];
+                    let end = args.find([',', ')']).unwrap_or(args.len());
+                    let name = args[..end].trim();
+                    out.push(name.strip_prefix("--").unwrap_or(name).to_string());
+                }
+            }
+            Value::Array(items) => items.iter().for_each(|v| collect(v, out)),
+            Value::Object(map) => map.values().for_each(|v| collect(v, out)),
+            _ => {}
+        }
+    }
+
+    let mut names = Vec::new();
+    collect(style, &mut names);
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Substitute theme variables throughout a style JSON value and parse the result.
+///
+/// Declarations whose variables cannot be resolved are dropped. A value that is
+/// exactly one `var()` resolving to a number becomes a JSON number, so
+/// `padding: "var(--space)"` with `--space: 8` means `8px` as it would inline.
+pub fn resolve_style_vars(style: &Value, lookup: &dyn Fn(&str) -> Option<String>) -> StyleProps {
+    fn resolve(value: &Value, lookup: &dyn Fn(&str) -> Option<String>) -> Option<Value> {
+        match value {
+            Value::String(s) => {
+                let resolved = resolve_css_vars(s, lookup)?;
+                let whole_var = s.trim_start().starts_with("var(") && s.trim_end().ends_with(')');
+                if whole_var {
+                    if let Ok(n) = resolved.trim().parse::<i64>() {
+                        return Some(Value::from(n));
+                    }
+                    if let Some(n) = resolved
+                        .trim()
+                        .parse::<f64>()
+                        .ok()
+                        .and_then(serde_json::Number::from_f64)
+                    {
+                        return Some(Value::Number(n));
+                    }
+                }
+                Some(Value::String(resolved))
+            }
+            Value::Array(items) => Some(Value::Array(
+                items.iter().filter_map(|v| resolve(v, lookup)).collect(),
+            )),
+            Value::Object(map) => Some(Value::Object(
+                map.iter()
+                    .filter_map(|(k, v)| Some((k.clone(), resolve(v, lookup)?)))
+                    .collect(),
+            )),
+            other => Some(other.clone()),
+        }
+    }
+
+    let resolved = resolve(style, lookup).unwrap_or(Value::Null);
+    let mut props: StyleProps = serde_json::from_value(resolved).unwrap_or_else(|e| {
+        log::warn!("Failed to parse themed style: {}", e);
+        StyleProps::default()
+    });
+    props.finalize_parse();
+    props
+}
+
 /// Convert a CSS-like value string to Bevy's Val
-/// Supports: "100px", "50%", "auto"
+/// Supports: "100px", "50%", "auto", viewport units, "1.5rem" and `calc()` /
+/// `min()` / `max()` / `clamp()` (see [`parse_css_length`]).
 pub fn parse_val(value: &str) -> Val {
     let value = value.trim();
 
@@ -546,13 +928,22 @@ pub fn parse_val(value: &str) -> Val {
         return Val::Px(n);
     }
 
+    // rem / em / vmin / vmax and calc() / min() / max() / clamp()
+    match parse_css_length(value) {
+        Some(CssLength::Val(val)) => return val,
+        // Node lengths are re-resolved against the parent by `resolve_calc_lengths`;
+        // elsewhere (radii, shadows) the primary-window estimate stands.
+        Some(CssLength::Calc(expr)) => return Val::Px(expr.eval(&CalcContext::FALLBACK)),
+        None => {}
+    }
+
     log::warn!("Unknown Val format: '{}', defaulting to Auto", value);
     Val::Auto
 }
 
 /// Parse CSS margin/padding shorthand into a UiRect (1–4 values).
 pub fn parse_ui_rect_shorthand(value: &str) -> UiRect {
-    let parts: Vec<&str> = value.split_whitespace().collect();
+    let parts = split_css_values(value);
     match parts.as_slice() {
         [all] => UiRect::all(parse_val(all)),
         [vertical, horizontal] => UiRect {
@@ -586,7 +977,7 @@ pub fn parse_ui_rect_shorthand(value: &str) -> UiRect {
 
 /// Parse CSS border-radius shorthand into a BorderRadius (1–4 values).
 pub fn parse_border_radius_shorthand(value: &str) -> BorderRadius {
-    let parts: Vec<&str> = value.split_whitespace().collect();
+    let parts = split_css_values(value);
     match parts.as_slice() {
         [all] => BorderRadius::all(parse_val(all)),
         [tl_br, tr_bl] => BorderRadius::new(
@@ -717,7 +1108,7 @@ fn parse_box_shadow_layer(layer: &str) -> Option<ShadowStyle> {
         return None;
     }
 
-    let tokens: Vec<&str> = layer.split_whitespace().collect();
+    let tokens = split_css_values(layer);
     let mut lengths: Vec<Val> = Vec::new();
     let mut color = Color::srgba(0.0, 0.0, 0.0, 0.5);
 
@@ -862,10 +1253,45 @@ pub fn style_line_break(props: &StyleProps) -> Option<LineBreak> {
     props.line_break.as_deref().and_then(parse_line_break)
 }
 
-/// Combine `textAlign` + `lineBreak` into a single [`TextLayout`] when either is set.
+/// CSS `white-space`; `break-spaces` is treated as `pre-wrap`.
+pub fn parse_white_space(value: &str) -> Option<WhiteSpace> {
+    match value.trim().to_lowercase().as_str() {
+        "normal" => Some(WhiteSpace::Normal),
+        "nowrap" => Some(WhiteSpace::NoWrap),
+        "pre" => Some(WhiteSpace::Pre),
+        "pre-wrap" | "break-spaces" => Some(WhiteSpace::PreWrap),
+        "pre-line" => Some(WhiteSpace::PreLine),
+        _ => None,
+    }
+}
+
+pub fn style_white_space(props: &StyleProps) -> Option<WhiteSpace> {
+    props.white_space.as_deref().and_then(parse_white_space)
+}
+
+/// `textOverflow: "ellipsis"`; `clip` (the default) is `false`.
+pub fn style_text_ellipsis(props: &StyleProps) -> bool {
+    props
+        .text_overflow
+        .as_deref()
+        .is_some_and(|value| value.trim().eq_ignore_ascii_case("ellipsis"))
+}
+
+/// `maxLines` / `WebkitLineClamp` as a positive line count; `none` clears it.
+pub fn style_max_lines(props: &StyleProps) -> Option<usize> {
+    let value = props.max_lines.as_ref()?.0.trim().parse::<f32>().ok()?;
+    (value >= 1.0).then_some(value as usize)
+}
+
+/// Combine `textAlign` + `lineBreak` / `whiteSpace` into a single
+/// [`TextLayout`] when any is set. `whiteSpace: nowrap` / `pre` win over
+/// `lineBreak`.
 pub fn style_text_layout(props: &StyleProps) -> Option<TextLayout> {
     let justify = style_text_align(props);
-    let linebreak = style_line_break(props);
+    let linebreak = match style_white_space(props) {
+        Some(white_space) if !white_space.wraps() => Some(LineBreak::NoWrap),
+        _ => style_line_break(props),
+    };
     match (justify, linebreak) {
         (None, None) => None,
         (Some(j), Some(lb)) => Some(TextLayout::new(j, lb)),
@@ -952,23 +1378,62 @@ pub fn style_line_height(props: &StyleProps) -> Option<LineHeight> {
     props.line_height.as_ref().and_then(|v| parse_line_height(&v.0))
 }
 
-/// Asset path for fontFamily when it looks like a path; `None` for generic families.
-pub fn parse_font_family(value: &str) -> Option<String> {
-    let value = value.trim().trim_matches('"').trim_matches('\'');
-    if value.is_empty() {
-        return None;
+/// `fontFamily` list (`"Inter, 'Noto Sans', sans-serif"`), unquoted, in order.
+/// `None` when it names no family.
+pub fn parse_font_families(value: &str) -> Option<Vec<String>> {
+    let families: Vec<String> = value
+        .split(',')
+        .map(|name| name.trim().trim_matches('"').trim_matches('\'').trim())
+        .filter(|name| {
+            !name.is_empty()
+                && !matches!(
+                    name.to_lowercase().as_str(),
+                    "inherit" | "initial" | "unset"
+                )
+        })
+        .map(str::to_string)
+        .collect();
+    (!families.is_empty()).then_some(families)
+}
+
+pub fn style_font_families(props: &StyleProps) -> Option<Vec<String>> {
+    props.font_family.as_deref().and_then(parse_font_families)
+}
+
+/// CSS `font-weight` as 1–1000. `bolder` / `lighter` map to 700 / 300 (no
+/// inherited weight to step from).
+pub fn parse_font_weight(value: &str) -> Option<u16> {
+    match value.trim().to_lowercase().as_str() {
+        "normal" => Some(400),
+        "bold" | "bolder" => Some(700),
+        "lighter" => Some(300),
+        number => number
+            .parse::<f32>()
+            .ok()
+            .filter(|w| (1.0..=1000.0).contains(w))
+            .map(|w| w.round() as u16),
     }
-    let lower = value.to_lowercase();
-    match lower.as_str() {
-        "serif" | "sans-serif" | "monospace" | "cursive" | "fantasy" | "system-ui" | "inherit"
-        | "initial" | "unset" => None,
-        _ if value.contains('/') || value.contains('.') => Some(value.to_string()),
-        _ => Some(value.to_string()),
+}
+
+pub fn style_font_weight(props: &StyleProps) -> Option<u16> {
+    props
+        .font_weight
+        .as_ref()
+        .and_then(|v| parse_font_weight(&v.0))
+}
+
+/// CSS `font-style`; `oblique` (with or without an angle) is treated as italic.
+pub fn parse_font_style(value: &str) -> Option<FontStyle> {
+    let value = value.trim().to_lowercase();
+    match value.split_whitespace().next()? {
+        "normal" => Some(FontStyle::Normal),
+        "italic" | "oblique" => Some(FontStyle::Italic),
+        _ => None,
     }
 }
 
-pub fn style_font_family(props: &StyleProps) -> Option<String> {
-    props.font_family.as_deref().and_then(parse_font_family)
+pub fn style_font_style(props: &StyleProps) -> Option<FontStyle> {
+    props.font_style.as_deref().and_then(parse_font_style)
 }
 
 /// Resolved pointer-events mode for Bevy picking / UI focus.
@@ -1059,6 +1524,236 @@ pub fn style_tint(props: &StyleProps) -> Option<Color> {
         .and_then(parse_color)
 }
 
+/// Host-side CSS transform, resolved to [`UiTransform`] once the node is laid out.
+///
+/// Functions in a `transform` list are folded into one translate → rotate →
+/// scale, which covers the usual `translate(...) rotate(...) scale(...)` order.
+/// `origin` is measured from the node's top-left corner, like CSS.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct ReactTransform {
+    pub translation: Val2,
+    pub scale: Vec2,
+    /// Clockwise, in radians.
+    pub rotation: f32,
+    pub origin: Val2,
+}
+
+impl ReactTransform {
+    pub const CENTER: Val2 = Val2::percent(50.0, 50.0);
+
+    pub const IDENTITY: Self = Self {
+        translation: Val2::ZERO,
+        scale: Vec2::ONE,
+        rotation: 0.0,
+        origin: Self::CENTER,
+    };
+
+    /// Resolve against the node's logical size. Bevy scales and rotates about the
+    /// centre, so an off-centre origin becomes an extra translation.
+    pub fn to_ui_transform(self, size: Vec2) -> UiTransform {
+        let rotation = Rot2::radians(self.rotation);
+        if self.origin == Self::CENTER {
+            return UiTransform {
+                translation: self.translation,
+                scale: self.scale,
+                rotation,
+            };
+        }
+        let origin = self.origin.resolve(1.0, size, Vec2::ZERO) - size / 2.0;
+        let linear = Mat2::from_scale_angle(self.scale, self.rotation);
+        let translation =
+            self.translation.resolve(1.0, size, Vec2::ZERO) + origin - linear * origin;
+        UiTransform {
+            translation: Val2::px(translation.x, translation.y),
+            scale: self.scale,
+            rotation,
+        }
+    }
+
+    /// Interpolate for transitions. Translations in different units snap to `to`.
+    pub fn lerp(&self, to: &Self, t: f32) -> Self {
+        Self {
+            translation: Val2::new(
+                lerp_val(self.translation.x, to.translation.x, t),
+                lerp_val(self.translation.y, to.translation.y, t),
+            ),
+            scale: self.scale.lerp(to.scale, t),
+            rotation: self.rotation + (to.rotation - self.rotation) * t,
+            origin: to.origin,
+        }
+    }
+}
+
+impl Default for ReactTransform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// Interpolate lengths of the same unit; mixed units snap to `to`.
+pub(crate) fn lerp_val(from: Val, to: Val, t: f32) -> Val {
+    match (from, to) {
+        (Val::Px(a), Val::Px(b)) => Val::Px(a + (b - a) * t),
+        (Val::Percent(a), Val::Percent(b)) => Val::Percent(a + (b - a) * t),
+        _ => to,
+    }
+}
+
+fn add_val(a: Val, b: Val) -> Val {
+    match (a, b) {
+        (Val::Px(a), Val::Px(b)) => Val::Px(a + b),
+        (Val::Percent(a), Val::Percent(b)) => Val::Percent(a + b),
+        (Val::Px(0.0), other) => other,
+        (other, _) => {
+            log::warn!(
+                "Cannot add translations in different units; keeping {:?}",
+                other
+            );
+            other
+        }
+    }
+}
+
+/// Parse a CSS angle (`45deg`, `0.5turn`, `1.2rad`, `100grad`; bare numbers are degrees).
+pub fn parse_angle(value: &str) -> Option<f32> {
+    let value = value.trim();
+    let (number, to_radians) = if let Some(v) = value.strip_suffix("deg") {
+        (v, std::f32::consts::PI / 180.0)
+    } else if let Some(v) = value.strip_suffix("grad") {
+        (v, std::f32::consts::PI / 200.0)
+    } else if let Some(v) = value.strip_suffix("rad") {
+        (v, 1.0)
+    } else if let Some(v) = value.strip_suffix("turn") {
+        (v, std::f32::consts::TAU)
+    } else {
+        (value, std::f32::consts::PI / 180.0)
+    };
+    number.trim().parse::<f32>().ok().map(|n| n * to_radians)
+}
+
+/// Parse a CSS `transform` list into translation / scale / rotation (origin centred).
+pub fn parse_transform(value: &str) -> Option<ReactTransform> {
+    let mut transform = ReactTransform::IDENTITY;
+    let mut rest = value.trim();
+    if rest.eq_ignore_ascii_case("none") {
+        return Some(transform);
+    }
+
+    while !rest.is_empty() {
+        let open = rest.find('(')?;
+        let close = rest[open..].find(')')? + open;
+        let name = rest[..open].trim().to_ascii_lowercase();
+        let args: Vec<&str> = rest[open + 1..close]
+            .split([',', ' '])
+            .map(str::trim)
+            .filter(|a| !a.is_empty())
+            .collect();
+        rest = rest[close + 1..].trim_start();
+
+        let length = |i: usize| args.get(i).map(|a| parse_val(a));
+        let number = |i: usize| args.get(i).and_then(|a| a.parse::<f32>().ok());
+        match name.as_str() {
+            "translate" => {
+                let x = length(0)?;
+                let y = length(1).unwrap_or(Val::Px(0.0));
+                transform.translation.x = add_val(transform.translation.x, x);
+                transform.translation.y = add_val(transform.translation.y, y);
+            }
+            "translatex" => {
+                transform.translation.x = add_val(transform.translation.x, length(0)?);
+            }
+            "translatey" => {
+                transform.translation.y = add_val(transform.translation.y, length(0)?);
+            }
+            "scale" => {
+                let x = number(0)?;
+                transform.scale *= Vec2::new(x, number(1).unwrap_or(x));
+            }
+            "scalex" => transform.scale.x *= number(0)?,
+            "scaley" => transform.scale.y *= number(0)?,
+            "rotate" => transform.rotation += parse_angle(args.first()?)?,
+            _ => {
+                log::warn!("Unsupported transform function '{}'", name);
+            }
+        }
+    }
+    Some(transform)
+}
+
+/// Parse CSS `transform-origin` (1–2 keywords, lengths or percentages).
+pub fn parse_transform_origin(value: &str) -> Val2 {
+    let mut x = None;
+    let mut y = None;
+    for token in split_css_values(value) {
+        match token.to_ascii_lowercase().as_str() {
+            "left" => x = Some(Val::Percent(0.0)),
+            "right" => x = Some(Val::Percent(100.0)),
+            "top" => y = Some(Val::Percent(0.0)),
+            "bottom" => y = Some(Val::Percent(100.0)),
+            "center" => {}
+            _ if x.is_none() => x = Some(parse_val(token)),
+            _ => y = Some(parse_val(token)),
+        }
+    }
+    Val2::new(
+        x.unwrap_or(Val::Percent(50.0)),
+        y.unwrap_or(Val::Percent(50.0)),
+    )
+}
+
+/// Host transform from `transform` / `transformOrigin`, or `None` when neither is set.
+pub fn style_transform(props: &StyleProps) -> Option<ReactTransform> {
+    if props.transform.is_none() && props.transform_origin.is_none() {
+        return None;
+    }
+    let mut transform = props
+        .transform
+        .as_deref()
+        .and_then(parse_transform)
+        .unwrap_or_default();
+    if let Some(origin) = props.transform_origin.as_deref() {
+        transform.origin = parse_transform_origin(origin);
+    }
+    Some(transform)
+}
+
+/// Serialize a (possibly interpolated) color back to CSS for displayed styles.
+pub fn color_to_css(color: Color) -> String {
+    let c = color.to_srgba();
+    format!(
+        "rgba({}, {}, {}, {})",
+        (c.red * 255.0).round() as u8,
+        (c.green * 255.0).round() as u8,
+        (c.blue * 255.0).round() as u8,
+        c.alpha
+    )
+}
+
+/// Serialize a (possibly interpolated) transform back to CSS for displayed styles.
+pub fn transform_to_css(transform: &ReactTransform) -> String {
+    format!(
+        "translate({}, {}) rotate({}rad) scale({}, {})",
+        val_to_css(transform.translation.x),
+        val_to_css(transform.translation.y),
+        transform.rotation,
+        transform.scale.x,
+        transform.scale.y
+    )
+}
+
+/// Serialize a length back to the CSS form [`parse_val`] reads.
+pub fn val_to_css(val: Val) -> String {
+    match val {
+        Val::Auto => "auto".to_string(),
+        Val::Px(px) => format!("{px}px"),
+        Val::Percent(p) => format!("{p}%"),
+        Val::Vw(v) => format!("{v}vw"),
+        Val::Vh(v) => format!("{v}vh"),
+        Val::VMin(v) => format!("{v}vmin"),
+        Val::VMax(v) => format!("{v}vmax"),
+    }
+}
+
 /// Parse aspect-ratio: `1.5`, `16/9`, `16 / 9`.
 pub fn parse_aspect_ratio(value: &str) -> Option<f32> {
     let value = value.trim();
@@ -1234,8 +1929,69 @@ fn parse_grid_auto_flow(value: &str) -> GridAutoFlow {
     }
 }
 
-/// Parse a single grid track sizing function into a RepeatedGridTrack (count 1).
-fn parse_grid_track(token: &str) -> Option<RepeatedGridTrack> {
+/// A grid length (`100px`, `25%`, `10vw`, `2rem`) as a Bevy `Val`.
+fn parse_grid_length(token: &str) -> Option<Val> {
+    match parse_css_length(token)? {
+        CssLength::Val(val) => Some(val),
+        // `rem` at the default root font size; tracks are not re-resolved.
+        CssLength::Calc(expr) if expr.is_absolute() => {
+            Some(Val::Px(expr.eval(&CalcContext::FALLBACK)))
+        }
+        CssLength::Calc(_) => {
+            log::warn!("Mixed-unit '{}' is not supported in grid tracks", token);
+            None
+        }
+    }
+}
+
+fn parse_min_track_sizing(token: &str) -> MinTrackSizingFunction {
+    match token.trim().to_lowercase().as_str() {
+        "auto" => MinTrackSizingFunction::Auto,
+        "min-content" | "mincontent" => MinTrackSizingFunction::MinContent,
+        "max-content" | "maxcontent" => MinTrackSizingFunction::MaxContent,
+        token => match parse_grid_length(token) {
+            Some(Val::Px(n)) => MinTrackSizingFunction::Px(n),
+            Some(Val::Percent(n)) => MinTrackSizingFunction::Percent(n),
+            Some(Val::Vw(n)) => MinTrackSizingFunction::Vw(n),
+            Some(Val::Vh(n)) => MinTrackSizingFunction::Vh(n),
+            Some(Val::VMin(n)) => MinTrackSizingFunction::VMin(n),
+            Some(Val::VMax(n)) => MinTrackSizingFunction::VMax(n),
+            _ => {
+                log::warn!("Invalid minmax() minimum '{}', defaulting to auto", token);
+                MinTrackSizingFunction::Auto
+            }
+        },
+    }
+}
+
+fn parse_max_track_sizing(token: &str) -> MaxTrackSizingFunction {
+    let token = token.trim().to_lowercase();
+    if let Some(fr) = token.strip_suffix("fr")
+        && let Ok(n) = fr.trim().parse::<f32>() {
+            return MaxTrackSizingFunction::Fraction(n);
+        }
+    match token.as_str() {
+        "auto" => MaxTrackSizingFunction::Auto,
+        "min-content" | "mincontent" => MaxTrackSizingFunction::MinContent,
+        "max-content" | "maxcontent" => MaxTrackSizingFunction::MaxContent,
+        token => match parse_grid_length(token) {
+            Some(Val::Px(n)) => MaxTrackSizingFunction::Px(n),
+            Some(Val::Percent(n)) => MaxTrackSizingFunction::Percent(n),
+            Some(Val::Vw(n)) => MaxTrackSizingFunction::Vw(n),
+            Some(Val::Vh(n)) => MaxTrackSizingFunction::Vh(n),
+            Some(Val::VMin(n)) => MaxTrackSizingFunction::VMin(n),
+            Some(Val::VMax(n)) => MaxTrackSizingFunction::VMax(n),
+            _ => {
+                log::warn!("Invalid minmax() maximum '{}', defaulting to auto", token);
+                MaxTrackSizingFunction::Auto
+            }
+        },
+    }
+}
+
+/// Parse a single grid track sizing function: a length, `fr`, `auto`,
+/// `min-content` / `max-content`, `fit-content()` or `minmax()`.
+fn parse_grid_track_size(token: &str) -> Option<GridTrack> {
     let token = token.trim().to_lowercase();
     if token.is_empty() {
         return None;
@@ -1256,215 +2012,189 @@ fn parse_grid_track(token: &str) -> Option<RepeatedGridTrack> {
             return Some(GridTrack::flex(n));
         }
 
-    if let Some(px) = token.strip_suffix("px")
-        && let Ok(n) = px.trim().parse::<f32>() {
-            return Some(GridTrack::px(n));
-        }
-
-    if let Some(pct) = token.strip_suffix('%')
-        && let Ok(n) = pct.trim().parse::<f32>() {
-            return Some(GridTrack::percent(n));
+    if let Some(inner) = token
+        .strip_prefix("minmax(")
+        .and_then(|s| s.strip_suffix(')'))
+    {
+        if let [min, max] = split_css_list(inner).as_slice() {
+            return Some(GridTrack::minmax(
+                parse_min_track_sizing(min),
+                parse_max_track_sizing(max),
+            ));
         }
+        log::warn!("minmax() takes two values: '{}', defaulting to auto", token);
+        return Some(GridTrack::auto());
+    }
 
     if let Some(inner) = token
         .strip_prefix("fit-content(")
         .and_then(|s| s.strip_suffix(')'))
     {
-        if let Some(px) = inner.strip_suffix("px")
-            && let Ok(n) = px.trim().parse::<f32>() {
-                return Some(GridTrack::fit_content_px(n));
-            }
-        if let Some(pct) = inner.strip_suffix('%')
-            && let Ok(n) = pct.trim().parse::<f32>() {
-                return Some(GridTrack::fit_content_percent(n));
-            }
+        match parse_grid_length(inner) {
+            Some(Val::Px(n)) => return Some(GridTrack::fit_content_px(n)),
+            Some(Val::Percent(n)) => return Some(GridTrack::fit_content_percent(n)),
+            _ => {}
+        }
     }
 
-    // Plain number → px
-    if let Ok(n) = token.parse::<f32>() {
-        return Some(GridTrack::px(n));
+    match parse_grid_length(&token) {
+        Some(Val::Px(n)) => Some(GridTrack::px(n)),
+        Some(Val::Percent(n)) => Some(GridTrack::percent(n)),
+        Some(Val::Vw(n)) => Some(GridTrack::vw(n)),
+        Some(Val::Vh(n)) => Some(GridTrack::vh(n)),
+        Some(Val::VMin(n)) => Some(GridTrack::vmin(n)),
+        Some(Val::VMax(n)) => Some(GridTrack::vmax(n)),
+        _ => {
+            log::warn!("Unknown grid track '{}', defaulting to auto", token);
+            Some(GridTrack::auto())
+        }
     }
+}
+
+/// Parse a single grid track sizing function into a RepeatedGridTrack (count 1).
+fn parse_grid_track(token: &str) -> Option<RepeatedGridTrack> {
+    parse_grid_track_size(token).map(Into::into)
+}
 
-    log::warn!("Unknown grid track '{}', defaulting to auto", token);
-    Some(GridTrack::auto())
+/// A `grid-template-columns` / `grid-template-rows` value: tracks plus the
+/// `[name]`d lines between them.
+#[derive(Debug, Clone, Default)]
+pub struct GridTrackList {
+    pub tracks: Vec<RepeatedGridTrack>,
+    /// Line names with their 1-based line number. Lines after an
+    /// `auto-fill` / `auto-fit` repeat count from the end (`-1` is the last).
+    pub line_names: Vec<(String, i16)>,
 }
 
-/// Parse `grid-template-columns` / `grid-template-rows` track lists.
-/// Supports space-separated tracks and `repeat(N, track)`.
-pub fn parse_grid_template(value: &str) -> Vec<RepeatedGridTrack> {
+/// Tracks and line names inside `repeat(…, <track-list>)`, names keyed by the
+/// number of tracks before them.
+fn parse_repeated_tracks(value: &str) -> (Vec<GridTrack>, Vec<(String, usize)>) {
     let mut tracks = Vec::new();
+    let mut names = Vec::new();
     let mut rest = value.trim();
-
     while !rest.is_empty() {
-        rest = rest.trim_start();
-        if rest.is_empty() {
-            break;
+        if let Some(after) = rest.strip_prefix('[') {
+            let end = after.find(']').unwrap_or(after.len());
+            names.extend(
+                after[..end]
+                    .split_whitespace()
+                    .map(|n| (n.to_string(), tracks.len())),
+            );
+            rest = after.get(end + 1..).unwrap_or("").trim_start();
+            continue;
         }
-
-        if let Some(after_repeat) = rest.strip_prefix("repeat(")
-            && let Some(end) = find_closing_paren(after_repeat) {
-                let inner = &after_repeat[..end];
-                if let Some((count_str, track_str)) = inner.split_once(',') {
-                    let count_str = count_str.trim().to_lowercase();
-                    let track_str = track_str.trim();
-                    let repetition = match count_str.as_str() {
-                        "auto-fill" | "autofill" => {
-                            // Auto-fill/fit only support fixed tracks via RepeatedGridTrack helpers;
-                            // fall back to a single auto track when unsupported.
-                            if let Some(t) = parse_grid_track(track_str) {
-                                tracks.push(t);
-                            }
-                            rest = after_repeat[end + 1..].trim_start();
-                            continue;
-                        }
-                        "auto-fit" | "autofit" => {
-                            if let Some(t) = parse_grid_track(track_str) {
-                                tracks.push(t);
-                            }
-                            rest = after_repeat[end + 1..].trim_start();
-                            continue;
-                        }
-                        _ => count_str.parse::<u16>().unwrap_or(1),
-                    };
-
-                    if let Some(base) = parse_grid_track(track_str) {
-                        // Expand integer repeats into N identical tracks
-                        for _ in 0..repetition {
-                            tracks.push(base.clone());
-                        }
-                    }
-                }
-                rest = after_repeat[end + 1..].trim_start();
-                continue;
-            }
-
-        // Take next whitespace-delimited token (or minmax/fit-content call)
         let (token, remaining) = next_css_token(rest);
-        if let Some(t) = parse_grid_track(token) {
-            tracks.push(t);
-        }
+        tracks.extend(parse_grid_track_size(token));
         rest = remaining;
     }
-
-    tracks
+    (tracks, names)
 }
 
-fn parse_grid_auto_tracks(value: &str) -> Vec<GridTrack> {
-    value
-        .split_whitespace()
-        .filter_map(|token| {
-            // parse_grid_track returns RepeatedGridTrack; extract via constructors again
-            let token = token.trim().to_lowercase();
-            if token == "auto" {
-                return Some(GridTrack::auto());
-            }
-            if token == "min-content" || token == "mincontent" {
-                return Some(GridTrack::min_content());
-            }
-            if token == "max-content" || token == "maxcontent" {
-                return Some(GridTrack::max_content());
-            }
-            if let Some(fr) = token.strip_suffix("fr")
-                && let Ok(n) = fr.trim().parse::<f32>() {
-                    return Some(GridTrack::flex(n));
-                }
-            if let Some(px) = token.strip_suffix("px")
-                && let Ok(n) = px.trim().parse::<f32>() {
-                    return Some(GridTrack::px(n));
-                }
-            if let Some(pct) = token.strip_suffix('%')
-                && let Ok(n) = pct.trim().parse::<f32>() {
-                    return Some(GridTrack::percent(n));
-                }
-            token.parse::<f32>().ok().map(GridTrack::px)
-        })
-        .collect()
-}
+/// Parse a track list with `[named]` lines, `minmax()`, `fit-content()` and
+/// `repeat(N | auto-fill | auto-fit, <tracks>)`. Integer repeats expand into
+/// their tracks; only one auto repeat is allowed per list.
+pub fn parse_grid_track_list(value: &str) -> GridTrackList {
+    let mut list = GridTrackList::default();
+    // (name, tracks before it, counted from the end)
+    let mut names: Vec<(String, usize, bool)> = Vec::new();
+    let mut line = 0usize;
+    let mut after_auto = false;
+    let mut rest = value.trim();
 
-/// Parse CSS grid-row / grid-column placement.
-pub fn parse_grid_placement(value: &str) -> GridPlacement {
-    let value = value.trim().to_lowercase();
-    if value == "auto" {
-        return GridPlacement::auto();
-    }
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix('[') {
+            let end = after.find(']').unwrap_or(after.len());
+            names.extend(
+                after[..end]
+                    .split_whitespace()
+                    .map(|n| (n.to_string(), line, after_auto)),
+            );
+            rest = after.get(end + 1..).unwrap_or("").trim_start();
+            continue;
+        }
 
-    let parts: Vec<&str> = value.split('/').map(|s| s.trim()).collect();
-    match parts.as_slice() {
-        [start] => {
-            if let Some(span) = start.strip_prefix("span ")
-                && let Ok(n) = span.trim().parse::<u16>() {
-                    return GridPlacement::span(n.max(1));
+        let (token, remaining) = next_css_token(rest);
+        rest = remaining;
+        let Some(inner) = token
+            .strip_prefix("repeat(")
+            .and_then(|s| s.strip_suffix(')'))
+        else {
+            list.tracks.extend(parse_grid_track(token));
+            line += 1;
+            continue;
+        };
+        let Some((count, tracks)) = inner.split_once(',') else {
+            log::warn!("Invalid grid repeat '{}'", token);
+            continue;
+        };
+        let (tracks, repeated_names) = parse_repeated_tracks(tracks);
+        let repetition = match count.trim().to_lowercase().as_str() {
+            "auto-fill" | "autofill" => GridTrackRepetition::AutoFill,
+            "auto-fit" | "autofit" => GridTrackRepetition::AutoFit,
+            count => GridTrackRepetition::Count(count.parse::<u16>().unwrap_or_else(|_| {
+                log::warn!("Invalid grid repeat count '{}', using 1", count);
+                1
+            })),
+        };
+        match repetition {
+            GridTrackRepetition::Count(n) => {
+                for i in 0..n as usize {
+                    names.extend(repeated_names.iter().map(|(name, offset)| {
+                        (name.clone(), line + i * tracks.len() + offset, after_auto)
+                    }));
                 }
-            if let Ok(n) = start.parse::<i16>()
-                && n != 0 {
-                    return GridPlacement::start(n);
+                for _ in 0..n {
+                    list.tracks
+                        .extend(tracks.iter().copied().map(RepeatedGridTrack::from));
                 }
-            GridPlacement::auto()
-        }
-        [start, end] => {
-            let start = start.trim();
-            let end = end.trim();
-            if let Some(span) = end.strip_prefix("span ") {
-                let span_n = span.trim().parse::<u16>().unwrap_or(1).max(1);
-                if let Ok(s) = start.parse::<i16>()
-                    && s != 0 {
-                        return GridPlacement::start_span(s, span_n);
-                    }
-                return GridPlacement::span(span_n);
+                line += n as usize * tracks.len();
             }
-            if start == "span" || start.starts_with("span ") {
-                // unusual; treat as span only
-                if let Some(span) = start.strip_prefix("span ")
-                    && let Ok(n) = span.trim().parse::<u16>() {
-                        return GridPlacement::span(n.max(1));
-                    }
+            _ if after_auto => {
+                log::warn!(
+                    "Only one auto-fill / auto-fit repeat is allowed: '{}'",
+                    value
+                );
             }
-            let s = start.parse::<i16>().ok();
-            let e = end.parse::<i16>().ok();
-            match (s, e) {
-                (Some(s), Some(e)) if s != 0 && e != 0 => GridPlacement::start_end(s, e),
-                (Some(s), _) if s != 0 => GridPlacement::start(s),
-                (_, Some(e)) if e != 0 => GridPlacement::end(e),
-                _ => GridPlacement::auto(),
+            repetition => {
+                // Names inside an auto repeat refer to its first repetition
+                names.extend(
+                    repeated_names
+                        .into_iter()
+                        .map(|(name, offset)| (name, line + offset, false)),
+                );
+                list.tracks
+                    .push(RepeatedGridTrack::repeat_many(repetition, tracks));
+                after_auto = true;
+                line = 0;
             }
         }
-        _ => GridPlacement::auto(),
     }
+
+    list.line_names = names
+        .into_iter()
+        .map(|(name, before, from_end)| {
+            let number = if from_end {
+                -((line - before) as i16 + 1)
+            } else {
+                before as i16 + 1
+            };
+            (name, number)
+        })
+        .collect();
+    list
 }
 
-fn build_grid_placement(
-    shorthand: Option<&String>,
-    start: Option<&CssScalar>,
-    end: Option<&CssScalar>,
-) -> Option<GridPlacement> {
-    if let Some(s) = shorthand {
-        return Some(parse_grid_placement(s));
-    }
-    match (start, end) {
-        (Some(s), Some(e)) => {
-            let start_n = s.0.trim().parse::<i16>().ok()?;
-            let end_n = e.0.trim().parse::<i16>().ok()?;
-            if start_n == 0 || end_n == 0 {
-                return None;
-            }
-            Some(GridPlacement::start_end(start_n, end_n))
-        }
-        (Some(s), None) => {
-            let start_n = s.0.trim().parse::<i16>().ok()?;
-            if start_n == 0 {
-                return None;
-            }
-            Some(GridPlacement::start(start_n))
-        }
-        (None, Some(e)) => {
-            let end_n = e.0.trim().parse::<i16>().ok()?;
-            if end_n == 0 {
-                return None;
-            }
-            Some(GridPlacement::end(end_n))
-        }
-        (None, None) => None,
-    }
+/// Parse `grid-template-columns` / `grid-template-rows` tracks; see
+/// [`parse_grid_track_list`].
+pub fn parse_grid_template(value: &str) -> Vec<RepeatedGridTrack> {
+    parse_grid_track_list(value).tracks
+}
+
+fn parse_grid_auto_tracks(value: &str) -> Vec<GridTrack> {
+    split_css_values(value)
+        .into_iter()
+        .filter_map(parse_grid_track_size)
+        .collect()
 }
 
 fn find_closing_paren(s: &str) -> Option<usize> {
@@ -1484,7 +2214,7 @@ fn find_closing_paren(s: &str) -> Option<usize> {
     None
 }
 
-fn next_css_token(s: &str) -> (&str, &str) {
+pub(crate) fn next_css_token(s: &str) -> (&str, &str) {
     let s = s.trim_start();
     if s.is_empty() {
         return ("", "");
@@ -1505,7 +2235,21 @@ fn next_css_token(s: &str) -> (&str, &str) {
     }
 }
 
-fn split_css_list(s: &str) -> Vec<&str> {
+/// Whitespace-separated values, keeping function calls (`calc(100% - 8px)`) whole.
+pub(crate) fn split_css_values(s: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut rest = s;
+    loop {
+        let (token, next) = next_css_token(rest);
+        if token.is_empty() {
+            return parts;
+        }
+        parts.push(token);
+        rest = next;
+    }
+}
+
+pub(crate) fn split_css_list(s: &str) -> Vec<&str> {
     let mut parts = Vec::new();
     let mut start = 0;
     let mut depth = 0usize;
@@ -1977,19 +2721,10 @@ pub fn json_to_style(props: &StyleProps) -> Node {
     if let Some(ref flow) = props.grid_auto_flow {
         style.grid_auto_flow = parse_grid_auto_flow(flow);
     }
-    if let Some(placement) = build_grid_placement(
-        props.grid_column.as_ref(),
-        props.grid_column_start.as_ref(),
-        props.grid_column_end.as_ref(),
-    ) {
-        style.grid_column = placement;
-    }
-    if let Some(placement) = build_grid_placement(
-        props.grid_row.as_ref(),
-        props.grid_row_start.as_ref(),
-        props.grid_row_end.as_ref(),
-    ) {
-        style.grid_row = placement;
+    // Named lines resolve to auto here; `resolve_grid_placements` fills them in
+    if let Some(lines) = GridItemLines::from_style(props) {
+        style.grid_row = lines.row(None);
+        style.grid_column = lines.column(None);
     }
 
     // Margins — multi-value shorthand, then per-side overrides
@@ -2062,7 +2797,7 @@ pub fn json_to_style(props: &StyleProps) -> Node {
 
     // Gap
     if let Some(ref g) = props.gap {
-        let parts: Vec<&str> = g.0.split_whitespace().collect();
+        let parts = split_css_values(&g.0);
         match parts.as_slice() {
             [both] => {
                 let val = parse_val(both);
@@ -2112,6 +2847,7 @@ pub fn json_to_style(props: &StyleProps) -> Node {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::react::grid::parse_grid_placement;
 
     #[test]
     fn test_parse_val() {
@@ -2321,6 +3057,48 @@ mod tests {
         assert_eq!(parse_grid_placement("5"), GridPlacement::start(5));
     }
 
+    #[test]
+    fn test_grid_minmax_auto_repeat_and_line_names() {
+        let list = parse_grid_track_list(
+            "[full-start] minmax(120px, 1fr) [main-start] repeat(auto-fill, minmax(64px, 1fr)) [main-end] 2rem [full-end]",
+        );
+        assert_eq!(
+            list.tracks,
+            vec![
+                GridTrack::minmax(
+                    MinTrackSizingFunction::Px(120.0),
+                    MaxTrackSizingFunction::Fraction(1.0),
+                ),
+                RepeatedGridTrack::minmax(
+                    GridTrackRepetition::AutoFill,
+                    MinTrackSizingFunction::Px(64.0),
+                    MaxTrackSizingFunction::Fraction(1.0),
+                ),
+                GridTrack::px(32.0),
+            ]
+        );
+        assert_eq!(
+            list.line_names,
+            vec![
+                ("full-start".to_string(), 1),
+                ("main-start".to_string(), 2),
+                ("main-end".to_string(), -2),
+                ("full-end".to_string(), -1),
+            ]
+        );
+
+        let repeated = parse_grid_track_list("repeat(2, [col] 40px) [last]");
+        assert_eq!(repeated.tracks.len(), 2);
+        assert_eq!(
+            repeated.line_names,
+            vec![
+                ("col".to_string(), 1),
+                ("col".to_string(), 2),
+                ("last".to_string(), 3),
+            ]
+        );
+    }
+
     #[test]
     fn test_grid_line_start_end_props() {
         let props: StyleProps = serde_json::from_str(
@@ -2497,6 +3275,22 @@ mod tests {
         assert!(bad.content.is_none());
     }
 
+    #[test]
+    fn test_white_space_and_line_clamp_helpers() {
+        let clamped: StyleProps = serde_json::from_str(
+            r#"{"whiteSpace": "pre", "textOverflow": "ellipsis", "WebkitLineClamp": 2}"#,
+        )
+        .unwrap();
+        assert_eq!(style_white_space(&clamped), Some(WhiteSpace::Pre));
+        assert!(style_text_ellipsis(&clamped));
+        assert_eq!(style_max_lines(&clamped), Some(2));
+        assert_eq!(
+            style_text_layout(&clamped).unwrap().linebreak,
+            LineBreak::NoWrap
+        );
+        assert_eq!(parse_white_space("break-spaces"), Some(WhiteSpace::PreWrap));
+    }
+
     #[test]
     fn test_text_and_image_helpers() {
         let props: StyleProps = serde_json::from_str(
@@ -2535,10 +3329,23 @@ mod tests {
         assert_eq!(parse_text_align("start"), Some(Justify::Left));
         assert_eq!(parse_line_break("break-all"), Some(LineBreak::AnyCharacter));
         assert_eq!(
-            style_font_family(&props).as_deref(),
-            Some("fonts/FiraSans.ttf")
+            style_font_families(&props),
+            Some(vec!["fonts/FiraSans.ttf".to_string()])
+        );
+        assert_eq!(
+            parse_font_families(r#"Inter, "Noto Sans", sans-serif"#),
+            Some(vec![
+                "Inter".to_string(),
+                "Noto Sans".to_string(),
+                "sans-serif".to_string()
+            ])
         );
-        assert!(parse_font_family("sans-serif").is_none());
+        assert!(parse_font_families("inherit").is_none());
+        assert_eq!(parse_font_weight("bold"), Some(700));
+        assert_eq!(parse_font_weight("600"), Some(600));
+        assert_eq!(parse_font_weight("heavy"), None);
+        assert_eq!(parse_font_style("oblique 10deg"), Some(FontStyle::Italic));
+
         assert_eq!(style_object_fit(&props), Some(NodeImageMode::Stretch));
         assert_eq!(parse_object_fit("contain"), NodeImageMode::Auto);
         assert!(matches!(
@@ -2689,4 +3496,139 @@ mod tests {
         assert!(props.unknown.is_empty());
         assert_eq!(props.background_color.as_deref(), Some("red"));
     }
+
+    #[test]
+    fn test_parse_transform_folds_functions() {
+        let t = parse_transform("translate(10px, 50%) rotate(90deg) scale(1.5)").unwrap();
+        assert_eq!(t.translation, Val2::new(Val::Px(10.0), Val::Percent(50.0)));
+        assert!((t.rotation - std::f32::consts::FRAC_PI_2).abs() < 1e-5);
+        assert_eq!(t.scale, Vec2::splat(1.5));
+        assert_eq!(t.origin, ReactTransform::CENTER);
+
+        let t =
+            parse_transform("translateX(4px) translateX(6px) scaleY(2) rotate(0.5turn)").unwrap();
+        assert_eq!(t.translation.x, Val::Px(10.0));
+        assert_eq!(t.scale, Vec2::new(1.0, 2.0));
+        assert!((t.rotation - std::f32::consts::PI).abs() < 1e-5);
+
+        assert_eq!(parse_transform("none"), Some(ReactTransform::IDENTITY));
+        assert_eq!(parse_transform("scale("), None);
+    }
+
+    #[test]
+    fn test_transform_origin_and_round_trip() {
+        assert_eq!(parse_transform_origin("top left"), Val2::ZERO);
+        assert_eq!(
+            parse_transform_origin("right"),
+            Val2::new(Val::Percent(100.0), Val::Percent(50.0))
+        );
+        assert_eq!(
+            parse_transform_origin("10px 100%"),
+            Val2::new(Val::Px(10.0), Val::Percent(100.0))
+        );
+
+        let props: StyleProps = serde_json::from_str(
+            r#"{"transform":"scale(2) rotate(45deg)","transformOrigin":"bottom"}"#,
+        )
+        .unwrap();
+        let transform = style_transform(&props).unwrap();
+        assert_eq!(
+            transform.origin,
+            Val2::new(Val::Percent(50.0), Val::Percent(100.0))
+        );
+        assert_eq!(
+            parse_transform(&transform_to_css(&transform)).map(|t| t.scale),
+            Some(transform.scale)
+        );
+        assert_eq!(style_transform(&StyleProps::default()), None);
+    }
+
+    #[test]
+    fn test_transition_timing_and_delay() {
+        let transitions = parse_transition_string(
+            "width 200ms cubic-bezier(0.1, 0.7, 1, 0.1) 50ms, opacity 1s steps(4, start), all 0.3s ease-out",
+        );
+        let width = transitions.spec("width").unwrap();
+        assert!((width.duration - 0.2).abs() < 0.001);
+        assert!((width.delay - 0.05).abs() < 0.001);
+        assert_eq!(
+            width.timing,
+            TimingFunction::CubicBezier(Vec2::new(0.1, 0.7), Vec2::new(1.0, 0.1))
+        );
+        assert_eq!(
+            transitions.spec("opacity").unwrap().timing,
+            TimingFunction::Steps(4, JumpAt::Start)
+        );
+        assert_eq!(
+            transitions.spec("height").unwrap().timing,
+            TimingFunction::EASE_OUT
+        );
+
+        let object: StyleProps = serde_json::from_str(
+            r#"{"transition":{"padding":"300ms ease-in-out","borderRadius":100}}"#,
+        )
+        .unwrap();
+        let padding = object.transition.spec("paddingLeft").unwrap();
+        assert!((padding.duration - 0.3).abs() < 0.001);
+        assert_eq!(padding.timing, TimingFunction::EASE_IN_OUT);
+        assert!(object.transition.spec("borderTopLeftRadius").is_some());
+        assert!(object.transition.spec("width").is_none());
+    }
+
+    #[test]
+    fn test_timing_functions_sample_css_curves() {
+        assert_eq!(parse_timing_function("bogus"), None);
+        assert_eq!(parse_timing_function("steps(0)"), None);
+        assert_eq!(TimingFunction::Linear.sample(0.25), 0.25);
+        assert_eq!(TimingFunction::Steps(4, JumpAt::End).sample(0.3), 0.25);
+        assert!(TimingFunction::EASE_IN.sample(0.5) < 0.5);
+        assert!(TimingFunction::EASE_OUT.sample(0.5) > 0.5);
+
+        let spec = TransitionSpec {
+            duration: 1.0,
+            delay: 0.5,
+            timing: TimingFunction::Linear,
+        };
+        assert_eq!(spec.progress(0.4), 0.0);
+        assert!((spec.progress(1.0) - 0.5).abs() < 1e-5);
+        assert_eq!(spec.progress(2.0), 1.0);
+    }
+
+    #[test]
+    fn test_css_vars_resolve_with_fallbacks_and_nesting() {
+        let lookup = |name: &str| match name {
+            "accent" => Some("var(--blue)".to_string()),
+            "blue" => Some("#0af".to_string()),
+            "space" => Some("8".to_string()),
+            "loop" => Some("var(--loop)".to_string()),
+            _ => None,
+        };
+        assert_eq!(
+            resolve_css_vars("var(--accent)", &lookup).as_deref(),
+            Some("#0af")
+        );
+        assert_eq!(
+            resolve_css_vars("var(--missing, rgb(1, 2, 3))", &lookup).as_deref(),
+            Some("rgb(1, 2, 3)")
+        );
+        assert_eq!(
+            resolve_css_vars("0 var(--space)px", &lookup).as_deref(),
+            Some("0 8px")
+        );
+        assert_eq!(resolve_css_vars("var(--missing)", &lookup), None);
+        assert_eq!(resolve_css_vars("var(--loop)", &lookup), None);
+
+        let style = serde_json::json!({
+            "padding": "var(--space)",
+            "backgroundColor": "var(--accent)",
+            "borderColor": "var(--missing)",
+            "hover": { "color": "var(--missing, white)" },
+        });
+        assert_eq!(style_var_names(&style), vec!["accent", "missing", "space"]);
+        let props = resolve_style_vars(&style, &lookup);
+        assert_eq!(props.padding.map(|v| v.0).as_deref(), Some("8px"));
+        assert_eq!(props.background_color.as_deref(), Some("#0af"));
+        assert!(props.border_color.is_none());
+        assert_eq!(props.hover.and_then(|h| h.color).as_deref(), Some("white"));
+    }
 }
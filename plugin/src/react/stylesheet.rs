@@ -0,0 +1,315 @@
+//! Style classes — styles registered once and referenced by `className`.
+//!
+//! [`ReactStyleSheet`] holds pre-parsed classes, registered from JS
+//! (`StyleSheet.create`, the `RegisterStyle` op) or from Rust so a game's design
+//! system lives in one place. Nodes with a `className`, or a `var()` or `media`
+//! block in their style, carry a [`ReactComposedStyle`]; [`apply_composed_styles`]
+//! cascades classes → inline `style` → matching media blocks, substitutes theme
+//! variables, and re-applies when a class is re-registered, a variable it reads
+//! changes or a breakpoint flips.
+
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use bevy::ui::ComputedUiRenderTargetInfo;
+use serde_json::Value;
+
+use crate::react::fonts::ReactFontRegistry;
+use crate::react::media::{MediaQuery, ReactViewport, resolve_media_styles};
+use crate::react::style::{
+    StyleProps, cascade_style_props, resolve_css_vars, resolve_style_vars, style_var_names,
+};
+use crate::react::systems::{ReactNode, apply_node_style};
+use crate::react::theme::{ReactTheme, lookup_var};
+
+/// One registered class: the parsed style plus what theming needs.
+#[derive(Debug, Clone)]
+pub struct StyleClass {
+    props: StyleProps,
+    /// Source JSON, re-resolved when the class uses `var()`.
+    raw: Value,
+    vars: Vec<String>,
+    revision: u64,
+}
+
+impl StyleClass {
+    pub fn props(&self) -> &StyleProps {
+        &self.props
+    }
+}
+
+/// Named style classes for the `className` prop.
+///
+/// ```ignore
+/// sheet.insert("button-primary", json!({
+///     "backgroundColor": "var(--accent)",
+///     "padding": "8px 16px",
+///     "hover": { "backgroundColor": "var(--accent-hover)" },
+/// }));
+/// ```
+#[derive(Resource, Debug, Default)]
+pub struct ReactStyleSheet {
+    classes: HashMap<String, StyleClass>,
+    next_revision: u64,
+}
+
+impl ReactStyleSheet {
+    /// Register (or replace) a class from a style object, as sent on `style`.
+    /// Nodes already using it restyle on the next frame.
+    pub fn insert(&mut self, name: impl Into<String>, style: Value) {
+        let name = name.into();
+        let mut props: StyleProps = serde_json::from_value(style.clone()).unwrap_or_else(|e| {
+            log::warn!("Failed to parse style class '{}': {}", name, e);
+            StyleProps::default()
+        });
+        props.finalize_parse();
+        self.next_revision += 1;
+        let class = StyleClass {
+            props,
+            vars: style_var_names(&style),
+            raw: style,
+            revision: self.next_revision,
+        };
+        self.classes.insert(name, class);
+    }
+
+    /// [`insert`](Self::insert) from JSON text.
+    pub fn insert_json(
+        &mut self,
+        name: impl Into<String>,
+        style_json: &str,
+    ) -> Result<(), serde_json::Error> {
+        self.insert(name, serde_json::from_str(style_json)?);
+        Ok(())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&StyleClass> {
+        self.classes.get(name)
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<StyleClass> {
+        self.classes.remove(name)
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.classes.contains_key(name)
+    }
+}
+
+/// `className` as sent by React: `"card primary"` or `["card", "primary"]`.
+fn parse_class_names(value: Option<&Value>) -> Vec<String> {
+    match value {
+        Some(Value::String(s)) => s.split_whitespace().map(str::to_string).collect(),
+        Some(Value::Array(items)) => items
+            .iter()
+            .filter_map(Value::as_str)
+            .flat_map(str::split_whitespace)
+            .map(str::to_string)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Inputs a composed style was last built from; unchanged inputs skip the rebuild.
+#[derive(Debug, Clone, PartialEq)]
+struct ComposedInputs {
+    /// Revision of each class, `None` while unregistered.
+    revisions: Vec<Option<u64>>,
+    /// Resolved value of each variable in [`ReactComposedStyle::vars`].
+    values: Vec<Option<String>>,
+    /// Whether each media block (classes first, then inline) matches.
+    media: Vec<bool>,
+}
+
+/// A node style composed on the host from style classes, the inline `style`
+/// and theme variables.
+#[derive(Component, Debug, Clone)]
+pub struct ReactComposedStyle {
+    classes: Vec<String>,
+    inline: Option<StyleProps>,
+    /// Inline JSON, re-resolved when it uses `var()`.
+    inline_raw: Value,
+    inline_vars: Vec<String>,
+    inline_media: Vec<MediaQuery>,
+    vars: Vec<String>,
+    is_text: bool,
+    fallback_font: Option<Handle<Font>>,
+    applied: Option<ComposedInputs>,
+}
+
+impl ReactComposedStyle {
+    /// `Some` when the props use `className`, or a `var()` or `media` in `style`.
+    pub fn from_props(
+        props: &Value,
+        is_text: bool,
+        fallback_font: Option<&Handle<Font>>,
+    ) -> Option<Self> {
+        let classes = parse_class_names(props.get("className"));
+        let inline_raw = props
+            .get("style")
+            .filter(|s| s.is_object())
+            .cloned()
+            .unwrap_or(Value::Null);
+        let inline_vars = style_var_names(&inline_raw);
+        let inline_media: Vec<MediaQuery> = inline_raw
+            .get("media")
+            .and_then(Value::as_object)
+            .map(|media| media.keys().filter_map(|q| MediaQuery::parse(q)).collect())
+            .unwrap_or_default();
+        if classes.is_empty() && inline_vars.is_empty() && inline_media.is_empty() {
+            return None;
+        }
+
+        let inline = (!inline_raw.is_null() && inline_vars.is_empty()).then(|| {
+            let mut style: StyleProps =
+                serde_json::from_value(inline_raw.clone()).unwrap_or_default();
+            style.finalize_parse();
+            style
+        });
+        Some(Self {
+            classes,
+            inline,
+            inline_raw,
+            vars: inline_vars.clone(),
+            inline_vars,
+            inline_media,
+            is_text,
+            fallback_font: fallback_font.cloned(),
+            applied: None,
+        })
+    }
+
+    /// Class names in cascade order (later classes win, inline `style` last).
+    pub fn classes(&self) -> &[String] {
+        &self.classes
+    }
+
+    /// Theme variables (without `--`) read by the classes and inline style.
+    pub fn vars(&self) -> &[String] {
+        &self.vars
+    }
+}
+
+/// Insert or clear a node's [`ReactComposedStyle`] after a create / update.
+pub(crate) fn sync_composed_style(
+    commands: &mut Commands,
+    entity: Entity,
+    composed: Option<ReactComposedStyle>,
+) {
+    match composed {
+        Some(composed) => {
+            commands.entity(entity).insert(composed);
+        }
+        None => {
+            commands.entity(entity).remove::<ReactComposedStyle>();
+        }
+    }
+}
+
+/// Compose and apply node styles that are new, or whose classes, theme
+/// variables or matching media queries changed since they were applied. Runs
+/// right after React messages are processed, so nodes are already attached to
+/// their theme scopes.
+#[allow(clippy::too_many_arguments)]
+pub fn apply_composed_styles(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    fonts: Option<Res<ReactFontRegistry>>,
+    sheet: Option<Res<ReactStyleSheet>>,
+    theme: Option<Res<ReactTheme>>,
+    viewport: Option<Res<ReactViewport>>,
+    scopes: Query<Ref<ReactTheme>>,
+    mut removed_scopes: RemovedComponents<ReactTheme>,
+    reparented: Query<(), (Changed<ChildOf>, With<ReactNode>)>,
+    parents: Query<&ChildOf>,
+    mut nodes: Query<(
+        Entity,
+        &mut ReactComposedStyle,
+        Option<Ref<ComputedUiRenderTargetInfo>>,
+    )>,
+) {
+    let scopes_removed = removed_scopes.read().count() > 0;
+    let inputs_changed = scopes_removed
+        || sheet.as_ref().is_some_and(|sheet| sheet.is_changed())
+        || theme.as_ref().is_some_and(|theme| theme.is_changed())
+        || viewport.as_ref().is_some_and(|v| v.is_changed())
+        || scopes.iter().any(|scope| scope.is_changed())
+        || !reparented.is_empty();
+    let sheet = sheet.as_deref();
+    let theme = theme.as_deref();
+    let window_viewport = viewport.as_deref().copied().unwrap_or_default();
+    let no_fonts = ReactFontRegistry::default();
+    let fonts = fonts.as_deref().unwrap_or(&no_fonts);
+
+    for (entity, mut composed, target) in &mut nodes {
+        let target_changed = target.as_ref().is_some_and(|target| target.is_changed());
+        if composed.applied.is_some() && !inputs_changed && !target_changed {
+            continue;
+        }
+        let viewport = target
+            .as_deref()
+            .and_then(ReactViewport::from_target)
+            .unwrap_or(window_viewport);
+        let classes: Vec<Option<&StyleClass>> = composed
+            .classes
+            .iter()
+            .map(|name| sheet.and_then(|sheet| sheet.get(name)))
+            .collect();
+
+        let mut vars = composed.inline_vars.clone();
+        vars.extend(
+            classes
+                .iter()
+                .flatten()
+                .flat_map(|class| class.vars.iter().cloned()),
+        );
+        vars.sort();
+        vars.dedup();
+
+        let lookup = |name: &str| lookup_var(entity, name, theme, &scopes, &parents);
+        let inputs = ComposedInputs {
+            revisions: classes.iter().map(|c| c.map(|c| c.revision)).collect(),
+            values: vars
+                .iter()
+                .map(|name| lookup(name).and_then(|value| resolve_css_vars(&value, &lookup)))
+                .collect(),
+            media: classes
+                .iter()
+                .flatten()
+                .flat_map(|class| class.props.media.matches(&viewport))
+                .chain(composed.inline_media.iter().map(|q| q.matches(&viewport)))
+                .collect(),
+        };
+        if composed.applied.as_ref() == Some(&inputs) {
+            continue;
+        }
+
+        let mut style = StyleProps::default();
+        for class in classes.iter().flatten() {
+            style = if class.vars.is_empty() {
+                cascade_style_props(&style, &class.props)
+            } else {
+                cascade_style_props(&style, &resolve_style_vars(&class.raw, &lookup))
+            };
+        }
+        if let Some(inline) = composed.inline.as_ref() {
+            style = cascade_style_props(&style, inline);
+        } else if !composed.inline_vars.is_empty() {
+            let resolved = resolve_style_vars(&composed.inline_raw, &lookup);
+            style = cascade_style_props(&style, &resolved);
+        }
+        let style = resolve_media_styles(&style, &viewport);
+
+        apply_node_style(
+            &mut commands,
+            entity,
+            Some(&style),
+            composed.is_text,
+            &asset_server,
+            fonts,
+            composed.fallback_font.as_ref(),
+        );
+        let composed = composed.bypass_change_detection();
+        composed.vars = vars;
+        composed.applied = Some(inputs);
+    }
+}
@@ -0,0 +1,366 @@
+//! `<svg>` host element: SVG rasterized to an [`Image`] on the CPU.
+//!
+//! `markup` (inline SVG) or `src` (an `*.svg` asset loaded by
+//! [`ReactSvgLoader`]) is parsed once; [`measure_react_svgs`] gives the node
+//! the document's intrinsic size, and [`rasterize_react_svgs`] renders it
+//! with resvg at the laid-out content box in physical pixels, again whenever
+//! that size changes. `style.color` is the document's `currentColor`;
+//! `style.tint` multiplies the rendered image like any `<image>`.
+
+use std::sync::Arc;
+
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext, RenderAssetUsages};
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy::ui::{ComputedUiRenderTargetInfo, ContentSize};
+use resvg::{tiny_skia, usvg};
+
+use crate::react::style::{NodeProps, parse_color};
+
+/// Largest raster edge in physical pixels.
+const MAX_SVG_RASTER_SIZE: u32 = 4096;
+
+/// Source text of an `*.svg` asset.
+#[derive(Asset, TypePath, Debug, Clone)]
+pub struct ReactSvgDocument {
+    pub source: String,
+}
+
+/// Loads `*.svg` files as [`ReactSvgDocument`].
+#[derive(Default)]
+pub struct ReactSvgLoader;
+
+impl AssetLoader for ReactSvgLoader {
+    type Asset = ReactSvgDocument;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let source = String::from_utf8(bytes)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        Ok(ReactSvgDocument { source })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["svg"]
+    }
+}
+
+/// Where an `<svg>` element's document comes from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReactSvgSource {
+    Markup(String),
+    Asset(Handle<ReactSvgDocument>),
+}
+
+/// An `<svg>` node: its document, `currentColor`, and the last raster.
+#[derive(Component, Clone)]
+pub struct ReactSvg {
+    source: Option<ReactSvgSource>,
+    color: Option<Color>,
+    tree: Option<Arc<usvg::Tree>>,
+    image: Option<Handle<Image>>,
+    rendered: UVec2,
+}
+
+impl ReactSvg {
+    pub fn new(source: Option<ReactSvgSource>, color: Option<Color>) -> Self {
+        Self {
+            source,
+            color,
+            tree: None,
+            image: None,
+            rendered: UVec2::ZERO,
+        }
+    }
+
+    /// Size of the current raster in physical pixels (zero before the first one).
+    pub fn rendered_size(&self) -> UVec2 {
+        self.rendered
+    }
+
+    /// Handle of the rasterized [`Image`], once rendered.
+    pub fn image(&self) -> Option<&Handle<Image>> {
+        self.image.as_ref()
+    }
+
+    /// `currentColor` (from `style.color`); `None` renders it black.
+    pub fn color(&self) -> Option<Color> {
+        self.color
+    }
+
+    /// Change `currentColor`; the document is re-parsed and rasterized again.
+    pub fn set_color(&mut self, color: Option<Color>) {
+        self.color = color;
+    }
+
+    fn source_text<'a>(&'a self, documents: &'a Assets<ReactSvgDocument>) -> Option<&'a str> {
+        match self.source.as_ref()? {
+            ReactSvgSource::Markup(markup) => Some(markup),
+            ReactSvgSource::Asset(handle) => documents.get(handle).map(|doc| doc.source.as_str()),
+        }
+    }
+}
+
+/// [`ReactSvg`] for an `<svg>`'s props: `markup` wins over `src`.
+pub(crate) fn react_svg_from_props(props: &NodeProps, asset_server: &AssetServer) -> ReactSvg {
+    let source = match (&props.markup, &props.src) {
+        (Some(markup), _) => Some(ReactSvgSource::Markup(markup.clone())),
+        (None, Some(src)) => Some(ReactSvgSource::Asset(asset_server.load(src))),
+        (None, None) => None,
+    };
+    let color = props
+        .style
+        .as_ref()
+        .and_then(|style| style.color.as_deref())
+        .and_then(parse_color);
+    ReactSvg::new(source, color)
+}
+
+/// `<svg>` prop update: swap the document, keeping the raster when it did not
+/// change. `color` and `tint` come from the resolved style in `apply_node_style`.
+pub(crate) fn sync_react_svg(
+    commands: &mut Commands,
+    entity: Entity,
+    props: &NodeProps,
+    asset_server: &AssetServer,
+) {
+    let mut svg = react_svg_from_props(props, asset_server);
+    commands.queue(move |world: &mut World| {
+        let Ok(mut entity_mut) = world.get_entity_mut(entity) else {
+            return;
+        };
+        if let Some(current) = entity_mut.get::<ReactSvg>() {
+            if current.source == svg.source {
+                return;
+            }
+            // Reuse the image asset so the node never flashes empty.
+            svg.color = current.color;
+            svg.image = current.image.clone();
+        }
+        entity_mut.insert(svg);
+    });
+}
+
+/// Parse SVG source, resolving `currentColor` to `color` (black when unset).
+pub fn parse_svg(source: &str, color: Option<Color>) -> Result<usvg::Tree, usvg::Error> {
+    let options = usvg::Options {
+        style_sheet: color.map(|color| format!("svg {{ color: {} }}", color.to_srgba().to_hex())),
+        ..default()
+    };
+    usvg::Tree::from_str(source, &options)
+}
+
+/// Render `tree` into a `size` image, scaled to fit and centered
+/// (`preserveAspectRatio="xMidYMid meet"`).
+pub fn rasterize_svg(tree: &usvg::Tree, size: UVec2) -> Option<Image> {
+    let mut pixmap = tiny_skia::Pixmap::new(size.x, size.y)?;
+    let intrinsic = tree.size();
+    let scale = (size.x as f32 / intrinsic.width()).min(size.y as f32 / intrinsic.height());
+    let transform = tiny_skia::Transform::from_translate(
+        (size.x as f32 - intrinsic.width() * scale) / 2.0,
+        (size.y as f32 - intrinsic.height() * scale) / 2.0,
+    )
+    .pre_scale(scale, scale);
+    resvg::render(tree, transform, &mut pixmap.as_mut());
+
+    let data = pixmap
+        .pixels()
+        .iter()
+        .flat_map(|pixel| {
+            let color = pixel.demultiply();
+            [color.red(), color.green(), color.blue(), color.alpha()]
+        })
+        .collect();
+    Some(Image::new(
+        Extent3d {
+            width: size.x,
+            height: size.y,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    ))
+}
+
+/// Parse new or reloaded SVG documents and size their nodes to the
+/// document's intrinsic size. Runs between `UiSystems::Content` (which drops
+/// the measure of a changed [`ImageNode`]) and `UiSystems::Layout`.
+#[allow(clippy::type_complexity)]
+pub fn measure_react_svgs(
+    documents: Res<Assets<ReactSvgDocument>>,
+    mut document_events: MessageReader<AssetEvent<ReactSvgDocument>>,
+    mut svgs: Query<(
+        &mut ReactSvg,
+        &mut ContentSize,
+        Ref<ImageNode>,
+        Ref<ComputedUiRenderTargetInfo>,
+    )>,
+) {
+    let reloaded_ids: Vec<AssetId<ReactSvgDocument>> = document_events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } => Some(*id),
+            _ => None,
+        })
+        .collect();
+    for (mut svg, mut content_size, image_node, target) in &mut svgs {
+        let reloaded = match &svg.source {
+            Some(ReactSvgSource::Asset(handle)) => reloaded_ids.contains(&handle.id()),
+            _ => false,
+        };
+        let reparse = reloaded || svg.is_changed();
+        if reparse {
+            // The parsed tree is cache state: keep it out of change detection.
+            let svg = svg.bypass_change_detection();
+            svg.tree = svg.source_text(&documents).and_then(|source| {
+                parse_svg(source, svg.color)
+                    .inspect_err(|err| log::warn!("Failed to parse SVG: {err}"))
+                    .ok()
+                    .map(Arc::new)
+            });
+            svg.rendered = UVec2::ZERO;
+        }
+        let Some(tree) = svg.tree.as_ref() else {
+            continue;
+        };
+        if reparse || image_node.is_changed() || target.is_changed() {
+            let intrinsic = Vec2::new(tree.size().width(), tree.size().height());
+            *content_size = ContentSize::fixed_size(intrinsic * target.scale_factor());
+        }
+    }
+}
+
+/// Re-render each `<svg>` whose content box (in physical pixels) or document
+/// changed, reusing its image asset.
+pub fn rasterize_react_svgs(
+    mut images: ResMut<Assets<Image>>,
+    mut svgs: Query<(&mut ReactSvg, &mut ImageNode, &ComputedNode)>,
+) {
+    for (mut svg, mut image_node, computed) in &mut svgs {
+        let Some(tree) = svg.tree.clone() else {
+            continue;
+        };
+        let inset = computed.content_inset();
+        let content = computed.size - Vec2::new(inset.left + inset.right, inset.top + inset.bottom);
+        let size = content
+            .round()
+            .max(Vec2::ZERO)
+            .as_uvec2()
+            .min(UVec2::splat(MAX_SVG_RASTER_SIZE));
+        if size.x == 0 || size.y == 0 || size == svg.rendered {
+            continue;
+        }
+        let Some(image) = rasterize_svg(&tree, size) else {
+            continue;
+        };
+        let svg = svg.bypass_change_detection();
+        svg.rendered = size;
+        match svg.image.as_ref() {
+            Some(handle) if images.insert(handle, image.clone()).is_ok() => {}
+            _ => svg.image = Some(images.add(image)),
+        }
+        if let Some(handle) = svg.image.as_ref()
+            && image_node.image != *handle
+        {
+            image_node.image = handle.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::asset::AssetPlugin;
+
+    use super::*;
+
+    const DOT: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" width="20" height="10">
+        <rect width="20" height="10" fill="currentColor"/>
+    </svg>"#;
+
+    fn pixel(image: &Image, x: u32, y: u32) -> [u8; 4] {
+        let i = ((y * image.width() + x) * 4) as usize;
+        image.data.as_ref().unwrap()[i..i + 4].try_into().unwrap()
+    }
+
+    #[test]
+    fn test_rasterize_svg_current_color_and_fit() {
+        let tree = parse_svg(DOT, Some(Color::srgb(1.0, 0.0, 0.0))).unwrap();
+        let image = rasterize_svg(&tree, UVec2::new(8, 8)).unwrap();
+        assert_eq!(image.size(), UVec2::new(8, 8));
+        // 20×10 fitted into 8×8 fills rows 2..6.
+        assert_eq!(pixel(&image, 4, 4), [255, 0, 0, 255]);
+        assert_eq!(pixel(&image, 4, 0)[3], 0);
+        assert_eq!(pixel(&image, 4, 7)[3], 0);
+
+        let black = rasterize_svg(&parse_svg(DOT, None).unwrap(), UVec2::new(8, 8)).unwrap();
+        assert_eq!(pixel(&black, 4, 4), [0, 0, 0, 255]);
+
+        assert!(parse_svg("<svg", None).is_err());
+    }
+
+    #[test]
+    fn test_svg_node_rerasterizes_on_resize() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Image>()
+            .init_asset::<ReactSvgDocument>()
+            .add_systems(Update, (measure_react_svgs, rasterize_react_svgs).chain());
+        let svg = ReactSvg::new(
+            Some(ReactSvgSource::Markup(DOT.to_string())),
+            Some(Color::srgb(0.0, 0.0, 1.0)),
+        );
+        let entity = app
+            .world_mut()
+            .spawn((
+                svg,
+                ImageNode::default(),
+                ContentSize::default(),
+                ComputedUiRenderTargetInfo::default(),
+                ComputedNode {
+                    size: Vec2::new(40.0, 20.0),
+                    ..default()
+                },
+            ))
+            .id();
+        let raster = |app: &App| {
+            let svg = app.world().get::<ReactSvg>(entity).unwrap();
+            let handle = svg.image().unwrap().clone();
+            assert_eq!(app.world().get::<ImageNode>(entity).unwrap().image, handle);
+            let image = app
+                .world()
+                .resource::<Assets<Image>>()
+                .get(&handle)
+                .unwrap();
+            (
+                handle,
+                image.size(),
+                pixel(image, image.width() / 2, image.height() / 2),
+            )
+        };
+
+        app.update();
+        let (first, size, center) = raster(&app);
+        assert_eq!(size, UVec2::new(40, 20));
+        assert_eq!(center, [0, 0, 255, 255]);
+
+        app.world_mut()
+            .get_mut::<ComputedNode>(entity)
+            .unwrap()
+            .size = Vec2::new(64.0, 32.0);
+        app.update();
+        let (second, size, _) = raster(&app);
+        assert_eq!(size, UVec2::new(64, 32));
+        assert_eq!(first, second);
+    }
+}
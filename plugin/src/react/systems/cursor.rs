@@ -0,0 +1,319 @@
+//! CSS `cursor` → the primary window's [`CursorIcon`] while the pointer is over a node.
+//!
+//! Nodes with a `cursor` style (base, `hover` or `pressed`) carry [`ReactCursor`].
+//! [`apply_react_cursor`] takes the innermost hovered node (highest in the UI
+//! stack) and walks up to the nearest node with a cursor, since CSS `cursor`
+//! inherits; text inputs default to the I-beam. The window's own cursor comes
+//! back once no React node asks for one.
+
+use bevy::prelude::*;
+use bevy::window::{CursorIcon, CustomCursor, CustomCursorImage, PrimaryWindow, SystemCursorIcon};
+
+use crate::react::style::StyleProps;
+use crate::react::systems::types::{Focusable, ReactNode};
+
+/// Cursors resolved from a node's style and its `hover` / `pressed` overrides.
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct ReactCursor {
+    pub base: Option<CursorIcon>,
+    pub hover: Option<CursorIcon>,
+    pub pressed: Option<CursorIcon>,
+}
+
+impl ReactCursor {
+    /// `None` when neither the style nor its overrides set a cursor.
+    pub fn from_style(style: &StyleProps, asset_server: &AssetServer) -> Option<Self> {
+        let parse = |style: Option<&StyleProps>| {
+            style
+                .and_then(|style| style.cursor.as_deref())
+                .and_then(|value| parse_cursor(value, asset_server))
+        };
+        let cursor = Self {
+            base: parse(Some(style)),
+            hover: parse(style.hover.as_deref()),
+            pressed: parse(style.pressed.as_deref()),
+        };
+        (cursor != Self::default()).then_some(cursor)
+    }
+
+    /// Cursor while hovered (every node under the pointer counts) or pressed.
+    fn resolve(&self, pressed: bool) -> Option<&CursorIcon> {
+        let hovered = self.hover.as_ref().or(self.base.as_ref());
+        if pressed {
+            self.pressed.as_ref().or(hovered)
+        } else {
+            hovered
+        }
+    }
+}
+
+fn system_cursor(keyword: &str) -> Option<SystemCursorIcon> {
+    Some(match keyword {
+        "default" => SystemCursorIcon::Default,
+        "context-menu" => SystemCursorIcon::ContextMenu,
+        "help" => SystemCursorIcon::Help,
+        "pointer" => SystemCursorIcon::Pointer,
+        "progress" => SystemCursorIcon::Progress,
+        "wait" => SystemCursorIcon::Wait,
+        "cell" => SystemCursorIcon::Cell,
+        "crosshair" => SystemCursorIcon::Crosshair,
+        "text" => SystemCursorIcon::Text,
+        "vertical-text" => SystemCursorIcon::VerticalText,
+        "alias" => SystemCursorIcon::Alias,
+        "copy" => SystemCursorIcon::Copy,
+        "move" => SystemCursorIcon::Move,
+        "no-drop" => SystemCursorIcon::NoDrop,
+        "not-allowed" => SystemCursorIcon::NotAllowed,
+        "grab" => SystemCursorIcon::Grab,
+        "grabbing" => SystemCursorIcon::Grabbing,
+        "e-resize" => SystemCursorIcon::EResize,
+        "n-resize" => SystemCursorIcon::NResize,
+        "ne-resize" => SystemCursorIcon::NeResize,
+        "nw-resize" => SystemCursorIcon::NwResize,
+        "s-resize" => SystemCursorIcon::SResize,
+        "se-resize" => SystemCursorIcon::SeResize,
+        "sw-resize" => SystemCursorIcon::SwResize,
+        "w-resize" => SystemCursorIcon::WResize,
+        "ew-resize" => SystemCursorIcon::EwResize,
+        "ns-resize" => SystemCursorIcon::NsResize,
+        "nesw-resize" => SystemCursorIcon::NeswResize,
+        "nwse-resize" => SystemCursorIcon::NwseResize,
+        "col-resize" => SystemCursorIcon::ColResize,
+        "row-resize" => SystemCursorIcon::RowResize,
+        "all-scroll" => SystemCursorIcon::AllScroll,
+        "zoom-in" => SystemCursorIcon::ZoomIn,
+        "zoom-out" => SystemCursorIcon::ZoomOut,
+        _ => return None,
+    })
+}
+
+/// `url(path) x y` → a custom image cursor with its hotspot (default `0 0`).
+fn image_cursor(value: &str, asset_server: &AssetServer) -> Option<CursorIcon> {
+    let rest = value.strip_prefix("url(")?;
+    let (path, hotspot) = rest.split_once(')')?;
+    let path = path.trim().trim_matches(|c| c == '"' || c == '\'');
+    let mut coords = hotspot.split_whitespace().map(str::parse::<u16>);
+    let hotspot = match (coords.next(), coords.next()) {
+        (None, _) => (0, 0),
+        (Some(Ok(x)), Some(Ok(y))) => (x, y),
+        _ => return None,
+    };
+    Some(CursorIcon::Custom(CustomCursor::Image(CustomCursorImage {
+        handle: asset_server.load(path.to_string()),
+        hotspot,
+        ..default()
+    })))
+}
+
+/// Parse CSS `cursor`: a keyword (`"pointer"`, `"not-allowed"`, ...) or
+/// `"url(cursors/sword.png) 4 4, pointer"`; the first usable entry wins.
+/// `"auto"` and unknown values give `None`, leaving the cursor to ancestors.
+pub fn parse_cursor(value: &str, asset_server: &AssetServer) -> Option<CursorIcon> {
+    value.split(',').find_map(|entry| {
+        let entry = entry.trim();
+        if entry.starts_with("url(") {
+            return image_cursor(entry, asset_server);
+        }
+        system_cursor(&entry.to_lowercase()).map(CursorIcon::System)
+    })
+}
+
+/// Insert or clear [`ReactCursor`] for a node's resolved `style` (create,
+/// update and class / theme restyle paths).
+pub(crate) fn sync_react_cursor(
+    commands: &mut Commands,
+    entity: Entity,
+    style: Option<&StyleProps>,
+    asset_server: &AssetServer,
+) {
+    let cursor = style.and_then(|style| ReactCursor::from_style(style, asset_server));
+    commands.queue(move |world: &mut World| {
+        let Ok(mut entity_mut) = world.get_entity_mut(entity) else {
+            return;
+        };
+        match cursor {
+            Some(cursor) => {
+                if entity_mut.get::<ReactCursor>() != Some(&cursor) {
+                    entity_mut.insert(cursor);
+                }
+            }
+            None => {
+                entity_mut.remove::<ReactCursor>();
+            }
+        }
+    });
+}
+
+/// The window's cursor from before React set one.
+#[derive(Default)]
+pub struct ReactCursorState {
+    previous: Option<Option<CursorIcon>>,
+}
+
+fn node_cursor(
+    entity: Entity,
+    nodes: &Query<(Option<&ReactCursor>, Option<&Interaction>, Has<Focusable>)>,
+    parents: &Query<&ChildOf>,
+) -> Option<CursorIcon> {
+    let mut current = Some(entity);
+    while let Some(entity) = current {
+        if let Ok((cursor, interaction, text_input)) = nodes.get(entity) {
+            let pressed = interaction == Some(&Interaction::Pressed);
+            if let Some(icon) = cursor.and_then(|cursor| cursor.resolve(pressed)) {
+                return Some(icon.clone());
+            }
+            if text_input {
+                return Some(SystemCursorIcon::Text.into());
+            }
+        }
+        current = parents.get(entity).ok().map(ChildOf::parent);
+    }
+    None
+}
+
+/// Set the primary window's [`CursorIcon`] from the innermost hovered node.
+/// Runs after `UiSystems::Focus` has updated [`Interaction`].
+#[allow(clippy::type_complexity)]
+pub fn apply_react_cursor(
+    mut commands: Commands,
+    hovered: Query<(Entity, &Interaction, &ComputedNode, Option<&Pickable>), With<ReactNode>>,
+    nodes: Query<(Option<&ReactCursor>, Option<&Interaction>, Has<Focusable>)>,
+    parents: Query<&ChildOf>,
+    windows: Query<(Entity, Option<&CursorIcon>), With<PrimaryWindow>>,
+    mut state: Local<ReactCursorState>,
+) {
+    let Ok((window, current)) = windows.single() else {
+        return;
+    };
+    // `pointerEvents: "none"` nodes are hovered through, not hovered.
+    let innermost = hovered
+        .iter()
+        .filter(|(_, interaction, _, pickable)| {
+            **interaction != Interaction::None && pickable.is_none_or(|p| p.is_hoverable)
+        })
+        .max_by_key(|(_, _, node, _)| node.stack_index)
+        .map(|(entity, ..)| entity);
+
+    match innermost.and_then(|entity| node_cursor(entity, &nodes, &parents)) {
+        Some(icon) => {
+            if state.previous.is_none() {
+                state.previous = Some(current.cloned());
+            }
+            if current != Some(&icon) {
+                commands.entity(window).insert(icon);
+            }
+        }
+        None => match state.previous.take() {
+            Some(Some(previous)) => {
+                commands.entity(window).insert(previous);
+            }
+            Some(None) => {
+                commands.entity(window).remove::<CursorIcon>();
+            }
+            None => {}
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::asset::AssetPlugin;
+
+    use super::*;
+
+    fn system(icon: SystemCursorIcon) -> Option<CursorIcon> {
+        Some(CursorIcon::System(icon))
+    }
+
+    #[test]
+    fn test_parse_cursor() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Image>();
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let parse = |value: &str| parse_cursor(value, &asset_server);
+
+        assert_eq!(parse("pointer"), system(SystemCursorIcon::Pointer));
+        assert_eq!(parse("Not-Allowed"), system(SystemCursorIcon::NotAllowed));
+        assert_eq!(parse("bogus, grab"), system(SystemCursorIcon::Grab));
+        assert!(parse("auto").is_none());
+
+        match parse("url('cursors/sword.png') 4 6, pointer") {
+            Some(CursorIcon::Custom(CustomCursor::Image(image))) => {
+                assert_eq!(image.hotspot, (4, 6));
+                assert_eq!(
+                    image.handle.path().map(|p| p.to_string()).as_deref(),
+                    Some("cursors/sword.png")
+                );
+            }
+            other => panic!("unexpected cursor {other:?}"),
+        }
+        assert_eq!(
+            parse("url(cursors/sword.png) x, text"),
+            system(SystemCursorIcon::Text)
+        );
+    }
+
+    #[test]
+    fn test_innermost_hovered_node_sets_window_cursor() {
+        let mut app = App::new();
+        app.add_systems(Update, apply_react_cursor);
+        let window = app
+            .world_mut()
+            .spawn((
+                Window::default(),
+                PrimaryWindow,
+                CursorIcon::from(SystemCursorIcon::Wait),
+            ))
+            .id();
+        let node = |stack_index: u32| {
+            (
+                ReactNode {
+                    node_id: stack_index as u64,
+                },
+                Interaction::None,
+                ComputedNode {
+                    stack_index,
+                    ..default()
+                },
+            )
+        };
+        let card = app
+            .world_mut()
+            .spawn((
+                node(1),
+                ReactCursor {
+                    base: system(SystemCursorIcon::Pointer),
+                    pressed: system(SystemCursorIcon::Grabbing),
+                    ..default()
+                },
+            ))
+            .id();
+        let label = app.world_mut().spawn((node(2), ChildOf(card))).id();
+        let input = app.world_mut().spawn((node(3), Focusable)).id();
+        let cursor = |app: &App| app.world().get::<CursorIcon>(window).cloned();
+        let set = |app: &mut App, entity: Entity, interaction: Interaction| {
+            *app.world_mut().get_mut::<Interaction>(entity).unwrap() = interaction;
+        };
+
+        // The label has no cursor of its own and inherits the card's.
+        set(&mut app, label, Interaction::Hovered);
+        app.update();
+        assert_eq!(cursor(&app), system(SystemCursorIcon::Pointer));
+
+        set(&mut app, card, Interaction::Pressed);
+        app.update();
+        assert_eq!(cursor(&app), system(SystemCursorIcon::Grabbing));
+
+        // A node higher in the stack wins.
+        set(&mut app, input, Interaction::Hovered);
+        app.update();
+        assert_eq!(cursor(&app), system(SystemCursorIcon::Text));
+
+        for entity in [card, label, input] {
+            set(&mut app, entity, Interaction::None);
+        }
+        app.update();
+        assert_eq!(cursor(&app), system(SystemCursorIcon::Wait));
+    }
+}
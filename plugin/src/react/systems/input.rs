@@ -7,6 +7,7 @@ use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
 use bevy::input::ButtonState;
 use bevy::picking::hover::HoverMap;
 use bevy::prelude::*;
+use bevy::text::TextLayoutInfo;
 use bevy::ui::{ComputedUiTargetCamera, OverflowAxis, RelativeCursorPosition, ScrollPosition};
 use bevy::window::PrimaryWindow;
 use serde_json::{Value, json};
@@ -16,7 +17,11 @@ use crate::react::event_queue::{
     keyboard_modifiers, logical_key_to_string, pointer_payload, scroll_payload, wheel_payload,
     FLUSH_EVENTS_SCRIPT, ReactEventQueue, ReactFocusCommand,
 };
-use crate::react::systems::{Focusable, FocusedNode, ReactNode, ReactRoot};
+use crate::react::systems::text_spans::{SpanTargets, text_span_target};
+use crate::react::systems::{
+    Focusable, FocusedNode, ReactNode, ReactRoot, ReactRootPaused, ReactTextNode,
+    ReactTextureCursor,
+};
 
 /// Bevy-side request to focus a React node by id (programmatic focus API).
 ///
@@ -37,6 +42,13 @@ pub struct RequestReactBlur;
 #[derive(Component, Default)]
 pub struct PreviousInteraction(pub Interaction);
 
+/// Last normalized cursor position reported as `mousemove` for a node.
+///
+/// Texture roots have their [`RelativeCursorPosition`] rewritten every frame,
+/// so change detection alone would repeat `mousemove` for a still cursor.
+#[derive(Component, Default)]
+pub struct PreviousCursorPosition(pub Option<Vec2>);
+
 /// Line-height used when converting `MouseScrollUnit::Line` to pixels.
 const SCROLL_LINE_HEIGHT: f32 = 20.0;
 
@@ -58,7 +70,9 @@ pub fn handle_input_interactions(
     parents: Query<&ChildOf>,
     roots: Query<&ReactRoot>,
     focus_targets: Query<(Entity, &ReactNode, Option<&Focusable>, Option<&Button>)>,
-    cameras: Query<&Camera>,
+    text_blocks: Query<(&TextLayoutInfo, &ComputedNode)>,
+    text_spans: Query<SpanTargets, With<ReactTextNode>>,
+    cameras: Query<(&Camera, Option<&ReactTextureCursor>)>,
     windows: Query<&Window>,
     primary_window: Query<Entity, With<PrimaryWindow>>,
     mut commands: Commands,
@@ -87,6 +101,15 @@ pub fn handle_input_interactions(
         let window_cursor = cursor_for_ui_target(target_camera, &cameras, &windows, primary);
         let payload = pointer_payload(relative, window_cursor);
         let can_focus = is_focus_target(focusable, button);
+        // Nested text has no `Interaction`: press / click go to the span hit.
+        let target_id = relative
+            .and_then(|relative| relative.normalized)
+            .and_then(|normalized| {
+                let (layout, computed) = text_blocks.get(entity).ok()?;
+                let point = (normalized + 0.5) * computed.size;
+                text_span_target(entity, point, layout, &text_spans)
+            })
+            .unwrap_or(node_id);
 
         // Ensure relative cursor tracking for subsequent pointer events
         if relative.is_none() {
@@ -133,16 +156,16 @@ pub fn handle_input_interactions(
                 clear_focus(&mut focused, &event_queue);
             }
 
-            log::debug!("Node press: id={}, root={}", node_id, root_id);
-            event_queue.push_event(root_id.clone(), node_id, "press", payload.clone());
+            log::debug!("Node press: id={}, root={}", target_id, root_id);
+            event_queue.push_event(root_id.clone(), target_id, "press", payload.clone());
         }
 
         // DOM-style click: fire on release within bounds (still hovered), not on press.
         if was_pressed && !is_pressed {
-            log::debug!("Node release: id={}, root={}", node_id, root_id);
-            event_queue.push_event(root_id.clone(), node_id, "release", payload.clone());
+            log::debug!("Node release: id={}, root={}", target_id, root_id);
+            event_queue.push_event(root_id.clone(), target_id, "release", payload.clone());
             if is_hovered {
-                event_queue.push_event(root_id.clone(), node_id, "click", payload.clone());
+                event_queue.push_event(root_id.clone(), target_id, "click", payload.clone());
             }
         }
 
@@ -163,24 +186,26 @@ pub fn handle_input_interactions(
 }
 
 /// Emit `mousemove` (and `drag` while pressed) when relative cursor position changes.
-#[allow(clippy::type_complexity)]
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
 pub fn handle_pointer_move(
-    query: Query<
+    mut query: Query<
         (
             Entity,
             &Interaction,
             &ReactNode,
             &RelativeCursorPosition,
             Option<&ComputedUiTargetCamera>,
+            Option<&mut PreviousCursorPosition>,
         ),
         Changed<RelativeCursorPosition>,
     >,
     event_queue: Option<Res<ReactEventQueue>>,
     parents: Query<&ChildOf>,
     roots: Query<&ReactRoot>,
-    cameras: Query<&Camera>,
+    cameras: Query<(&Camera, Option<&ReactTextureCursor>)>,
     windows: Query<&Window>,
     primary_window: Query<Entity, With<PrimaryWindow>>,
+    mut commands: Commands,
 ) {
     let Some(event_queue) = event_queue else {
         return;
@@ -188,12 +213,26 @@ pub fn handle_pointer_move(
 
     let primary = primary_window.iter().next();
 
-    for (entity, interaction, react_node, relative, target_camera) in &query {
+    for (entity, interaction, react_node, relative, target_camera, previous) in &mut query {
         // Only emit while the pointer is over or capturing the node.
         if matches!(*interaction, Interaction::None) {
             continue;
         }
 
+        match previous {
+            Some(mut previous) => {
+                if previous.0 == relative.normalized {
+                    continue;
+                }
+                previous.0 = relative.normalized;
+            }
+            None => {
+                commands
+                    .entity(entity)
+                    .insert(PreviousCursorPosition(relative.normalized));
+            }
+        }
+
         let Some(root_id) = find_root_id(entity, &parents, &roots) else {
             continue;
         };
@@ -380,23 +419,27 @@ pub fn handle_wheel_scroll(
 }
 
 /// Resolve cursor position from the window that owns this UI node's target camera.
+///
+/// Texture roots report their [`ReactTextureCursor`] (target pixels) instead.
 fn cursor_for_ui_target(
     target_camera: Option<&ComputedUiTargetCamera>,
-    cameras: &Query<&Camera>,
+    cameras: &Query<(&Camera, Option<&ReactTextureCursor>)>,
     windows: &Query<&Window>,
     primary_window: Option<Entity>,
 ) -> Option<Vec2> {
     let camera_entity = target_camera.and_then(|t| t.get())?;
-    let camera = cameras.get(camera_entity).ok()?;
-    let NormalizedRenderTarget::Window(window_ref) = camera.target.normalize(primary_window)?
-    else {
-        return None;
-    };
-    windows.get(window_ref.entity()).ok()?.cursor_position()
+    let (camera, texture_cursor) = cameras.get(camera_entity).ok()?;
+    match camera.target.normalize(primary_window)? {
+        NormalizedRenderTarget::Window(window_ref) => {
+            windows.get(window_ref.entity()).ok()?.cursor_position()
+        }
+        NormalizedRenderTarget::Image(_) => texture_cursor?.position,
+        _ => None,
+    }
 }
 
 /// Find the React root id by traversing up the entity hierarchy
-fn find_root_id(
+pub(crate) fn find_root_id(
     entity: Entity,
     parents: &Query<&ChildOf>,
     roots: &Query<&ReactRoot>,
@@ -733,6 +776,7 @@ pub fn handle_keyboard_input(
 pub fn flush_react_events(
     event_queue: Option<Res<ReactEventQueue>>,
     js_client: Option<Res<JsClientResource>>,
+    paused: Query<&ReactRoot, With<ReactRootPaused>>,
 ) {
     let Some(event_queue) = event_queue else {
         return;
@@ -741,6 +785,11 @@ pub fn flush_react_events(
         return;
     };
 
+    // Paused roots receive nothing; stale input is dropped rather than replayed on resume.
+    if !paused.is_empty() {
+        event_queue.retain(|event| !paused.iter().any(|root| root.id == event.root_id));
+    }
+
     if event_queue.is_empty() {
         return;
     }
@@ -1,5 +1,8 @@
 //! Host-side interaction styling: merge hover/pressed/focused/checked overrides and
-//! apply simple color/opacity transitions without React round-trips.
+//! apply eased color/opacity/transform/length transitions without React round-trips.
+//!
+//! Transitions run whenever the resolved target changes — an interaction state
+//! change or a React prop update to the base style.
 
 use bevy::picking::hover::Hovered;
 use bevy::prelude::*;
@@ -7,15 +10,20 @@ use bevy::text::TextColor;
 use bevy::ui::{Checked, FocusPolicy};
 
 use crate::react::style::{
-    json_to_style, parse_color, resolve_interaction_style, style_opacity, style_pointer_events,
-    style_to_background_gradient, style_to_border_color, style_to_border_radius,
-    style_to_box_shadow, PointerEvents, StyleProps,
+    color_to_css, json_to_style, merge_style_props, parse_color, resolve_interaction_style,
+    style_opacity, style_pointer_events, style_to_background_gradient, style_to_border_color,
+    style_to_border_radius, style_to_box_shadow, style_transform, transform_to_css,
+    PointerEvents, ReactTransform, StyleProps, StyleTransitions, TransitionSpec,
 };
+use crate::react::systems::lengths::{ReactCalcLengths, sync_calc_lengths};
+use crate::react::systems::opacity::{ReactFadedColors, ReactOpacity, sync_react_opacity};
+use crate::react::systems::transform::sync_react_transform;
 use crate::react::systems::types::{
-    ColorAnim, FloatAnim, FocusedNode, ReactNode, ReactStyleState, StyleAnimationState,
+    FocusedNode, ReactNode, ReactStyleState, StyleAnim, StyleAnimationState, StyleLerp,
 };
 
 /// Each frame: resolve interaction overrides, advance transitions, apply styles.
+#[allow(clippy::type_complexity)]
 pub fn apply_interaction_styles(
     mut commands: Commands,
     time: Res<Time>,
@@ -30,12 +38,29 @@ pub fn apply_interaction_styles(
         Option<&BackgroundColor>,
         Option<&BorderColor>,
         Option<&TextColor>,
+        Option<&ReactTransform>,
+        Option<&ReactCalcLengths>,
+        Option<&ReactOpacity>,
+        Option<&ReactFadedColors>,
     )>,
 ) {
     let dt = time.delta_secs();
 
-    for (entity, interaction, hovered, checked, react_node, mut state, bg, border, text_color) in
-        &mut query
+    for (
+        entity,
+        interaction,
+        hovered,
+        checked,
+        react_node,
+        mut state,
+        bg,
+        border,
+        text_color,
+        transform,
+        calc_lengths,
+        opacity,
+        faded,
+    ) in &mut query
     {
         let is_focused =
             focused.entity == Some(entity) || focused.node_id == Some(react_node.node_id);
@@ -55,18 +80,36 @@ pub fn apply_interaction_styles(
             is_hovered,
         );
 
+        // Transitions start from the unfaded colors when an ancestor fades this node.
         let transition = state.transition.clone();
         let displayed = advance_and_build_displayed(
             &mut state.anim,
             &transition,
             &target,
-            bg.map(|c| c.0),
-            border.and_then(|b| uniform_border_color(b)),
-            text_color.map(|c| c.0),
+            faded
+                .and_then(ReactFadedColors::background)
+                .or(bg.map(|c| c.0)),
+            faded
+                .and_then(ReactFadedColors::border)
+                .or(border)
+                .and_then(uniform_border_color),
+            faded
+                .and_then(ReactFadedColors::text)
+                .or(text_color.map(|c| c.0)),
+            opacity.map(|o| o.0),
+            transform.copied(),
             dt,
         );
+        let (node, radius) = animate_lengths(&mut state.anim, &transition, &displayed, dt);
+        let (displayed, node, radius) = match state.anim.keyframe_overlay.as_ref() {
+            Some(overlay) => layer_keyframes(&displayed, overlay, node, radius),
+            None => (displayed, node, radius),
+        };
 
-        apply_resolved_style(&mut commands, entity, &displayed);
+        apply_resolved_style(&mut commands, entity, &displayed, node, radius);
+        if ReactCalcLengths::from_style(&displayed).as_ref() != calc_lengths {
+            sync_calc_lengths(&mut commands, entity, Some(&displayed));
+        }
     }
 }
 
@@ -91,22 +134,25 @@ fn colors_approx_eq(a: Color, b: Color) -> bool {
         && (a.alpha - b.alpha).abs() < 0.001
 }
 
+#[allow(clippy::too_many_arguments)]
 fn advance_and_build_displayed(
     anim: &mut StyleAnimationState,
-    transition: &crate::react::style::StyleTransitions,
+    transition: &StyleTransitions,
     target: &StyleProps,
     current_bg: Option<Color>,
     current_border: Option<Color>,
     current_text: Option<Color>,
+    current_opacity: Option<f32>,
+    current_transform: Option<ReactTransform>,
     dt: f32,
 ) -> StyleProps {
     let mut displayed = target.clone();
 
     let target_bg = target.background_color.as_deref().and_then(parse_color);
-    let bg = step_color_anim(
+    let bg = step_anim(
         &mut anim.background_color,
         target_bg,
-        transition.duration_secs("backgroundColor"),
+        transition.spec("backgroundColor"),
         current_bg,
         dt,
     );
@@ -115,10 +161,10 @@ fn advance_and_build_displayed(
     }
 
     let target_border = target.border_color.as_deref().and_then(parse_color);
-    let border = step_color_anim(
+    let border = step_anim(
         &mut anim.border_color,
         target_border,
-        transition.duration_secs("borderColor"),
+        transition.spec("borderColor"),
         current_border,
         dt,
     );
@@ -127,10 +173,10 @@ fn advance_and_build_displayed(
     }
 
     let target_text = target.color.as_deref().and_then(parse_color);
-    let text = step_color_anim(
+    let text = step_anim(
         &mut anim.color,
         target_text,
-        transition.duration_secs("color"),
+        transition.spec("color"),
         current_text,
         dt,
     );
@@ -139,11 +185,10 @@ fn advance_and_build_displayed(
     }
 
     let target_opacity = style_opacity(target);
-    let current_opacity = current_bg.map(|c| c.to_srgba().alpha);
-    let opacity = step_float_anim(
+    let opacity = step_anim(
         &mut anim.opacity,
         target_opacity,
-        transition.duration_secs("opacity"),
+        transition.spec("opacity"),
         current_opacity,
         dt,
     );
@@ -151,142 +196,186 @@ fn advance_and_build_displayed(
         displayed.opacity = Some(crate::react::style::CssScalar(o.to_string()));
     }
 
+    let transform = step_anim(
+        &mut anim.transform,
+        style_transform(target),
+        transition.spec("transform"),
+        current_transform,
+        dt,
+    );
+    if let Some(t) = transform {
+        displayed.transform = Some(transform_to_css(&t));
+    }
+
     displayed
 }
 
-fn step_color_anim(
-    track: &mut Option<ColorAnim>,
-    target: Option<Color>,
-    duration: Option<f32>,
-    current: Option<Color>,
+pub(crate) type LengthField<T> = fn(&mut T) -> &mut Val;
+
+/// `Node` lengths that transition, by camelCase longhand.
+pub(crate) const NODE_LENGTHS: &[(&str, LengthField<Node>)] = &[
+    ("width", |n| &mut n.width),
+    ("height", |n| &mut n.height),
+    ("minWidth", |n| &mut n.min_width),
+    ("minHeight", |n| &mut n.min_height),
+    ("maxWidth", |n| &mut n.max_width),
+    ("maxHeight", |n| &mut n.max_height),
+    ("flexBasis", |n| &mut n.flex_basis),
+    ("top", |n| &mut n.top),
+    ("right", |n| &mut n.right),
+    ("bottom", |n| &mut n.bottom),
+    ("left", |n| &mut n.left),
+    ("paddingTop", |n| &mut n.padding.top),
+    ("paddingRight", |n| &mut n.padding.right),
+    ("paddingBottom", |n| &mut n.padding.bottom),
+    ("paddingLeft", |n| &mut n.padding.left),
+    ("marginTop", |n| &mut n.margin.top),
+    ("marginRight", |n| &mut n.margin.right),
+    ("marginBottom", |n| &mut n.margin.bottom),
+    ("marginLeft", |n| &mut n.margin.left),
+    ("rowGap", |n| &mut n.row_gap),
+    ("columnGap", |n| &mut n.column_gap),
+];
+
+/// `BorderRadius` corners that transition, by camelCase longhand.
+const RADIUS_LENGTHS: &[(&str, LengthField<BorderRadius>)] = &[
+    ("borderTopLeftRadius", |r| &mut r.top_left),
+    ("borderTopRightRadius", |r| &mut r.top_right),
+    ("borderBottomRightRadius", |r| &mut r.bottom_right),
+    ("borderBottomLeftRadius", |r| &mut r.bottom_left),
+];
+
+/// Build the displayed `Node` / `BorderRadius`, easing any transitioned lengths.
+fn animate_lengths(
+    anim: &mut StyleAnimationState,
+    transition: &StyleTransitions,
+    displayed: &StyleProps,
     dt: f32,
-) -> Option<Color> {
-    let Some(target) = target else {
-        *track = None;
-        return None;
-    };
+) -> (Node, Option<BorderRadius>) {
+    let mut node = json_to_style(displayed);
+    for (prop, field) in NODE_LENGTHS {
+        step_length(anim, transition, prop, Some(field(&mut node)), dt);
+    }
 
-    let duration = duration.unwrap_or(0.0);
+    let mut radius = style_to_border_radius(displayed);
+    for (prop, field) in RADIUS_LENGTHS {
+        step_length(anim, transition, prop, radius.as_mut().map(field), dt);
+    }
 
-    match track.as_mut() {
-        Some(anim) if colors_approx_eq(anim.to, target) => {
-            anim.elapsed += dt;
-            Some(anim.current())
-        }
-        Some(anim) => {
-            let from = anim.current();
-            if duration <= 0.0 {
-                *track = None;
-                return Some(target);
-            }
-            *track = Some(ColorAnim {
-                from,
-                to: target,
-                elapsed: 0.0,
-                duration,
-            });
-            Some(from)
-        }
-        None => {
-            if duration <= 0.0 {
-                return Some(target);
-            }
-            let from = current.unwrap_or(target);
-            if colors_approx_eq(from, target) {
-                return Some(target);
-            }
-            *track = Some(ColorAnim {
-                from,
-                to: target,
-                elapsed: 0.0,
-                duration,
-            });
-            Some(from)
+    (node, radius)
+}
+
+/// Put a keyframe overlay on top of the transitioned style. Lengths the overlay
+/// does not change keep their transitioned values.
+fn layer_keyframes(
+    displayed: &StyleProps,
+    overlay: &StyleProps,
+    mut transitioned: Node,
+    radius: Option<BorderRadius>,
+) -> (StyleProps, Node, Option<BorderRadius>) {
+    let animated = merge_style_props(displayed, overlay);
+    let mut unanimated = json_to_style(displayed);
+    let mut node = json_to_style(&animated);
+    for (_, field) in NODE_LENGTHS {
+        if *field(&mut node) == *field(&mut unanimated) {
+            *field(&mut node) = *field(&mut transitioned);
         }
     }
+
+    let radius = if style_to_border_radius(overlay).is_some() {
+        style_to_border_radius(&animated)
+    } else {
+        radius
+    };
+    (animated, node, radius)
+}
+
+fn step_length(
+    anim: &mut StyleAnimationState,
+    transition: &StyleTransitions,
+    prop: &'static str,
+    value: Option<&mut Val>,
+    dt: f32,
+) {
+    let (Some(spec), Some(value)) = (transition.spec(prop), value) else {
+        anim.lengths.remove(prop);
+        return;
+    };
+    let mut track = anim.lengths.remove(prop);
+    // Layout is rebuilt from props, so the previous value only lives in the track.
+    if let Some(current) = step_anim(&mut track, Some(*value), Some(spec), None, dt) {
+        *value = current;
+    }
+    if let Some(track) = track {
+        anim.lengths.insert(prop, track);
+    }
 }
 
-fn step_float_anim(
-    track: &mut Option<FloatAnim>,
-    target: Option<f32>,
-    duration: Option<f32>,
-    current: Option<f32>,
+/// Advance one transition track toward `target` and return the value to display.
+///
+/// The track is kept once settled so the next target change eases from it.
+/// Without a track, `current` (the live component value) is the start point.
+fn step_anim<T: StyleLerp>(
+    track: &mut Option<StyleAnim<T>>,
+    target: Option<T>,
+    spec: Option<TransitionSpec>,
+    current: Option<T>,
     dt: f32,
-) -> Option<f32> {
+) -> Option<T> {
     let Some(target) = target else {
         *track = None;
         return None;
     };
-
-    let duration = duration.unwrap_or(0.0);
+    let spec = spec.unwrap_or_default();
 
     match track.as_mut() {
-        Some(anim) if (anim.to - target).abs() < 0.0001 => {
-            anim.elapsed += dt;
-            Some(anim.current())
+        Some(anim) if anim.to.approx_eq(&target) => {
+            anim.elapsed = (anim.elapsed + dt).min(anim.spec.total());
         }
         Some(anim) => {
-            let from = anim.current();
-            if duration <= 0.0 {
-                *track = None;
-                return Some(target);
-            }
-            *track = Some(FloatAnim {
+            let from = if spec.is_instant() {
+                target
+            } else {
+                anim.current()
+            };
+            *anim = StyleAnim {
                 from,
                 to: target,
                 elapsed: 0.0,
-                duration,
-            });
-            Some(from)
+                spec,
+            };
         }
         None => {
-            if duration <= 0.0 {
-                return Some(target);
-            }
-            let from = current.unwrap_or(target);
-            if (from - target).abs() < 0.0001 {
-                return Some(target);
-            }
-            *track = Some(FloatAnim {
+            let from = match current {
+                Some(current) if !spec.is_instant() => current,
+                _ => target,
+            };
+            *track = Some(StyleAnim {
                 from,
                 to: target,
                 elapsed: 0.0,
-                duration,
+                spec,
             });
-            Some(from)
         }
     }
+    track.as_ref().map(StyleAnim::current)
 }
 
-fn color_to_css(color: Color) -> String {
-    let c = color.to_srgba();
-    format!(
-        "rgba({}, {}, {}, {})",
-        (c.red * 255.0).round() as u8,
-        (c.green * 255.0).round() as u8,
-        (c.blue * 255.0).round() as u8,
-        c.alpha
-    )
-}
-
-fn apply_resolved_style(commands: &mut Commands, entity: Entity, style_props: &StyleProps) {
-    commands.entity(entity).insert(json_to_style(style_props));
+fn apply_resolved_style(
+    commands: &mut Commands,
+    entity: Entity,
+    style_props: &StyleProps,
+    node: Node,
+    radius: Option<BorderRadius>,
+) {
+    commands.entity(entity).insert(node);
 
     match style_props.background_color.as_deref().and_then(parse_color) {
-        Some(mut color) => {
-            if let Some(opacity) = style_opacity(style_props) {
-                color.set_alpha(opacity);
-            }
+        Some(color) => {
             commands.entity(entity).insert(BackgroundColor(color));
         }
         None => {
-            if let Some(opacity) = style_opacity(style_props) {
-                let mut color = Color::WHITE;
-                color.set_alpha(opacity);
-                commands.entity(entity).insert(BackgroundColor(color));
-            } else {
-                commands.entity(entity).remove::<BackgroundColor>();
-            }
+            commands.entity(entity).remove::<BackgroundColor>();
         }
     }
 
@@ -298,7 +387,7 @@ fn apply_resolved_style(commands: &mut Commands, entity: Entity, style_props: &S
             commands.entity(entity).remove::<BorderColor>();
         }
     }
-    match style_to_border_radius(style_props) {
+    match radius {
         Some(radius) => {
             commands.entity(entity).insert(radius);
         }
@@ -322,6 +411,7 @@ fn apply_resolved_style(commands: &mut Commands, entity: Entity, style_props: &S
             commands.entity(entity).remove::<BackgroundGradient>();
         }
     }
+    sync_react_transform(commands, entity, style_transform(style_props));
 
     match style_props.display.as_deref() {
         Some(d) if d.eq_ignore_ascii_case("none") => {
@@ -348,24 +438,144 @@ fn apply_resolved_style(commands: &mut Commands, entity: Entity, style_props: &S
         commands.entity(entity).insert(ZIndex(z));
     }
 
-    if let Some(ref color_str) = style_props.color
-        && let Some(mut color) = parse_color(color_str)
-    {
-        if let Some(opacity) = style_opacity(style_props) {
-            color.set_alpha(opacity);
-        }
+    if let Some(color) = style_props.color.as_deref().and_then(parse_color) {
         commands.entity(entity).insert(TextColor(color));
     }
+    sync_react_opacity(commands, entity, Some(style_props));
 }
 
 /// Insert or remove [`ReactStyleState`] from parsed style props (called from render).
 pub fn sync_react_style_state(commands: &mut Commands, entity: Entity, style: Option<&StyleProps>) {
     match style {
         Some(props) if props.has_interaction_styles() => {
-            commands.entity(entity).insert(ReactStyleState::from_props(props));
+            let mut state = ReactStyleState::from_props(props);
+            // Keep running tracks so a base-style change transitions from what is on screen.
+            commands.queue(move |world: &mut World| {
+                let Ok(mut entity_mut) = world.get_entity_mut(entity) else {
+                    return;
+                };
+                if let Some(previous) = entity_mut.take::<ReactStyleState>() {
+                    state.anim = previous.anim;
+                }
+                entity_mut.insert(state);
+            });
         }
         _ => {
             commands.entity(entity).remove::<ReactStyleState>();
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::react::style::{parse_props, TimingFunction};
+
+    fn style(json: &str) -> StyleProps {
+        parse_props(&format!(r#"{{"style":{json}}}"#))
+            .style
+            .unwrap()
+    }
+
+    fn setup_app(json: &str) -> (App, Entity) {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<FocusedNode>()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )))
+            .add_systems(Update, apply_interaction_styles);
+        let entity = app.world_mut().spawn(ReactNode { node_id: 1 }).id();
+        set_style(&mut app, entity, json);
+        (app, entity)
+    }
+
+    fn set_style(app: &mut App, entity: Entity, json: &str) {
+        let mut commands = app.world_mut().commands();
+        sync_react_style_state(&mut commands, entity, Some(&style(json)));
+        app.world_mut().flush();
+    }
+
+    fn width(app: &App, entity: Entity) -> Val {
+        app.world().get::<Node>(entity).unwrap().width
+    }
+
+    #[test]
+    fn test_step_anim_holds_for_delay_then_eases() {
+        let spec = TransitionSpec {
+            duration: 1.0,
+            delay: 0.5,
+            timing: TimingFunction::EASE_IN,
+        };
+        let mut track = None;
+        assert_eq!(
+            step_anim(&mut track, Some(0.0), Some(spec), None, 0.1),
+            Some(0.0)
+        );
+
+        assert_eq!(
+            step_anim(&mut track, Some(10.0), Some(spec), None, 0.1),
+            Some(0.0)
+        );
+        assert_eq!(
+            step_anim(&mut track, Some(10.0), Some(spec), None, 0.4),
+            Some(0.0)
+        );
+        let eased = step_anim(&mut track, Some(10.0), Some(spec), None, 0.5).unwrap();
+        assert!(
+            eased > 0.0 && eased < 5.0,
+            "ease-in lags linear at the midpoint: {eased}"
+        );
+        let done = step_anim(&mut track, Some(10.0), Some(spec), None, 1.0).unwrap();
+        assert!((done - 10.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_base_prop_update_transitions_width() {
+        let (mut app, entity) = setup_app(r#"{"width":100,"transition":"width 400ms"}"#);
+        app.update();
+        app.update();
+        assert_eq!(width(&app, entity), Val::Px(100.0));
+
+        set_style(
+            &mut app,
+            entity,
+            r#"{"width":200,"transition":"width 400ms"}"#,
+        );
+        app.update();
+        app.update();
+        let Val::Px(mid) = width(&app, entity) else {
+            panic!("width should stay in px");
+        };
+        assert!(mid > 100.0 && mid < 200.0, "width mid-transition: {mid}");
+
+        for _ in 0..5 {
+            app.update();
+        }
+        assert_eq!(width(&app, entity), Val::Px(200.0));
+    }
+
+    #[test]
+    fn test_hover_padding_uses_shorthand_transition() {
+        let (mut app, entity) = setup_app(
+            r#"{"padding":0,"hover":{"padding":20},"transition":"padding 400ms linear"}"#,
+        );
+        app.update();
+        app.world_mut()
+            .entity_mut(entity)
+            .insert(Interaction::Hovered);
+        app.update();
+        app.update();
+
+        let padding = app.world().get::<Node>(entity).unwrap().padding;
+        let Val::Px(left) = padding.left else {
+            panic!("padding should stay in px");
+        };
+        assert!(left > 0.0 && left < 20.0, "padding mid-transition: {left}");
+        assert_eq!(padding.left, padding.top);
+    }
+}
@@ -0,0 +1,213 @@
+//! `onLayout` events and `measure(ref)` for React nodes.
+//!
+//! Nodes whose props include `onLayout` carry a [`ReactLayoutListener`];
+//! [`emit_react_layout_events`] sends a `layout` event after UI layout whenever
+//! such a node's rect moves or resizes. [`MEASURE_NODE_COMMAND`] answers a
+//! one-off query for any mounted node. Rects are in logical pixels with the
+//! origin at the top-left of the UI target.
+
+use bevy::prelude::*;
+use bevy::ui::UiGlobalTransform;
+use serde_json::{Value, json};
+
+use crate::react::ReactBridge;
+use crate::react::components_registry::ReactEntityMap;
+use crate::react::event_queue::ReactEventQueue;
+use crate::react::systems::{ReactNode, ReactRoot, find_root_id};
+
+/// Bridge command: `{ nodeId }` → `{ x, y, width, height }`.
+pub const MEASURE_NODE_COMMAND: &str = "measure_node";
+
+/// Subscribes a node to `layout` events; holds the last rect sent to JS.
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct ReactLayoutListener {
+    sent: Option<Rect>,
+}
+
+/// Insert or clear [`ReactLayoutListener`] from React props (create / update paths).
+pub(crate) fn sync_layout_listener(commands: &mut Commands, entity: Entity, props: &Value) {
+    let subscribed = props
+        .get("onLayout")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+    if subscribed {
+        commands
+            .entity(entity)
+            .insert_if_new(ReactLayoutListener::default());
+    } else {
+        commands.entity(entity).remove::<ReactLayoutListener>();
+    }
+}
+
+/// A laid-out node's rect in logical pixels (top-left corner and size).
+pub fn node_layout_rect(node: &ComputedNode, transform: &UiGlobalTransform) -> Rect {
+    let scale = node.inverse_scale_factor;
+    Rect::from_center_size(transform.translation * scale, node.size * scale)
+}
+
+fn layout_payload(rect: Rect) -> Value {
+    json!({
+        "x": rect.min.x,
+        "y": rect.min.y,
+        "width": rect.width(),
+        "height": rect.height(),
+    })
+}
+
+/// Queue a `layout` event for each subscribed node whose rect changed.
+#[allow(clippy::type_complexity)]
+pub fn emit_react_layout_events(
+    event_queue: Option<Res<ReactEventQueue>>,
+    mut listeners: Query<
+        (
+            Entity,
+            &ReactNode,
+            &ComputedNode,
+            &UiGlobalTransform,
+            &mut ReactLayoutListener,
+        ),
+        Or<(
+            Changed<ComputedNode>,
+            Changed<UiGlobalTransform>,
+            Added<ReactLayoutListener>,
+        )>,
+    >,
+    parents: Query<&ChildOf>,
+    roots: Query<&ReactRoot>,
+) {
+    let Some(event_queue) = event_queue else {
+        return;
+    };
+    for (entity, node, computed, transform, mut listener) in listeners.iter_mut() {
+        let rect = node_layout_rect(computed, transform);
+        if listener.sent == Some(rect) {
+            continue;
+        }
+        let Some(root_id) = find_root_id(entity, &parents, &roots) else {
+            continue;
+        };
+        listener.sent = Some(rect);
+        event_queue.push_event(root_id, node.node_id, "layout", layout_payload(rect));
+    }
+}
+
+/// Handle [`MEASURE_NODE_COMMAND`] calls on `bridge`.
+pub(crate) fn register_measure_node(bridge: &ReactBridge) {
+    bridge.register(MEASURE_NODE_COMMAND, |world, args| {
+        let Some(node_id) = args.get("nodeId").and_then(Value::as_u64) else {
+            return json!({ "error": "measure requires a nodeId" });
+        };
+        let entity = world
+            .get_resource::<ReactEntityMap>()
+            .and_then(|map| map.get(node_id))
+            .and_then(Entity::try_from_bits);
+        let Some(entity) = entity else {
+            return json!({ "error": format!("node {node_id} is not mounted") });
+        };
+        let Ok(entity_ref) = world.get_entity(entity) else {
+            return json!({ "error": format!("node {node_id} is not mounted") });
+        };
+        match (
+            entity_ref.get::<ComputedNode>(),
+            entity_ref.get::<UiGlobalTransform>(),
+        ) {
+            (Some(node), Some(transform)) => layout_payload(node_layout_rect(node, transform)),
+            _ => json!({ "error": format!("node {node_id} has no layout") }),
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::Affine2;
+
+    use super::*;
+    use crate::react::process_react_bridge_calls;
+
+    fn laid_out(size: Vec2, center: Vec2) -> (ComputedNode, UiGlobalTransform) {
+        let node = ComputedNode {
+            size,
+            inverse_scale_factor: 0.5,
+            ..default()
+        };
+        (
+            node,
+            UiGlobalTransform::from(Affine2::from_translation(center)),
+        )
+    }
+
+    #[test]
+    fn test_layout_events_follow_size_and_position() {
+        let mut app = App::new();
+        app.init_resource::<ReactEventQueue>()
+            .add_systems(Update, emit_react_layout_events);
+        let root = app
+            .world_mut()
+            .spawn(ReactRoot {
+                id: "root-1".to_string(),
+            })
+            .id();
+        let (node, transform) = laid_out(Vec2::new(200.0, 100.0), Vec2::new(300.0, 150.0));
+        let entity = app
+            .world_mut()
+            .spawn((
+                ReactNode { node_id: 7 },
+                node,
+                transform,
+                ReactLayoutListener::default(),
+                ChildOf(root),
+            ))
+            .id();
+
+        app.update();
+        let events = app.world().resource::<ReactEventQueue>().drain();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].node_id, 7);
+        assert_eq!(events[0].event_type, "layout");
+        let payload: Value = serde_json::from_str(&events[0].payload_json).unwrap();
+        assert_eq!(
+            payload,
+            json!({ "x": 100.0, "y": 50.0, "width": 100.0, "height": 50.0 })
+        );
+
+        // Unchanged rect (even if the component is touched) sends nothing.
+        app.world_mut()
+            .get_mut::<ComputedNode>(entity)
+            .unwrap()
+            .set_changed();
+        app.update();
+        assert!(app.world().resource::<ReactEventQueue>().drain().is_empty());
+
+        *app.world_mut()
+            .get_mut::<UiGlobalTransform>(entity)
+            .unwrap() = UiGlobalTransform::from(Affine2::from_translation(Vec2::new(320.0, 150.0)));
+        app.update();
+        let events = app.world().resource::<ReactEventQueue>().drain();
+        assert_eq!(events.len(), 1);
+        assert!(events[0].payload_json.contains("\"x\":110.0"));
+    }
+
+    #[test]
+    fn test_measure_node_command() {
+        let mut app = App::new();
+        app.init_resource::<ReactBridge>()
+            .init_resource::<ReactEntityMap>()
+            .add_systems(Update, process_react_bridge_calls);
+        let bridge = app.world().resource::<ReactBridge>().clone();
+        register_measure_node(&bridge);
+        let (node, transform) = laid_out(Vec2::new(40.0, 20.0), Vec2::new(20.0, 10.0));
+        let entity = app.world_mut().spawn((node, transform)).id();
+        app.world().resource::<ReactEntityMap>().insert(3, entity);
+
+        let mut measure = |args: Value| {
+            bridge.enqueue_call(MEASURE_NODE_COMMAND.to_string(), args, Some(1));
+            app.update();
+            bridge.drain_call_results().remove(0).value
+        };
+        assert_eq!(
+            measure(json!({ "nodeId": 3 })),
+            json!({ "x": 0.0, "y": 0.0, "width": 20.0, "height": 10.0 })
+        );
+        assert!(measure(json!({ "nodeId": 4 })).get("error").is_some());
+    }
+}
@@ -0,0 +1,381 @@
+//! Host-resolved lengths: `calc()` / `min()` / `max()` / `clamp()` mixing units
+//! Bevy cannot combine (`calc(100% - 48px)`, `clamp(12px, 2vw, 20px)`), and
+//! `rem` / `em`, which Bevy has no unit for.
+//!
+//! Such lengths are kept as [`CalcExpr`]s in [`ReactCalcLengths`] and written
+//! into `Node` (and a text node's `TextFont` size) as px by
+//! [`resolve_calc_lengths`], against the parent's content box from the previous
+//! layout, the node's render-target viewport and [`ReactRootFontSize`].
+
+use bevy::prelude::*;
+use bevy::ui::ComputedUiRenderTargetInfo;
+
+use crate::react::calc::{CalcContext, CalcExpr, CssLength, ReactRootFontSize, parse_css_length};
+use crate::react::media::ReactViewport;
+use crate::react::style::{StyleProps, split_css_values};
+use crate::react::systems::interaction_style::NODE_LENGTHS;
+
+/// `Node` lengths whose `%` refers to the parent's height; the rest (including
+/// vertical margins and padding, as in CSS) use its width.
+const HEIGHT_RELATIVE: &[&str] = &[
+    "height",
+    "minHeight",
+    "maxHeight",
+    "top",
+    "bottom",
+    "rowGap",
+];
+
+/// Lengths that may go negative; sizes, padding and gaps stop at 0.
+const SIGNED: &[&str] = &[
+    "top",
+    "right",
+    "bottom",
+    "left",
+    "marginTop",
+    "marginRight",
+    "marginBottom",
+    "marginLeft",
+];
+
+/// Node lengths that need the parent's size or the root font size, by
+/// camelCase longhand (`fontSize` included).
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct ReactCalcLengths {
+    lengths: Vec<(&'static str, CalcExpr)>,
+}
+
+impl ReactCalcLengths {
+    /// `None` when every length in `style` folds into a plain [`Val`].
+    pub fn from_style(style: &StyleProps) -> Option<Self> {
+        let font_size = style
+            .font_size
+            .as_ref()
+            .map(|value| ("fontSize", value.0.as_str()));
+        let lengths: Vec<_> = node_length_sources(style)
+            .into_iter()
+            .chain(font_size)
+            .filter(|(_, value)| value.contains('(') || value.to_ascii_lowercase().ends_with("em"))
+            .filter_map(|(prop, value)| match parse_css_length(value)? {
+                CssLength::Calc(expr) => Some((prop, expr)),
+                CssLength::Val(_) => None,
+            })
+            .collect();
+        (!lengths.is_empty()).then_some(Self { lengths })
+    }
+
+    pub fn get(&self, prop: &str) -> Option<&CalcExpr> {
+        self.lengths
+            .iter()
+            .find(|(name, _)| *name == prop)
+            .map(|(_, expr)| expr)
+    }
+}
+
+/// Source value of each `Node` length, following `json_to_style`'s precedence
+/// (shorthand first, longhands override).
+fn node_length_sources(props: &StyleProps) -> Vec<(&'static str, &str)> {
+    fn set<'a>(out: &mut Vec<(&'static str, &'a str)>, prop: &'static str, value: &'a str) {
+        out.retain(|(name, _)| *name != prop);
+        out.push((prop, value));
+    }
+
+    fn set_rect<'a>(
+        out: &mut Vec<(&'static str, &'a str)>,
+        sides: [&'static str; 4],
+        value: &'a str,
+    ) {
+        let [top, right, bottom, left] = match split_css_values(value).as_slice() {
+            [all] => [*all; 4],
+            [vertical, horizontal] => [*vertical, *horizontal, *vertical, *horizontal],
+            [top, horizontal, bottom] => [*top, *horizontal, *bottom, *horizontal],
+            [top, right, bottom, left] => [*top, *right, *bottom, *left],
+            _ => return,
+        };
+        for (prop, value) in sides.into_iter().zip([top, right, bottom, left]) {
+            set(out, prop, value);
+        }
+    }
+
+    let mut out = Vec::new();
+    let longhands = [
+        ("width", &props.width),
+        ("height", &props.height),
+        ("minWidth", &props.min_width),
+        ("minHeight", &props.min_height),
+        ("maxWidth", &props.max_width),
+        ("maxHeight", &props.max_height),
+        ("flexBasis", &props.flex_basis),
+    ];
+    for (prop, value) in longhands {
+        if let Some(value) = value {
+            set(&mut out, prop, &value.0);
+        }
+    }
+
+    let margin = ["marginTop", "marginRight", "marginBottom", "marginLeft"];
+    if let Some(value) = &props.margin {
+        set_rect(&mut out, margin, &value.0);
+    }
+    let margin_sides = [
+        &props.margin_top,
+        &props.margin_right,
+        &props.margin_bottom,
+        &props.margin_left,
+    ];
+    for (prop, value) in margin.into_iter().zip(margin_sides) {
+        if let Some(value) = value {
+            set(&mut out, prop, &value.0);
+        }
+    }
+
+    let padding = ["paddingTop", "paddingRight", "paddingBottom", "paddingLeft"];
+    if let Some(value) = &props.padding {
+        set_rect(&mut out, padding, &value.0);
+    }
+    let padding_sides = [
+        &props.padding_top,
+        &props.padding_right,
+        &props.padding_bottom,
+        &props.padding_left,
+    ];
+    for (prop, value) in padding.into_iter().zip(padding_sides) {
+        if let Some(value) = value {
+            set(&mut out, prop, &value.0);
+        }
+    }
+
+    let inset = [
+        ("top", &props.top),
+        ("right", &props.right),
+        ("bottom", &props.bottom),
+        ("left", &props.left),
+    ];
+    for (prop, value) in inset {
+        if let Some(value) = value {
+            set(&mut out, prop, &value.0);
+        }
+    }
+
+    if let Some(gap) = &props.gap {
+        match split_css_values(&gap.0).as_slice() {
+            [row, column] => {
+                set(&mut out, "rowGap", row);
+                set(&mut out, "columnGap", column);
+            }
+            _ => {
+                set(&mut out, "rowGap", &gap.0);
+                set(&mut out, "columnGap", &gap.0);
+            }
+        }
+    }
+    if let Some(value) = &props.row_gap {
+        set(&mut out, "rowGap", &value.0);
+    }
+    if let Some(value) = &props.column_gap {
+        set(&mut out, "columnGap", &value.0);
+    }
+    out
+}
+
+/// Insert or clear a node's [`ReactCalcLengths`] after its style is applied.
+pub(crate) fn sync_calc_lengths(
+    commands: &mut Commands,
+    entity: Entity,
+    style: Option<&StyleProps>,
+) {
+    match style.and_then(ReactCalcLengths::from_style) {
+        Some(lengths) => {
+            commands.entity(entity).insert(lengths);
+        }
+        None => {
+            commands.entity(entity).remove::<ReactCalcLengths>();
+        }
+    }
+}
+
+/// Resolve calc lengths into `Node` px. Runs in `PostUpdate` before UI layout,
+/// so `%` uses the parent's size from the previous frame.
+#[allow(clippy::type_complexity)]
+pub fn resolve_calc_lengths(
+    window: Option<Res<ReactViewport>>,
+    root_font_size: Option<Res<ReactRootFontSize>>,
+    mut nodes: Query<(
+        &ReactCalcLengths,
+        &mut Node,
+        Option<&mut TextFont>,
+        Option<&ComputedUiRenderTargetInfo>,
+        Option<&ChildOf>,
+    )>,
+    computed: Query<&ComputedNode>,
+) {
+    let window = window.as_deref().copied().unwrap_or_default();
+    let root_font_size =
+        root_font_size.map_or(CalcContext::FALLBACK.root_font_size, |size| size.0.max(0.0));
+    for (lengths, mut node, mut text_font, target, child_of) in &mut nodes {
+        let viewport = target
+            .and_then(ReactViewport::from_target)
+            .unwrap_or(window);
+        let viewport = Vec2::new(viewport.width, viewport.height);
+        let parent = child_of
+            .and_then(|child_of| computed.get(child_of.parent()).ok())
+            .map(content_box)
+            .unwrap_or(viewport);
+
+        for (prop, expr) in &lengths.lengths {
+            if *prop == "fontSize" {
+                // `%` would be the parent's font size, which layout does not track.
+                let ctx = CalcContext {
+                    percent_base: 0.0,
+                    viewport,
+                    root_font_size,
+                };
+                let px = expr.eval(&ctx).max(0.0);
+                if let Some(text_font) = text_font.as_mut()
+                    && text_font.font_size != px
+                {
+                    text_font.font_size = px;
+                }
+                continue;
+            }
+            let Some((_, field)) = NODE_LENGTHS.iter().find(|(name, _)| name == prop) else {
+                continue;
+            };
+            let ctx = CalcContext {
+                percent_base: if HEIGHT_RELATIVE.contains(prop) {
+                    parent.y
+                } else {
+                    parent.x
+                },
+                viewport,
+                root_font_size,
+            };
+            let mut px = expr.eval(&ctx);
+            if !SIGNED.contains(prop) {
+                px = px.max(0.0);
+            }
+            // Keep change detection quiet while the result is stable.
+            if *field(node.bypass_change_detection()) != Val::Px(px) {
+                *field(&mut node) = Val::Px(px);
+            }
+        }
+    }
+}
+
+/// Logical content box: what a child's `%` lengths refer to.
+fn content_box(computed: &ComputedNode) -> Vec2 {
+    let inset = Vec2::new(
+        computed.padding.left
+            + computed.padding.right
+            + computed.border.left
+            + computed.border.right,
+        computed.padding.top
+            + computed.padding.bottom
+            + computed.border.top
+            + computed.border.bottom,
+    );
+    ((computed.size - inset) * computed.inverse_scale_factor).max(Vec2::ZERO)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::react::style::parse_props;
+
+    fn style(json: &str) -> StyleProps {
+        parse_props(&format!(r#"{{"style":{json}}}"#))
+            .style
+            .unwrap()
+    }
+
+    fn spawn_child(app: &mut App, parent_size: Vec2, json: &str) -> Entity {
+        let parent = app
+            .world_mut()
+            .spawn((
+                Node::default(),
+                ComputedNode {
+                    size: parent_size,
+                    padding: BorderRect::all(10.0),
+                    inverse_scale_factor: 1.0,
+                    ..default()
+                },
+            ))
+            .id();
+        let style = style(json);
+        let lengths = ReactCalcLengths::from_style(&style).expect("calc lengths");
+        app.world_mut()
+            .spawn((Node::default(), lengths, ChildOf(parent)))
+            .id()
+    }
+
+    #[test]
+    fn test_sources_follow_shorthand_precedence() {
+        let lengths = ReactCalcLengths::from_style(&style(
+            r#"{"padding":"calc(2% + 4px) 8px","paddingBottom":"12px","gap":"calc(1% + 1px) 4px","width":"100%"}"#,
+        ))
+        .unwrap();
+        assert!(lengths.get("paddingTop").is_some());
+        assert!(lengths.get("paddingRight").is_none(), "8px folds natively");
+        assert!(lengths.get("paddingBottom").is_none(), "longhand overrides");
+        assert!(lengths.get("rowGap").is_some());
+        assert!(lengths.get("width").is_none());
+
+        assert!(ReactCalcLengths::from_style(&style(r#"{"width":"calc(100% / 2)"}"#)).is_none());
+    }
+
+    #[test]
+    fn test_mixed_lengths_resolve_against_parent_content_box() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(ReactViewport::new(1000.0, 500.0, 1.0))
+            .add_systems(Update, resolve_calc_lengths);
+        let node = spawn_child(
+            &mut app,
+            Vec2::new(420.0, 220.0),
+            r#"{"width":"calc(100% - 48px)","height":"min(50%, 10vh)","marginLeft":"calc(-10px - 1%)","paddingTop":"calc(5vw - 100%)"}"#,
+        );
+        app.update();
+
+        let node = app.world().get::<Node>(node).unwrap();
+        assert_eq!(node.width, Val::Px(352.0));
+        assert_eq!(node.height, Val::Px(50.0));
+        assert_eq!(node.margin.left, Val::Px(-14.0));
+        assert_eq!(node.padding.top, Val::Px(0.0), "padding clamps at zero");
+    }
+
+    #[test]
+    fn test_rem_follows_root_font_size_per_app() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(ReactRootFontSize(20.0))
+            .add_systems(Update, resolve_calc_lengths);
+        let node = spawn_child(
+            &mut app,
+            Vec2::new(400.0, 200.0),
+            r#"{"width":"10rem","marginTop":"calc(1em + 4px)","fontSize":"1.5rem"}"#,
+        );
+        app.world_mut().entity_mut(node).insert(TextFont::default());
+        // A second app keeps its own root size.
+        let mut other = App::new();
+        other
+            .add_plugins(MinimalPlugins)
+            .add_systems(Update, resolve_calc_lengths);
+        let other_node = spawn_child(&mut other, Vec2::new(400.0, 200.0), r#"{"width":"10rem"}"#);
+        app.update();
+        other.update();
+
+        let width = |app: &App, entity| app.world().get::<Node>(entity).unwrap().width;
+        assert_eq!(width(&app, node), Val::Px(200.0));
+        assert_eq!(width(&other, other_node), Val::Px(160.0));
+        assert_eq!(
+            app.world().get::<Node>(node).unwrap().margin.top,
+            Val::Px(24.0)
+        );
+        assert_eq!(app.world().get::<TextFont>(node).unwrap().font_size, 30.0);
+
+        app.insert_resource(ReactRootFontSize(10.0));
+        app.update();
+        assert_eq!(width(&app, node), Val::Px(100.0));
+        assert_eq!(app.world().get::<TextFont>(node).unwrap().font_size, 15.0);
+    }
+}
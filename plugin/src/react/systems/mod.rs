@@ -1,11 +1,32 @@
 mod render;
+mod cursor;
 mod input;
 mod interaction_style;
+mod layout_events;
+mod lengths;
+mod opacity;
+mod pause;
 mod teardown;
+mod text_overflow;
+mod text_spans;
+mod texture;
+mod transform;
 mod types;
 
 pub use render::*;
+pub use cursor::{ReactCursor, apply_react_cursor, parse_cursor};
 pub use input::*;
 pub use interaction_style::*;
+pub use layout_events::{
+    MEASURE_NODE_COMMAND, ReactLayoutListener, emit_react_layout_events, node_layout_rect,
+};
+pub(crate) use layout_events::register_measure_node;
+pub use lengths::{ReactCalcLengths, resolve_calc_lengths};
+pub use opacity::{ReactFadedColors, ReactOpacity, apply_react_opacity};
+pub(crate) use pause::*;
 pub use teardown::*;
+pub use text_overflow::{ReactTextOverflow, WhiteSpace, truncate_overflowing_text};
+pub use text_spans::{ReactTextStyle, inherit_text_span_styles, sync_text_spans};
+pub(crate) use texture::*;
+pub use transform::apply_react_transforms;
 pub use types::*;
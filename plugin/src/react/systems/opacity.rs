@@ -0,0 +1,337 @@
+//! Subtree opacity: a node's `opacity` fades it and all of its descendants.
+//!
+//! Bevy UI has no group alpha, so [`apply_react_opacity`] multiplies the
+//! effective opacity (the product of the node's and its ancestors') into each
+//! node's colors after styles are written. The unfaded colors are kept in
+//! [`ReactFadedColors`] and refreshed whenever another system rewrites them.
+
+use bevy::ecs::component::Mutable;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use bevy::ui::{BackgroundGradient, Gradient};
+
+use crate::react::style::{StyleProps, style_opacity};
+
+/// A node's own `opacity` (0–1), as displayed after transitions.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct ReactOpacity(pub f32);
+
+/// Insert or clear [`ReactOpacity`] from a node's displayed style.
+pub(crate) fn sync_react_opacity(
+    commands: &mut Commands,
+    entity: Entity,
+    style: Option<&StyleProps>,
+) {
+    match style.and_then(style_opacity) {
+        Some(opacity) => {
+            commands
+                .entity(entity)
+                .insert(ReactOpacity(opacity.clamp(0.0, 1.0)));
+        }
+        None => {
+            commands.entity(entity).remove::<ReactOpacity>();
+        }
+    }
+}
+
+/// Colors of a node under an `opacity` < 1, before the fade was applied.
+#[derive(Component, Debug, Clone, Default)]
+pub struct ReactFadedColors {
+    alpha: f32,
+    background: Option<BackgroundColor>,
+    border: Option<BorderColor>,
+    text: Option<TextColor>,
+    image: Option<ImageNode>,
+    shadow: Option<BoxShadow>,
+    gradient: Option<BackgroundGradient>,
+}
+
+impl ReactFadedColors {
+    /// Effective opacity currently multiplied in.
+    pub fn alpha(&self) -> f32 {
+        self.alpha
+    }
+
+    pub fn background(&self) -> Option<Color> {
+        self.background.map(|c| c.0)
+    }
+
+    pub fn border(&self) -> Option<&BorderColor> {
+        self.border.as_ref()
+    }
+
+    pub fn text(&self) -> Option<Color> {
+        self.text.map(|c| c.0)
+    }
+}
+
+trait Fade {
+    fn faded(&self, alpha: f32) -> Self;
+
+    /// Whether the colors `faded` writes already match.
+    fn same_colors(&self, other: &Self) -> bool;
+}
+
+fn fade(color: Color, alpha: f32) -> Color {
+    color.with_alpha(color.alpha() * alpha)
+}
+
+impl Fade for BackgroundColor {
+    fn faded(&self, alpha: f32) -> Self {
+        Self(fade(self.0, alpha))
+    }
+
+    fn same_colors(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl Fade for BorderColor {
+    fn faded(&self, alpha: f32) -> Self {
+        Self {
+            top: fade(self.top, alpha),
+            right: fade(self.right, alpha),
+            bottom: fade(self.bottom, alpha),
+            left: fade(self.left, alpha),
+        }
+    }
+
+    fn same_colors(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl Fade for TextColor {
+    fn faded(&self, alpha: f32) -> Self {
+        Self(fade(self.0, alpha))
+    }
+
+    fn same_colors(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl Fade for ImageNode {
+    fn faded(&self, alpha: f32) -> Self {
+        Self {
+            color: fade(self.color, alpha),
+            ..self.clone()
+        }
+    }
+
+    fn same_colors(&self, other: &Self) -> bool {
+        self.color == other.color
+    }
+}
+
+impl Fade for BoxShadow {
+    fn faded(&self, alpha: f32) -> Self {
+        let mut shadow = self.clone();
+        for layer in &mut shadow.0 {
+            layer.color = fade(layer.color, alpha);
+        }
+        shadow
+    }
+
+    fn same_colors(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl Fade for BackgroundGradient {
+    fn faded(&self, alpha: f32) -> Self {
+        let mut gradients = self.clone();
+        for gradient in &mut gradients.0 {
+            match gradient {
+                Gradient::Linear(g) => {
+                    for stop in &mut g.stops {
+                        stop.color = fade(stop.color, alpha);
+                    }
+                }
+                Gradient::Radial(g) => {
+                    for stop in &mut g.stops {
+                        stop.color = fade(stop.color, alpha);
+                    }
+                }
+                Gradient::Conic(g) => {
+                    for stop in &mut g.stops {
+                        stop.color = fade(stop.color, alpha);
+                    }
+                }
+            }
+        }
+        gradients
+    }
+
+    fn same_colors(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+/// Re-fade one color component. A component changed since the last run was
+/// rewritten unfaded by a style system, so it becomes the new base.
+fn fade_component<T>(current: Option<Mut<T>>, base: &mut Option<T>, alpha: f32, alpha_changed: bool)
+where
+    T: Component<Mutability = Mutable> + Clone + Fade,
+{
+    let Some(mut current) = current else {
+        *base = None;
+        return;
+    };
+    let rewritten = current.is_changed() || base.is_none();
+    if rewritten {
+        *base = Some(current.clone());
+    }
+    if !rewritten && !alpha_changed {
+        return;
+    }
+    let Some(base) = base.as_ref() else {
+        return;
+    };
+    let faded = if alpha >= 1.0 {
+        base.clone()
+    } else {
+        base.faded(alpha)
+    };
+    if !current.same_colors(&faded) {
+        *current = faded;
+    }
+}
+
+type FadeTargets = (
+    Option<&'static mut BackgroundColor>,
+    Option<&'static mut BorderColor>,
+    Option<&'static mut TextColor>,
+    Option<&'static mut ImageNode>,
+    Option<&'static mut BoxShadow>,
+    Option<&'static mut BackgroundGradient>,
+    Option<&'static mut ReactFadedColors>,
+);
+
+/// Multiply each node's effective opacity into its colors. Runs in
+/// `PostUpdate`, after style, transition and animation systems have written
+/// this frame's colors.
+pub fn apply_react_opacity(
+    mut commands: Commands,
+    opacities: Query<(Entity, &ReactOpacity)>,
+    parents: Query<&ChildOf>,
+    children: Query<&Children>,
+    faded: Query<Entity, With<ReactFadedColors>>,
+    mut targets: Query<FadeTargets>,
+) {
+    let own = |entity: Entity| opacities.get(entity).map_or(1.0, |(_, o)| o.0);
+
+    // Walk down from each outermost faded node.
+    let mut effective: HashMap<Entity, f32> = HashMap::new();
+    for (top, opacity) in &opacities {
+        if parents
+            .iter_ancestors(top)
+            .any(|ancestor| opacities.contains(ancestor))
+        {
+            continue;
+        }
+        let mut stack = vec![(top, opacity.0)];
+        while let Some((entity, alpha)) = stack.pop() {
+            effective.insert(entity, alpha);
+            if let Ok(kids) = children.get(entity) {
+                stack.extend(kids.iter().map(|child| (child, alpha * own(child))));
+            }
+        }
+    }
+    // Nodes no longer under any opacity restore their colors.
+    for entity in &faded {
+        effective.entry(entity).or_insert(1.0);
+    }
+
+    for (entity, alpha) in effective {
+        let Ok((background, border, text, image, shadow, gradient, state)) =
+            targets.get_mut(entity)
+        else {
+            continue;
+        };
+        let mut inserted = None;
+        let state = match state {
+            Some(state) => state.into_inner(),
+            None if alpha < 1.0 => inserted.insert(ReactFadedColors {
+                alpha: 1.0,
+                ..default()
+            }),
+            None => continue,
+        };
+        let alpha_changed = state.alpha != alpha;
+        fade_component(background, &mut state.background, alpha, alpha_changed);
+        fade_component(border, &mut state.border, alpha, alpha_changed);
+        fade_component(text, &mut state.text, alpha, alpha_changed);
+        fade_component(image, &mut state.image, alpha, alpha_changed);
+        fade_component(shadow, &mut state.shadow, alpha, alpha_changed);
+        fade_component(gradient, &mut state.gradient, alpha, alpha_changed);
+        state.alpha = alpha;
+
+        if alpha >= 1.0 {
+            commands.entity(entity).remove::<ReactFadedColors>();
+        } else if let Some(state) = inserted {
+            commands.entity(entity).insert(state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup() -> (App, Entity, Entity) {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_systems(Update, apply_react_opacity);
+        let panel = app
+            .world_mut()
+            .spawn((
+                Node::default(),
+                ReactOpacity(0.5),
+                BackgroundColor(Color::srgba(1.0, 0.0, 0.0, 0.8)),
+            ))
+            .id();
+        let label = app
+            .world_mut()
+            .spawn((Text::new("hi"), TextColor(Color::WHITE), ChildOf(panel)))
+            .id();
+        (app, panel, label)
+    }
+
+    fn alpha_of<T: Component>(app: &App, entity: Entity, color: impl Fn(&T) -> Color) -> f32 {
+        color(app.world().get::<T>(entity).unwrap()).alpha()
+    }
+
+    #[test]
+    fn test_opacity_fades_descendants_and_restores() {
+        let (mut app, panel, label) = setup();
+        app.update();
+        assert!((alpha_of::<BackgroundColor>(&app, panel, |c| c.0) - 0.4).abs() < 1e-5);
+        assert!((alpha_of::<TextColor>(&app, label, |c| c.0) - 0.5).abs() < 1e-5);
+
+        // Stable across frames; a nested opacity multiplies in.
+        app.world_mut().entity_mut(label).insert(ReactOpacity(0.5));
+        app.update();
+        app.update();
+        assert!((alpha_of::<BackgroundColor>(&app, panel, |c| c.0) - 0.4).abs() < 1e-5);
+        assert!((alpha_of::<TextColor>(&app, label, |c| c.0) - 0.25).abs() < 1e-5);
+
+        app.world_mut().entity_mut(panel).remove::<ReactOpacity>();
+        app.world_mut().entity_mut(label).remove::<ReactOpacity>();
+        app.update();
+        assert_eq!(alpha_of::<BackgroundColor>(&app, panel, |c| c.0), 0.8);
+        assert_eq!(alpha_of::<TextColor>(&app, label, |c| c.0), 1.0);
+        assert!(app.world().get::<ReactFadedColors>(label).is_none());
+    }
+
+    #[test]
+    fn test_rewritten_colors_become_the_new_base() {
+        let (mut app, _, label) = setup();
+        app.update();
+        app.world_mut()
+            .entity_mut(label)
+            .insert(TextColor(Color::srgba(0.0, 0.0, 0.0, 0.6)));
+        app.update();
+        assert!((alpha_of::<TextColor>(&app, label, |c| c.0) - 0.3).abs() < 1e-5);
+    }
+}
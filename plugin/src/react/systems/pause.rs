@@ -0,0 +1,160 @@
+//! Pause / resume React roots without tearing them down ([`ReactRootPaused`]).
+
+use bevy::prelude::*;
+
+use crate::js_bevy::JsClientResource;
+use crate::react::systems::types::*;
+
+/// Visibility the root had before it was paused; restored on resume.
+#[derive(Component, Clone, Copy, Debug)]
+pub(crate) struct ReactRootPausedVisibility(Visibility);
+
+/// Observer: hide a root and tell JS to suspend when [`ReactRootPaused`] is inserted.
+#[allow(clippy::type_complexity)]
+pub(crate) fn on_react_root_paused(
+    add: On<Add, ReactRootPaused>,
+    roots: Query<(
+        &ReactRoot,
+        Option<&Visibility>,
+        Option<&ReactScriptSource>,
+        Option<&ReactRootExport>,
+    )>,
+    mut commands: Commands,
+    js_client: Option<Res<JsClientResource>>,
+) {
+    let entity = add.entity;
+    let Ok((root, visibility, source, export)) = roots.get(entity) else {
+        return;
+    };
+
+    commands.entity(entity).insert((
+        ReactRootPausedVisibility(visibility.copied().unwrap_or_default()),
+        Visibility::Hidden,
+    ));
+
+    if let (Some(js_client), Some(source)) = (js_client, source) {
+        js_client.execute(root_lifecycle_script(source, export, &root.id, "suspend"));
+    }
+    log::info!("Paused React root {}", root.id);
+}
+
+/// Observer: restore visibility and tell JS to resume when [`ReactRootPaused`] is removed.
+#[allow(clippy::type_complexity)]
+pub(crate) fn on_react_root_resumed(
+    remove: On<Remove, ReactRootPaused>,
+    roots: Query<(
+        &ReactRoot,
+        Option<&ReactRootPausedVisibility>,
+        Option<&ReactScriptSource>,
+        Option<&ReactRootExport>,
+    )>,
+    mut commands: Commands,
+    js_client: Option<Res<JsClientResource>>,
+) {
+    let entity = remove.entity;
+    let Ok((root, visibility, source, export)) = roots.get(entity) else {
+        return;
+    };
+
+    // `Remove` also fires on despawn, so the entity may already be going away.
+    if let Ok(mut entity_commands) = commands.get_entity(entity) {
+        let restored = visibility.map(|v| v.0).unwrap_or_default();
+        entity_commands
+            .try_insert(restored)
+            .try_remove::<ReactRootPausedVisibility>();
+    }
+
+    if let (Some(js_client), Some(source)) = (js_client, source) {
+        js_client.execute(root_lifecycle_script(source, export, &root.id, "resume"));
+    }
+    log::info!("Resumed React root {}", root.id);
+}
+
+/// Call an optional lifecycle method (`suspend` / `resume`) on the root's export.
+fn root_lifecycle_script(
+    source: &ReactScriptSource,
+    export: Option<&ReactRootExport>,
+    root_id: &str,
+    method: &str,
+) -> String {
+    let export = export.cloned().unwrap_or_default();
+    format!(
+        r#"
+            (async () => {{
+                try {{
+                    const mod = await import('{module}');
+                    const app = mod{accessor};
+                    if (app && typeof app.{method} === 'function') {{
+                        app.{method}('{root_id}');
+                    }}
+                }} catch (err) {{
+                    console.warn('[bevy-react] Failed to {method} root {root_id}:', err);
+                }}
+            }})()
+        "#,
+        module = &source.module_name,
+        accessor = export.js_accessor(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup_app() -> (App, Entity) {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_observer(on_react_root_paused)
+            .add_observer(on_react_root_resumed);
+        let root = app
+            .world_mut()
+            .spawn((
+                ReactRoot::new(),
+                Node::default(),
+                Visibility::Visible,
+                ReactScriptSource::from_string("app", ""),
+            ))
+            .id();
+        (app, root)
+    }
+
+    #[test]
+    fn test_pause_hides_root_and_resume_restores_visibility() {
+        let (mut app, root) = setup_app();
+
+        app.world_mut().entity_mut(root).insert(ReactRootPaused);
+        app.update();
+        assert_eq!(
+            app.world().get::<Visibility>(root),
+            Some(&Visibility::Hidden)
+        );
+
+        app.world_mut().entity_mut(root).remove::<ReactRootPaused>();
+        app.update();
+        assert_eq!(
+            app.world().get::<Visibility>(root),
+            Some(&Visibility::Visible)
+        );
+        assert!(app.world().get::<ReactRootPausedVisibility>(root).is_none());
+    }
+
+    #[test]
+    fn test_despawning_paused_root_does_not_panic() {
+        let (mut app, root) = setup_app();
+
+        app.world_mut().entity_mut(root).insert(ReactRootPaused);
+        app.update();
+        app.world_mut().entity_mut(root).despawn();
+        app.update();
+        assert!(app.world().get_entity(root).is_err());
+    }
+
+    #[test]
+    fn test_lifecycle_script_calls_export_method() {
+        let source = ReactScriptSource::from_string("screens", "");
+        let export = ReactRootExport::new("PauseMenu");
+        let script = root_lifecycle_script(&source, Some(&export), "root-1", "suspend");
+        assert!(script.contains(r#"const app = mod["PauseMenu"];"#));
+        assert!(script.contains("app.suspend('root-1')"));
+    }
+}
@@ -1,17 +1,37 @@
+use bevy::platform::collections::HashMap;
 use bevy::prelude::*;
 use bevy::text::TextLayout;
 use bevy::ui::FocusPolicy;
+use serde_json::Value;
 
+use crate::react::anchor;
+use crate::react::animation::{Keyframes, ReactKeyframes};
+use crate::react::atlas;
+use crate::react::camera_viewport::{self, ReactCameraViewport};
 use crate::react::client::ReactClientProto;
 use crate::react::components_registry::{self, ReactEntityMap};
+use crate::react::fonts::{ReactFontRegistry, style_font};
+use crate::react::grid::{ReactGridItem, ReactGridTemplate, sync_grid_names};
+use crate::react::images::{ReactImageCache, sync_image_listener};
+use crate::react::materials::{ReactMaterialHost, sync_react_material};
 use crate::react::style::{
-    json_to_style, parse_color, parse_props, parse_val, style_font_family, style_line_height,
-    style_object_fit, style_opacity, style_pointer_events, style_text_align, style_tint,
+    json_to_style, parse_color, parse_props, parse_val, style_image_mode, style_line_height,
+    style_opacity, style_pointer_events, style_text_layout, style_tint,
     style_to_background_gradient, style_to_border_color, style_to_border_radius,
-    style_to_box_shadow, PointerEvents, StyleProps,
+    style_to_box_shadow, style_transform, NodeProps, PointerEvents, StyleProps,
 };
+use crate::react::stylesheet::{ReactComposedStyle, ReactStyleSheet, sync_composed_style};
+use crate::react::svg::{self, ReactSvg};
+use crate::react::systems::cursor::{ReactCursor, sync_react_cursor};
 use crate::react::systems::interaction_style::sync_react_style_state;
+use crate::react::systems::layout_events::sync_layout_listener;
+use crate::react::systems::lengths::{ReactCalcLengths, sync_calc_lengths};
+use crate::react::systems::opacity::{ReactOpacity, sync_react_opacity};
+use crate::react::systems::text_overflow::ReactTextOverflow;
+use crate::react::systems::text_spans::{ReactTextStyle, set_text_content};
+use crate::react::systems::transform::sync_react_transform;
 use crate::react::systems::types::*;
+use crate::react::theme::sync_theme_scope;
 use crate::react::widgets::{
     insert_button_widget, insert_checkbox_widget, insert_slider_thumb, insert_slider_widget,
     sync_widget_props,
@@ -22,22 +42,34 @@ pub fn process_react_messages(
     mut commands: Commands,
     receiver: Option<Res<ReactMessageReceiver>>,
     asset_server: Res<AssetServer>,
+    fonts: Option<Res<ReactFontRegistry>>,
+    image_cache: Option<Res<ReactImageCache>>,
     root_map: Res<ReactRootMap>,
     entity_map: Res<ReactEntityMap>,
     default_font: Option<Res<ReactDefaultFont>>,
     root_fonts: Query<&ReactRootFont>,
     mut contexts: Query<(Entity, Mut<ReactContext>)>,
     text_nodes: Query<(), With<ReactTextNode>>,
+    svg_nodes: Query<(), With<ReactSvg>>,
+    material_nodes: Query<(), With<ReactMaterialHost>>,
+    mut pending_ops: Local<HashMap<String, usize>>,
 ) {
     let Some(receiver) = receiver else {
         return;
     };
 
     let plugin_default = default_font.and_then(|f| f.0.clone());
+    let no_fonts = ReactFontRegistry::default();
+    let fonts = fonts.as_deref().unwrap_or(&no_fonts);
+    let no_images = ReactImageCache::default();
+    let images = image_cache.as_deref().unwrap_or(&no_images);
 
     // Process all pending messages
     while let Some(message) = receiver.0.try_recv() {
         log::trace!("Processing React message: {:?}", message);
+        if let Some(root_id) = mutation_root_id(&message) {
+            *pending_ops.entry(root_id.to_string()).or_default() += 1;
+        }
         match message {
             ReactClientProto::CreateNode {
                 root_id,
@@ -57,6 +89,8 @@ pub fn process_react_messages(
                     &mut commands,
                     context.as_mut(),
                     &asset_server,
+                    images,
+                    fonts,
                     fallback_font.as_ref(),
                     &entity_map,
                     node_id,
@@ -141,17 +175,29 @@ pub fn process_react_messages(
                     .get(&node_id)
                     .map(|entity| text_nodes.get(*entity).is_ok())
                     .unwrap_or(false);
+                let is_svg = context
+                    .nodes
+                    .get(&node_id)
+                    .is_some_and(|entity| svg_nodes.contains(*entity));
+                let is_material = context
+                    .nodes
+                    .get(&node_id)
+                    .is_some_and(|entity| material_nodes.contains(*entity));
                 let fallback_font = resolve_fallback_font(*root, &root_fonts, &plugin_default);
 
                 handle_update_node(
                     &mut commands,
                     context.as_mut(),
                     &asset_server,
+                    images,
+                    fonts,
                     fallback_font.as_ref(),
                     &entity_map,
                     node_id,
                     &props_json,
                     is_text,
+                    is_svg,
+                    is_material,
                 );
             }
 
@@ -226,10 +272,80 @@ pub fn process_react_messages(
             ReactClientProto::Complete => {
                 log::trace!("React batch complete");
             }
+
+            ReactClientProto::Commit { root_id } => {
+                let ops = pending_ops.remove(&root_id).unwrap_or(0);
+                let Some(&root) = root_map.roots.get(&root_id) else {
+                    continue;
+                };
+                write_lifecycle_message(&mut commands, ReactRootCommitted { root, ops });
+            }
+
+            ReactClientProto::RootMounted { root_id } => {
+                let Some(&root) = root_map.roots.get(&root_id) else {
+                    continue;
+                };
+                log::info!("React root {} mounted", root_id);
+                write_lifecycle_message(&mut commands, ReactRootMounted { root });
+            }
+
+            ReactClientProto::RootFailed { root_id, error } => {
+                pending_ops.remove(&root_id);
+                let Some(&root) = root_map.roots.get(&root_id) else {
+                    continue;
+                };
+                log::error!("React root {} failed: {}", root_id, error);
+                write_lifecycle_message(&mut commands, ReactRootFailed { root, error });
+            }
+
+            ReactClientProto::RegisterKeyframes {
+                name,
+                keyframes_json,
+            } => {
+                let Some(keyframes) = Keyframes::parse(&keyframes_json) else {
+                    log::warn!("Invalid keyframes '{}', ignoring", name);
+                    continue;
+                };
+                commands.queue(move |world: &mut World| {
+                    world
+                        .get_resource_or_init::<ReactKeyframes>()
+                        .insert(name, keyframes);
+                });
+            }
+
+            ReactClientProto::RegisterStyle { name, style_json } => {
+                commands.queue(move |world: &mut World| {
+                    let mut sheet = world.get_resource_or_init::<ReactStyleSheet>();
+                    if let Err(e) = sheet.insert_json(name.as_str(), &style_json) {
+                        log::warn!("Invalid style class '{}', ignoring: {}", name, e);
+                    }
+                });
+            }
         }
     }
 }
 
+/// Root of a tree mutation, counted toward the next [`ReactRootCommitted`].
+fn mutation_root_id(message: &ReactClientProto) -> Option<&str> {
+    match message {
+        ReactClientProto::CreateNode { root_id, .. }
+        | ReactClientProto::CreateText { root_id, .. }
+        | ReactClientProto::AppendChild { root_id, .. }
+        | ReactClientProto::InsertBefore { root_id, .. }
+        | ReactClientProto::RemoveChild { root_id, .. }
+        | ReactClientProto::UpdateNode { root_id, .. }
+        | ReactClientProto::UpdateText { root_id, .. }
+        | ReactClientProto::DestroyNode { root_id, .. }
+        | ReactClientProto::ClearContainer { root_id } => Some(root_id),
+        ReactClientProto::Complete
+        | ReactClientProto::Commit { .. }
+        | ReactClientProto::RootMounted { .. }
+        | ReactClientProto::RootFailed { .. }
+        | ReactClientProto::RegisterKeyframes { .. }
+        | ReactClientProto::RegisterStyle { .. } => None,
+    }
+}
+
 fn resolve_fallback_font(
     root: Entity,
     root_fonts: &Query<&ReactRootFont>,
@@ -247,13 +363,23 @@ fn handle_create_node(
     commands: &mut Commands,
     context: &mut ReactContext,
     asset_server: &AssetServer,
+    images: &ReactImageCache,
+    fonts: &ReactFontRegistry,
     fallback_font: Option<&Handle<Font>>,
     entity_map: &ReactEntityMap,
     node_id: u64,
     node_type: &str,
     props_json: &str,
 ) {
-    let props = parse_props(props_json);
+    let mut props = parse_props(props_json);
+    // Untyped props for the `sync_*` helpers, parsed once per message.
+    let props_value: Value = serde_json::from_str(props_json).unwrap_or_default();
+    // Class / `var()` styles wait for `apply_composed_styles`: theme scopes need the parent chain.
+    let composed =
+        ReactComposedStyle::from_props(&props_value, node_type == "bevy-text", fallback_font);
+    if composed.is_some() {
+        props.style = None;
+    }
     let style = props.style.as_ref().map(json_to_style).unwrap_or_default();
 
     let mut entity_commands = match node_type {
@@ -286,25 +412,56 @@ fn handle_create_node(
             // Image node
             let mut cmd = commands.spawn((style, ReactNode { node_id }));
 
-            if let Some(image_path) = props.src.clone().or(props.image.clone()) {
-                let image_handle: Handle<Image> = asset_server.load(image_path);
-                let mut image_node = ImageNode::new(image_handle);
-                if let Some(ref style_props) = props.style {
-                    if let Some(mode) = style_object_fit(style_props) {
-                        image_node.image_mode = mode;
-                    }
-                    if let Some(tint) = style_tint(style_props) {
-                        image_node.color = tint;
-                    }
-                    if let Some(opacity) = style_opacity(style_props) {
-                        image_node.color.set_alpha(opacity);
-                    }
-                }
+            if let Some(image_node) = image_node_from_props(
+                &props,
+                &props_value,
+                props.style.as_ref(),
+                asset_server,
+                images,
+            ) {
                 cmd.insert(image_node);
+                if let Some(animation) =
+                    atlas::parse_image_atlas(&props_value).and_then(|atlas| atlas.animation())
+                {
+                    cmd.insert(animation);
+                }
             }
             cmd
         }
 
+        "bevy-svg" => {
+            // SVG node, rasterized into its ImageNode by `rasterize_react_svgs`
+            let tint = props.style.as_ref().and_then(style_tint);
+            commands.spawn((
+                style,
+                ReactNode { node_id },
+                ImageNode {
+                    color: tint.unwrap_or(Color::WHITE),
+                    image_mode: NodeImageMode::Stretch,
+                    ..default()
+                },
+                svg::react_svg_from_props(&props, asset_server),
+            ))
+        }
+
+        "bevy-viewport" => {
+            // Camera view, sized and bound by `update_react_camera_viewports`
+            commands.spawn((
+                style,
+                ReactNode { node_id },
+                ImageNode {
+                    image_mode: NodeImageMode::Stretch,
+                    ..default()
+                },
+                ReactCameraViewport::new(camera_viewport::parse_viewport_camera(&props_value)),
+            ))
+        }
+
+        "bevy-material" => {
+            // Registered UiMaterial, inserted by `sync_react_material`
+            commands.spawn((style, ReactNode { node_id }, ReactMaterialHost))
+        }
+
         "bevy-text" => {
             // Text node with content
             let content = props.content.as_deref().unwrap_or("");
@@ -316,7 +473,7 @@ fn handle_create_node(
 
             // Apply text styling
             if let Some(ref style_props) = props.style {
-                apply_text_style(&mut cmd, style_props, asset_server, fallback_font);
+                apply_text_style(&mut cmd, style_props, asset_server, fonts, fallback_font);
             } else if let Some(font) = fallback_font {
                 cmd.insert(TextFont {
                     font: font.clone(),
@@ -367,10 +524,53 @@ fn handle_create_node(
         entity_commands.insert(ReactStyleState::from_props(style_props));
     }
 
+    // Subtree opacity, multiplied into descendants' colors by `apply_react_opacity`
+    if let Some(ref style_props) = props.style
+        && let Some(opacity) = style_opacity(style_props)
+    {
+        entity_commands.insert(ReactOpacity(opacity.clamp(0.0, 1.0)));
+    }
+
+    // CSS cursor, shown on the window by `apply_react_cursor`
+    if let Some(ref style_props) = props.style
+        && let Some(cursor) = ReactCursor::from_style(style_props, asset_server)
+    {
+        entity_commands.insert(cursor);
+    }
+
+    // calc() lengths resolved against the parent each layout pass
+    if let Some(ref style_props) = props.style
+        && let Some(lengths) = ReactCalcLengths::from_style(style_props)
+    {
+        entity_commands.insert(lengths);
+    }
+
+    // Named grid lines / areas, resolved before layout
+    if let Some(ref style_props) = props.style {
+        if let Some(template) = ReactGridTemplate::from_style(style_props) {
+            entity_commands.insert(template);
+        }
+        if let Some(item) = ReactGridItem::from_style(style_props) {
+            entity_commands.insert(item);
+        }
+    }
+
     let entity = entity_commands.id();
     drop(entity_commands);
     context.nodes.insert(node_id, entity);
     components_registry::sync_bundle_names(commands, entity_map, entity, node_id, props_json);
+    sync_theme_scope(commands, entity, &props_value);
+    sync_layout_listener(commands, entity, &props_value);
+    sync_composed_style(commands, entity, composed);
+    if node_type != "bevy-text" {
+        anchor::sync_anchor(commands, entity, &props_value);
+    }
+    if node_type == "bevy-image" {
+        sync_image_listener(commands, entity, &props_value);
+    }
+    if node_type == "bevy-material" {
+        sync_react_material(commands, entity, &props_value);
+    }
 
     log::debug!(
         "Created {} node: id={} entity={:?}",
@@ -393,6 +593,7 @@ fn handle_create_text(
         Text::new(content.to_string()),
         ReactNode { node_id },
         ReactTextNode,
+        ReactRawText,
     ));
     if let Some(font) = fallback_font {
         cmd.insert(TextFont {
@@ -468,11 +669,15 @@ fn handle_update_node(
     commands: &mut Commands,
     context: &ReactContext,
     asset_server: &AssetServer,
+    images: &ReactImageCache,
+    fonts: &ReactFontRegistry,
     fallback_font: Option<&Handle<Font>>,
     entity_map: &ReactEntityMap,
     node_id: u64,
     props_json: &str,
     is_text: bool,
+    is_svg: bool,
+    is_material: bool,
 ) {
     let Some(entity) = context.nodes.get(&node_id).copied() else {
         log::warn!("Failed to update node: id={} (entity not found)", node_id);
@@ -480,25 +685,161 @@ fn handle_update_node(
     };
 
     let props = parse_props(props_json);
+    // Untyped props for the `sync_*` helpers, parsed once per message.
+    let props_value: Value = serde_json::from_str(props_json).unwrap_or_default();
+    // Sources first: the style below sets `tint` / `objectFit` / `currentColor` on them.
+    if is_svg {
+        svg::sync_react_svg(commands, entity, &props, asset_server);
+    } else if !is_text
+        && let Some(image_node) =
+            image_node_from_props(&props, &props_value, None, asset_server, images)
+    {
+        update_image_node(commands, entity, image_node);
+        atlas::sync_atlas_animation(commands, entity, &props_value);
+        sync_image_listener(commands, entity, &props_value);
+    }
+
+    // Styles using `className` or `var()` are applied by `apply_composed_styles` instead.
+    let composed = ReactComposedStyle::from_props(&props_value, is_text, fallback_font);
+    if composed.is_none() {
+        apply_node_style(
+            commands,
+            entity,
+            props.style.as_ref(),
+            is_text,
+            asset_server,
+            fonts,
+            fallback_font,
+        );
+    }
+    sync_theme_scope(commands, entity, &props_value);
+    sync_layout_listener(commands, entity, &props_value);
+    sync_composed_style(commands, entity, composed);
 
     if is_text {
         // Text nodes: only Text / TextColor / TextFont / layout — never BackgroundColor
         if let Some(ref content) = props.content {
-            commands.entity(entity).insert(Text::new(content.clone()));
+            set_text_content(commands, entity, content.clone());
         }
-        match props.style.as_ref() {
+        components_registry::sync_bundle_names(commands, entity_map, entity, node_id, props_json);
+        log::debug!("Updated text node: id={}", node_id);
+        return;
+    }
+
+    // Sync headless widget value/checked/disabled from React props.
+    sync_widget_props(commands, entity, props_json.to_string());
+    anchor::sync_anchor(commands, entity, &props_value);
+    camera_viewport::sync_camera_viewport(commands, entity, &props_value);
+    if is_material {
+        sync_react_material(commands, entity, &props_value);
+    }
+
+    components_registry::sync_bundle_names(commands, entity_map, entity, node_id, props_json);
+    log::debug!("Updated node: id={}", node_id);
+}
+
+/// [`ImageNode`] for an image's `src` (asset path, URL or `data:` URI):
+/// the `atlas` frame, plus `objectFit` / `imageSlice` and `tint` from `style`.
+fn image_node_from_props(
+    props: &NodeProps,
+    props_value: &Value,
+    style: Option<&StyleProps>,
+    asset_server: &AssetServer,
+    images: &ReactImageCache,
+) -> Option<ImageNode> {
+    let image_path = props.src.as_deref().or(props.image.as_deref())?;
+    let mut image_node = ImageNode::new(images.load(image_path, asset_server));
+    if let Some(style_props) = style {
+        if let Some(mode) = style_image_mode(style_props) {
+            image_node.image_mode = mode;
+        }
+        if let Some(tint) = style_tint(style_props) {
+            image_node.color = tint;
+        }
+    }
+    if let Some(atlas) = atlas::parse_image_atlas(props_value) {
+        image_node.texture_atlas = Some(atlas::image_texture_atlas(&atlas, asset_server));
+    }
+    Some(image_node)
+}
+
+/// Swap an image's source / atlas on prop updates, keeping the `tint` and
+/// `objectFit` that [`apply_node_style`] resolved (themes and classes included).
+fn update_image_node(commands: &mut Commands, entity: Entity, mut image_node: ImageNode) {
+    commands.queue(move |world: &mut World| {
+        let Ok(mut entity_mut) = world.get_entity_mut(entity) else {
+            return;
+        };
+        if let Some(current) = entity_mut.get::<ImageNode>() {
+            image_node.color = current.color;
+            image_node.image_mode = current.image_mode.clone();
+        }
+        entity_mut.insert(image_node);
+    });
+}
+
+/// Image visuals from the resolved style: `tint` for any [`ImageNode`],
+/// `objectFit` / `imageSlice` for `<image>`, and `color` as an `<svg>`'s
+/// `currentColor`. SVGs and camera viewports keep their stretched raster.
+fn sync_image_style(commands: &mut Commands, entity: Entity, style: Option<&StyleProps>) {
+    let tint = style.and_then(style_tint).unwrap_or(Color::WHITE);
+    let mode = style.and_then(style_image_mode).unwrap_or_default();
+    let color = style
+        .and_then(|style| style.color.as_deref())
+        .and_then(parse_color);
+    commands.queue(move |world: &mut World| {
+        let Ok(mut entity_mut) = world.get_entity_mut(entity) else {
+            return;
+        };
+        if let Some(mut svg) = entity_mut.get_mut::<ReactSvg>()
+            && svg.color() != color
+        {
+            svg.set_color(color);
+        }
+        let stretched =
+            entity_mut.contains::<ReactSvg>() || entity_mut.contains::<ReactCameraViewport>();
+        let Some(mut image_node) = entity_mut.get_mut::<ImageNode>() else {
+            return;
+        };
+        if image_node.color != tint {
+            image_node.color = tint;
+        }
+        if !stretched && image_node.image_mode != mode {
+            image_node.image_mode = mode;
+        }
+    });
+}
+
+/// Apply (or clear) a node's resolved `style`: layout, visuals, text and host-side
+/// interaction state. Shared by prop updates and theme changes.
+pub(crate) fn apply_node_style(
+    commands: &mut Commands,
+    entity: Entity,
+    style: Option<&StyleProps>,
+    is_text: bool,
+    asset_server: &AssetServer,
+    fonts: &ReactFontRegistry,
+    fallback_font: Option<&Handle<Font>>,
+) {
+    sync_react_opacity(commands, entity, style);
+    sync_react_cursor(commands, entity, style, asset_server);
+    if is_text {
+        match style {
             Some(style_props) => {
                 apply_text_style_commands(
                     commands,
                     entity,
                     style_props,
                     asset_server,
+                    fonts,
                     fallback_font,
                 );
             }
             None => {
                 commands.entity(entity).remove::<TextColor>();
                 commands.entity(entity).remove::<TextLayout>();
+                commands.entity(entity).remove::<ReactTextStyle>();
+                commands.entity(entity).remove::<ReactTextOverflow>();
                 match fallback_font {
                     Some(font) => {
                         commands.entity(entity).insert(TextFont {
@@ -512,15 +853,13 @@ fn handle_update_node(
                 }
             }
         }
-        components_registry::sync_bundle_names(commands, entity_map, entity, node_id, props_json);
-        log::debug!("Updated text node: id={}", node_id);
         return;
     }
 
-    match props.style.as_ref() {
+    match style {
         Some(style_props) => {
-            let style = json_to_style(style_props);
-            commands.entity(entity).insert(style);
+            let node = json_to_style(style_props);
+            commands.entity(entity).insert(node);
             apply_visual_style_commands(commands, entity, style_props);
 
             // ZIndex
@@ -545,34 +884,14 @@ fn handle_update_node(
             commands.entity(entity).remove::<Visibility>();
             commands.entity(entity).remove::<Pickable>();
             commands.entity(entity).remove::<FocusPolicy>();
+            sync_react_transform(commands, entity, None);
         }
     }
 
-    sync_react_style_state(commands, entity, props.style.as_ref());
-
-    // Sync headless widget value/checked/disabled from React props.
-    sync_widget_props(commands, entity, props_json.to_string());
-
-    // Update image if provided
-    if let Some(image_path) = props.src.clone().or(props.image.clone()) {
-        let image_handle: Handle<Image> = asset_server.load(image_path);
-        let mut image_node = ImageNode::new(image_handle);
-        if let Some(ref style_props) = props.style {
-            if let Some(mode) = style_object_fit(style_props) {
-                image_node.image_mode = mode;
-            }
-            if let Some(tint) = style_tint(style_props) {
-                image_node.color = tint;
-            }
-            if let Some(opacity) = style_opacity(style_props) {
-                image_node.color.set_alpha(opacity);
-            }
-        }
-        commands.entity(entity).insert(image_node);
-    }
-
-    components_registry::sync_bundle_names(commands, entity_map, entity, node_id, props_json);
-    log::debug!("Updated node: id={}", node_id);
+    sync_image_style(commands, entity, style);
+    sync_calc_lengths(commands, entity, style);
+    sync_grid_names(commands, entity, style);
+    sync_react_style_state(commands, entity, style);
 }
 
 /// Update text content
@@ -587,9 +906,7 @@ fn handle_update_text(
         return;
     };
 
-    commands
-        .entity(entity)
-        .insert(Text::new(content.to_string()));
+    set_text_content(commands, entity, content.to_string());
     log::debug!("Updated text: id={} content={}", node_id, content);
 }
 
@@ -740,21 +1057,12 @@ fn handle_clear_container(
 }
 
 fn apply_visual_style(entity_commands: &mut EntityCommands, style_props: &StyleProps) {
-    match style_props.background_color.as_deref().and_then(parse_color) {
-        Some(mut color) => {
-            if let Some(opacity) = style_opacity(style_props) {
-                color.set_alpha(opacity);
-            }
-            entity_commands.insert(BackgroundColor(color));
-        }
-        None => {
-            // Opacity without an explicit background still needs a carrier color.
-            if let Some(opacity) = style_opacity(style_props) {
-                let mut color = Color::WHITE;
-                color.set_alpha(opacity);
-                entity_commands.insert(BackgroundColor(color));
-            }
-        }
+    if let Some(color) = style_props
+        .background_color
+        .as_deref()
+        .and_then(parse_color)
+    {
+        entity_commands.insert(BackgroundColor(color));
     }
 
     if let Some(border_color) = style_to_border_color(style_props) {
@@ -769,6 +1077,9 @@ fn apply_visual_style(entity_commands: &mut EntityCommands, style_props: &StyleP
     if let Some(gradient) = style_to_background_gradient(style_props) {
         entity_commands.insert(gradient);
     }
+    if let Some(transform) = style_transform(style_props) {
+        entity_commands.insert(transform);
+    }
     if let Some(d) = style_props.display.as_deref()
         && d.eq_ignore_ascii_case("none")
     {
@@ -816,20 +1127,11 @@ fn apply_pointer_events_commands(
 
 fn apply_visual_style_commands(commands: &mut Commands, entity: Entity, style_props: &StyleProps) {
     match style_props.background_color.as_deref().and_then(parse_color) {
-        Some(mut color) => {
-            if let Some(opacity) = style_opacity(style_props) {
-                color.set_alpha(opacity);
-            }
+        Some(color) => {
             commands.entity(entity).insert(BackgroundColor(color));
         }
         None => {
-            if style_opacity(style_props).is_none() {
-                commands.entity(entity).remove::<BackgroundColor>();
-            } else if let Some(opacity) = style_opacity(style_props) {
-                let mut color = Color::WHITE;
-                color.set_alpha(opacity);
-                commands.entity(entity).insert(BackgroundColor(color));
-            }
+            commands.entity(entity).remove::<BackgroundColor>();
         }
     }
 
@@ -865,6 +1167,7 @@ fn apply_visual_style_commands(commands: &mut Commands, entity: Entity, style_pr
             commands.entity(entity).remove::<BackgroundGradient>();
         }
     }
+    sync_react_transform(commands, entity, style_transform(style_props));
 
     match style_props.display.as_deref() {
         Some(d) if d.eq_ignore_ascii_case("none") => {
@@ -882,14 +1185,10 @@ fn apply_text_style(
     cmd: &mut EntityCommands,
     style_props: &StyleProps,
     asset_server: &AssetServer,
+    fonts: &ReactFontRegistry,
     fallback_font: Option<&Handle<Font>>,
 ) {
-    if let Some(ref color_str) = style_props.color
-        && let Some(mut color) = parse_color(color_str)
-    {
-        if let Some(opacity) = style_opacity(style_props) {
-            color.set_alpha(opacity);
-        }
+    if let Some(color) = style_props.color.as_deref().and_then(parse_color) {
         cmd.insert(TextColor(color));
     }
 
@@ -901,8 +1200,8 @@ fn apply_text_style(
         text_font.font_size = px;
         has_font = true;
     }
-    if let Some(path) = style_font_family(style_props) {
-        text_font.font = asset_server.load(path);
+    if let Some(font) = style_font(style_props, fonts, asset_server) {
+        text_font.font = font;
         has_font = true;
     } else if let Some(font) = fallback_font {
         text_font.font = font.clone();
@@ -916,9 +1215,13 @@ fn apply_text_style(
         cmd.insert(text_font);
     }
 
-    if let Some(justify) = style_text_align(style_props) {
-        cmd.insert(TextLayout::new_with_justify(justify));
+    if let Some(layout) = style_text_layout(style_props) {
+        cmd.insert(layout);
+    }
+    if let Some(overflow) = ReactTextOverflow::from_style(style_props) {
+        cmd.insert(overflow);
     }
+    cmd.insert(ReactTextStyle::from_style(style_props));
 }
 
 fn apply_text_style_commands(
@@ -926,13 +1229,11 @@ fn apply_text_style_commands(
     entity: Entity,
     style_props: &StyleProps,
     asset_server: &AssetServer,
+    fonts: &ReactFontRegistry,
     fallback_font: Option<&Handle<Font>>,
 ) {
     match style_props.color.as_deref().and_then(parse_color) {
-        Some(mut color) => {
-            if let Some(opacity) = style_opacity(style_props) {
-                color.set_alpha(opacity);
-            }
+        Some(color) => {
             commands.entity(entity).insert(TextColor(color));
         }
         None => {
@@ -948,8 +1249,8 @@ fn apply_text_style_commands(
         text_font.font_size = px;
         has_font = true;
     }
-    if let Some(path) = style_font_family(style_props) {
-        text_font.font = asset_server.load(path);
+    if let Some(font) = style_font(style_props, fonts, asset_server) {
+        text_font.font = font;
         has_font = true;
     } else if let Some(font) = fallback_font {
         text_font.font = font.clone();
@@ -965,14 +1266,23 @@ fn apply_text_style_commands(
         commands.entity(entity).remove::<TextFont>();
     }
 
-    match style_text_align(style_props) {
-        Some(justify) => {
-            commands
-                .entity(entity)
-                .insert(TextLayout::new_with_justify(justify));
+    match style_text_layout(style_props) {
+        Some(layout) => {
+            commands.entity(entity).insert(layout);
         }
         None => {
             commands.entity(entity).remove::<TextLayout>();
         }
     }
+    match ReactTextOverflow::from_style(style_props) {
+        Some(overflow) => {
+            commands.entity(entity).insert(overflow);
+        }
+        None => {
+            commands.entity(entity).remove::<ReactTextOverflow>();
+        }
+    }
+    commands
+        .entity(entity)
+        .insert(ReactTextStyle::from_style(style_props));
 }
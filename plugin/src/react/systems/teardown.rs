@@ -8,12 +8,14 @@ use crate::react::systems::types::*;
 /// Observer: clean Bevy + JS state when a `ReactRoot` is removed (including despawn).
 ///
 /// Runs while component data is still readable (`Remove` fires before the remove).
+#[allow(clippy::type_complexity)]
 pub fn on_react_root_removed(
     remove: On<Remove, ReactRoot>,
     mut roots: Query<(
         &ReactRoot,
         Option<&mut ReactContext>,
         Option<&ReactScriptSource>,
+        Option<&ReactRootExport>,
     )>,
     mut root_map: ResMut<ReactRootMap>,
     mut focused: ResMut<FocusedNode>,
@@ -21,12 +23,19 @@ pub fn on_react_root_removed(
     js_client: Option<Res<JsClientResource>>,
 ) {
     let entity = remove.entity;
-    let Ok((root, context, source)) = roots.get_mut(entity) else {
+    let Ok((root, context, source, export)) = roots.get_mut(entity) else {
         return;
     };
 
     let root_id = root.id.clone();
     root_map.roots.remove(&root_id);
+    write_lifecycle_message(
+        &mut commands,
+        ReactRootUnmounted {
+            root: entity,
+            root_id: root_id.clone(),
+        },
+    );
 
     let node_entities: Vec<Entity> = context
         .as_ref()
@@ -73,13 +82,15 @@ pub fn on_react_root_removed(
         return;
     };
 
+    let export = export.cloned().unwrap_or_default();
     js_client.execute(format!(
         r#"
             (async () => {{
                 try {{
                     const mod = await import('{module}');
-                    if (mod.default && typeof mod.default.unmount === 'function') {{
-                        mod.default.unmount('{root_id}');
+                    const app = mod{accessor};
+                    if (app && typeof app.unmount === 'function') {{
+                        app.unmount('{root_id}');
                     }} else if (typeof __react_unmount_root === 'function') {{
                         __react_unmount_root('{root_id}');
                     }}
@@ -89,6 +100,7 @@ pub fn on_react_root_removed(
             }})()
         "#,
         module = &source.module_name,
+        accessor = export.js_accessor(),
         root_id = root_id,
     ));
 
@@ -0,0 +1,428 @@
+//! `whiteSpace`, `textOverflow` and `maxLines` on text nodes.
+//!
+//! Bevy lays text out without a notion of overflow, so
+//! [`truncate_overflowing_text`] works after layout: it reads the laid out
+//! lines of each `Text` with [`ReactTextOverflow`], cuts the content (spans
+//! included) after the last glyph that fits, appends `…`, and checks again once
+//! Bevy has laid the shorter text out, until it fits. The untruncated content
+//! is kept, so content updates, a wider box or clearing the props restore it.
+
+use bevy::prelude::*;
+use bevy::text::{ComputedTextBlock, TextLayoutInfo};
+
+use crate::react::style::{StyleProps, style_max_lines, style_text_ellipsis, style_white_space};
+
+const ELLIPSIS: &str = "\u{2026}";
+
+/// CSS `white-space`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WhiteSpace {
+    #[default]
+    Normal,
+    NoWrap,
+    Pre,
+    PreWrap,
+    PreLine,
+}
+
+impl WhiteSpace {
+    pub fn wraps(self) -> bool {
+        !matches!(self, Self::NoWrap | Self::Pre)
+    }
+
+    /// Collapse white space runs as CSS does: into one space for `normal` /
+    /// `nowrap`, keeping newlines for `pre-line`, not at all for `pre*`.
+    pub fn collapse(self, text: &str) -> String {
+        let keep_newlines = match self {
+            Self::Pre | Self::PreWrap => return text.to_string(),
+            Self::Normal | Self::NoWrap => false,
+            Self::PreLine => true,
+        };
+        let mut collapsed = String::with_capacity(text.len());
+        for ch in text.chars() {
+            if keep_newlines && ch == '\n' {
+                collapsed.truncate(collapsed.trim_end_matches(' ').len());
+                collapsed.push('\n');
+            } else if ch.is_whitespace() {
+                if !(collapsed.ends_with(' ') || keep_newlines && collapsed.ends_with('\n')) {
+                    collapsed.push(' ');
+                }
+            } else {
+                collapsed.push(ch);
+            }
+        }
+        collapsed
+    }
+}
+
+/// A text node's `whiteSpace` / `textOverflow` / `maxLines`.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Default)]
+pub struct ReactTextOverflow {
+    /// `None` renders the content as written.
+    pub white_space: Option<WhiteSpace>,
+    pub ellipsis: bool,
+    pub max_lines: Option<usize>,
+}
+
+impl ReactTextOverflow {
+    /// `None` when the style sets none of the three props.
+    pub fn from_style(style: &StyleProps) -> Option<Self> {
+        let overflow = Self {
+            white_space: style_white_space(style),
+            ellipsis: style_text_ellipsis(style),
+            max_lines: style_max_lines(style),
+        };
+        (overflow != Self::default()).then_some(overflow)
+    }
+
+    fn displayed(&self, source: &[(Entity, String)]) -> Vec<(Entity, String)> {
+        source
+            .iter()
+            .map(|(entity, text)| {
+                let text = match self.white_space {
+                    Some(white_space) => white_space.collapse(text),
+                    None => text.clone(),
+                };
+                (*entity, text)
+            })
+            .collect()
+    }
+}
+
+/// Content of a text block (the root `Text`, then its spans) as React set it
+/// and as currently shown.
+#[derive(Component, Debug, Clone, Default)]
+pub struct ReactTextTruncation {
+    source: Vec<(Entity, String)>,
+    shown: Vec<(Entity, String)>,
+    /// Byte offset into the joined displayed text where it is cut.
+    cut: Option<usize>,
+    /// Content width of the node and width of its parent.
+    bounds: Vec2,
+    /// Shown content changed and Bevy has not laid it out yet.
+    pending: bool,
+}
+
+type OverflowRoots = (
+    Entity,
+    Option<Ref<'static, ReactTextOverflow>>,
+    Option<&'static mut ReactTextTruncation>,
+    &'static mut Text,
+    &'static ComputedTextBlock,
+    &'static ComputedNode,
+    Ref<'static, TextLayoutInfo>,
+    Option<&'static ChildOf>,
+);
+
+/// Text roots that truncate now or did last frame (and may need restoring).
+type OverflowFilter = Or<(With<ReactTextOverflow>, With<ReactTextTruncation>)>;
+
+/// Apply `whiteSpace` collapsing and truncate text that overflows its
+/// `maxLines` or, with `textOverflow: "ellipsis"`, its box. Runs after Bevy's
+/// text layout; a cut shows from the next frame.
+pub fn truncate_overflowing_text(
+    mut commands: Commands,
+    mut roots: Query<OverflowRoots, OverflowFilter>,
+    mut spans: Query<&mut TextSpan>,
+    nodes: Query<&ComputedNode>,
+) {
+    for (entity, overflow, state, mut text, block, node, layout, child_of) in &mut roots {
+        let Some(overflow) = overflow else {
+            if let Some(state) = state {
+                write_block(entity, &mut text, &mut spans, &state.source);
+            }
+            commands.entity(entity).remove::<ReactTextTruncation>();
+            continue;
+        };
+
+        let mut added = None;
+        let state = match state {
+            Some(state) => state.into_inner(),
+            None => added.insert(ReactTextTruncation::default()),
+        };
+        let inset = node.content_inset();
+        let bounds = Vec2::new(
+            node.size.x - inset.left - inset.right,
+            child_of
+                .and_then(|child_of| nodes.get(child_of.parent()).ok())
+                .map_or(0.0, |parent| parent.size.x),
+        );
+
+        'update: {
+            let current = block_content(entity, &text, block, &spans);
+            // A wider box may fit more of the cut text: start over from the
+            // full text. Narrower boxes only cut further.
+            let grew = state.cut.is_some()
+                && (bounds.x > state.bounds.x + 0.5 || bounds.y > state.bounds.y + 0.5);
+            if current != state.shown || overflow.is_changed() || grew {
+                // Spans still showing what was written keep their source.
+                state.source = current
+                    .into_iter()
+                    .map(|(span, text)| {
+                        let unchanged = state.shown.contains(&(span, text.clone()));
+                        let source = state
+                            .source
+                            .iter()
+                            .find(|(source, _)| unchanged && *source == span)
+                            .map_or(text, |(_, source)| source.clone());
+                        (span, source)
+                    })
+                    .collect();
+                state.shown = overflow.displayed(&state.source);
+                state.cut = None;
+                state.bounds = bounds;
+                state.pending = write_block(entity, &mut text, &mut spans, &state.shown);
+                break 'update;
+            }
+            state.bounds = bounds;
+
+            if state.pending {
+                if !layout.is_changed() {
+                    break 'update;
+                }
+                state.pending = false;
+            }
+            if !overflow.ellipsis && overflow.max_lines.is_none() {
+                break 'update;
+            }
+            let Some(cut) = overflow_cut(block, &overflow, bounds.x, state.cut) else {
+                break 'update;
+            };
+
+            let full = overflow.displayed(&state.source);
+            let joined: String = full.iter().map(|(_, text)| text.as_str()).collect();
+            let mut cut = cut.min(joined.len());
+            // Each pass cuts further, so truncation always settles.
+            if let Some(shown) = state.cut
+                && cut >= shown
+            {
+                cut = joined[..shown].char_indices().last().map_or(0, |(i, _)| i);
+            }
+            while !joined.is_char_boundary(cut) {
+                cut -= 1;
+            }
+            cut = joined[..cut].trim_end().len();
+            state.cut = Some(cut);
+            state.shown = truncate_block(&full, cut, overflow.ellipsis);
+            state.pending = write_block(entity, &mut text, &mut spans, &state.shown);
+        }
+
+        if let Some(state) = added {
+            commands.entity(entity).insert(state);
+        }
+    }
+}
+
+/// The root's text followed by its spans, in layout order.
+fn block_content(
+    root: Entity,
+    text: &Text,
+    block: &ComputedTextBlock,
+    spans: &Query<&mut TextSpan>,
+) -> Vec<(Entity, String)> {
+    let mut content = vec![(root, text.0.clone())];
+    content.extend(
+        block
+            .entities()
+            .iter()
+            .filter(|span| span.entity != root)
+            .filter_map(|span| Some((span.entity, spans.get(span.entity).ok()?.0.clone()))),
+    );
+    content
+}
+
+/// Write `content` to the root and its spans; `true` if anything changed.
+fn write_block(
+    root: Entity,
+    text: &mut Mut<Text>,
+    spans: &mut Query<&mut TextSpan>,
+    content: &[(Entity, String)],
+) -> bool {
+    let mut changed = false;
+    for (entity, value) in content {
+        if *entity == root {
+            if text.0 != *value {
+                text.0.clone_from(value);
+                changed = true;
+            }
+        } else if let Ok(mut span) = spans.get_mut(*entity)
+            && span.0 != *value
+        {
+            span.0.clone_from(value);
+            changed = true;
+        }
+    }
+    changed
+}
+
+/// Byte offset into the joined shown text after which it must be cut, or
+/// `None` if the laid out text fits. `shown_cut` is where the current
+/// ellipsis sits, to measure it.
+fn overflow_cut(
+    block: &ComputedTextBlock,
+    overflow: &ReactTextOverflow,
+    width: f32,
+    shown_cut: Option<usize>,
+) -> Option<usize> {
+    let buffer = &block.buffer().0;
+    let runs: Vec<_> = buffer.layout_runs().collect();
+    let max_lines = overflow.max_lines.unwrap_or(usize::MAX);
+    let too_wide = runs
+        .iter()
+        .position(|run| overflow.ellipsis && run.line_w > width + 0.5);
+    let clamped = (runs.len() > max_lines).then(|| max_lines - 1);
+    let run = &runs[too_wide.into_iter().chain(clamped).min()?];
+
+    let line_start: usize = buffer.lines[..run.line_i]
+        .iter()
+        .map(|line| line.text().len() + line.ending().as_str().len())
+        .sum();
+    let ellipsis_width = if overflow.ellipsis {
+        run.glyphs
+            .iter()
+            .find(|glyph| Some(line_start + glyph.start) == shown_cut)
+            .map_or(buffer.metrics().font_size, |glyph| glyph.w)
+    } else {
+        0.0
+    };
+    let limit = width - ellipsis_width;
+    let kept = run
+        .glyphs
+        .iter()
+        .take_while(|glyph| glyph.x + glyph.w <= limit)
+        .last()
+        .map(|glyph| glyph.end)
+        .or_else(|| run.glyphs.first().map(|glyph| glyph.start))
+        .unwrap_or(0);
+    Some(line_start + kept)
+}
+
+/// `content` cut at byte `cut` of its joined text, with the ellipsis in the
+/// span holding the last kept character and later spans emptied.
+fn truncate_block(
+    content: &[(Entity, String)],
+    cut: usize,
+    ellipsis: bool,
+) -> Vec<(Entity, String)> {
+    let mut start = 0;
+    let mut cut_done = false;
+    content
+        .iter()
+        .map(|(entity, text)| {
+            let end = start + text.len();
+            let shown = if cut_done {
+                String::new()
+            } else if cut <= end {
+                cut_done = true;
+                let mut shown = text[..cut - start].to_string();
+                if ellipsis {
+                    shown.push_str(ELLIPSIS);
+                }
+                shown
+            } else {
+                text.clone()
+            };
+            start = end;
+            (*entity, shown)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::input::InputPlugin;
+    use bevy::picking::DefaultPickingPlugins;
+    use bevy::text::TextPlugin;
+    use bevy::ui::{UiPlugin, UiSystems};
+    use bevy::window::WindowPlugin;
+
+    use super::*;
+
+    #[test]
+    fn test_white_space_collapsing() {
+        let text = "  Iron\t sword \n  of   doom ";
+        assert_eq!(WhiteSpace::Normal.collapse(text), " Iron sword of doom ");
+        assert_eq!(WhiteSpace::PreLine.collapse(text), " Iron sword\nof doom ");
+        assert_eq!(WhiteSpace::Pre.collapse(text), text);
+        assert!(!WhiteSpace::Pre.wraps());
+        assert!(WhiteSpace::PreLine.wraps());
+    }
+
+    #[test]
+    fn test_line_clamp_after_layout_and_restore() {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            ImagePlugin::default(),
+            WindowPlugin::default(),
+            InputPlugin,
+            DefaultPickingPlugins,
+            TextPlugin,
+            UiPlugin,
+        ))
+        .init_asset::<TextureAtlasLayout>()
+        .add_systems(
+            PostUpdate,
+            truncate_overflowing_text.after(UiSystems::PostLayout),
+        );
+        let source = "Sword   of a   thousand truths and more words here";
+        let text = app
+            .world_mut()
+            .spawn((
+                Text::new(source),
+                Node {
+                    width: Val::Px(120.0),
+                    ..default()
+                },
+                ReactTextOverflow {
+                    white_space: Some(WhiteSpace::Normal),
+                    ellipsis: true,
+                    max_lines: Some(2),
+                },
+            ))
+            .id();
+        let shown = |app: &App| app.world().get::<Text>(text).unwrap().0.clone();
+        let height = |app: &App| app.world().get::<ComputedNode>(text).unwrap().size.y;
+
+        app.update();
+        let full_height = height(&app);
+        for _ in 0..5 {
+            app.update();
+        }
+        assert!(shown(&app).starts_with("Sword of a"));
+        assert!(shown(&app).ends_with(ELLIPSIS));
+        assert!(height(&app) < full_height);
+
+        app.world_mut()
+            .entity_mut(text)
+            .remove::<ReactTextOverflow>();
+        app.update();
+        app.update();
+        assert_eq!(shown(&app), source);
+        assert_eq!(height(&app), full_height);
+    }
+
+    #[test]
+    fn test_truncate_block_across_spans() {
+        let root = Entity::from_raw_u32(1).unwrap();
+        let span = Entity::from_raw_u32(2).unwrap();
+        let tail = Entity::from_raw_u32(3).unwrap();
+        let content = vec![
+            (root, "Deal ".to_string()),
+            (span, "50".to_string()),
+            (tail, " damage".to_string()),
+        ];
+        assert_eq!(
+            truncate_block(&content, 6, true),
+            vec![
+                (root, "Deal ".to_string()),
+                (span, "5…".to_string()),
+                (tail, String::new()),
+            ]
+        );
+        // A cut at a span boundary ends the span before it.
+        assert_eq!(truncate_block(&content, 5, false)[0].1, "Deal ");
+        assert_eq!(truncate_block(&content, 5, false)[1].1, "");
+    }
+}
@@ -0,0 +1,364 @@
+//! Rich text: nested `bevy-text` nodes and raw text under a `bevy-text` flow as
+//! one paragraph.
+//!
+//! React creates every text node as its own `Text` entity. [`sync_text_spans`]
+//! turns text nodes parented to another text node into Bevy [`TextSpan`]s of
+//! the outermost `Text`, and back into `Text` once detached. Spans keep their
+//! own color / font / weight / size; what they leave unset is inherited from
+//! the enclosing text by [`inherit_text_span_styles`], as in CSS.
+//!
+//! Spans have no box or [`Interaction`] of their own: presses and clicks on
+//! the root are retargeted to the span under the cursor ([`text_span_target`])
+//! and bubble back up through the root in JS.
+
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use bevy::text::{ComputedTextBlock, LineHeight, TextLayoutInfo};
+use bevy::ui::ContentSize;
+use bevy::ui::widget::TextNodeFlags;
+
+use crate::react::fonts::{FontStyle, ReactFontRegistry};
+use crate::react::style::{
+    StyleProps, parse_color, parse_val, style_font_families, style_font_style, style_font_weight,
+    style_line_height,
+};
+use crate::react::systems::{ReactFadedColors, ReactNode, ReactRawText, ReactTextNode};
+
+/// Text style a text node sets itself, before inheritance.
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct ReactTextStyle {
+    pub color: Option<Color>,
+    pub font_families: Option<Vec<String>>,
+    pub font_weight: Option<u16>,
+    pub font_style: Option<FontStyle>,
+    pub font_size: Option<f32>,
+    pub line_height: Option<LineHeight>,
+}
+
+impl ReactTextStyle {
+    pub fn from_style(style: &StyleProps) -> Self {
+        Self {
+            color: style.color.as_deref().and_then(parse_color),
+            font_families: style_font_families(style),
+            font_weight: style_font_weight(style),
+            font_style: style_font_style(style),
+            font_size: style
+                .font_size
+                .as_ref()
+                .and_then(|size| match parse_val(&size.0) {
+                    Val::Px(px) => Some(px),
+                    _ => None,
+                }),
+            line_height: style_line_height(style),
+        }
+    }
+
+    /// Fill fields this style leaves unset from `outer`.
+    fn or(self, outer: &Self) -> Self {
+        Self {
+            color: self.color.or(outer.color),
+            font_families: self.font_families.or_else(|| outer.font_families.clone()),
+            font_weight: self.font_weight.or(outer.font_weight),
+            font_style: self.font_style.or(outer.font_style),
+            font_size: self.font_size.or(outer.font_size),
+            line_height: self.line_height.or(outer.line_height),
+        }
+    }
+
+    fn sets_font(&self) -> bool {
+        self.font_families.is_some() || self.font_weight.is_some() || self.font_style.is_some()
+    }
+}
+
+/// Set a text node's content, in place whether it is a `Text` root or a span.
+pub(crate) fn set_text_content(commands: &mut Commands, entity: Entity, content: String) {
+    commands.queue(move |world: &mut World| {
+        let Ok(mut entity) = world.get_entity_mut(entity) else {
+            return;
+        };
+        if let Some(mut span) = entity.get_mut::<TextSpan>() {
+            span.0 = content;
+        } else {
+            entity.insert(Text::new(content));
+        }
+    });
+}
+
+/// Components `Text` brings in that a span must not carry: spans have no
+/// layout box of their own, and a `TextLayout` on a span is rejected by Bevy.
+type TextRootOnly = (
+    Text,
+    TextLayout,
+    ComputedTextBlock,
+    TextLayoutInfo,
+    TextNodeFlags,
+    ContentSize,
+    Node,
+    ComputedNode,
+    Interaction,
+);
+
+type TextRootOrSpan = (
+    Entity,
+    Option<&'static ChildOf>,
+    Option<&'static Text>,
+    Option<&'static TextSpan>,
+    Has<TextLayout>,
+);
+
+/// Convert text nodes between `Text` and [`TextSpan`] as they move in and out
+/// of another text node.
+pub fn sync_text_spans(mut commands: Commands, nodes: Query<TextRootOrSpan, With<ReactTextNode>>) {
+    for (entity, child_of, text, span, has_layout) in &nodes {
+        let nested = child_of.is_some_and(|child_of| nodes.contains(child_of.parent()));
+        match (nested, text, span) {
+            (true, Some(text), _) => {
+                commands
+                    .entity(entity)
+                    .remove::<TextRootOnly>()
+                    .insert(TextSpan::new(text.0.clone()));
+            }
+            // `textAlign` on a span: only the root's layout applies.
+            (true, None, Some(_)) if has_layout => {
+                commands.entity(entity).remove::<TextRootOnly>();
+            }
+            (false, _, Some(span)) => {
+                commands
+                    .entity(entity)
+                    .remove::<TextSpan>()
+                    .insert((Text::new(span.0.clone()), Interaction::default()));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Text nodes an event can be retargeted to, with what it takes to skip raw text.
+pub(crate) type SpanTargets = (
+    &'static ReactNode,
+    Has<ReactRawText>,
+    Option<&'static ChildOf>,
+);
+
+/// Node id of the innermost text element under `point` (node-local, physical
+/// px) inside the `Text` root `root`, or `None` when that is the root itself.
+/// Raw strings resolve to their enclosing element, as DOM events do.
+pub(crate) fn text_span_target(
+    root: Entity,
+    point: Vec2,
+    layout: &TextLayoutInfo,
+    spans: &Query<SpanTargets, With<ReactTextNode>>,
+) -> Option<u64> {
+    let &(mut entity, _) = layout
+        .section_rects
+        .iter()
+        .find(|(_, rect)| rect.contains(point))?;
+    while entity != root {
+        let (node, raw, child_of) = spans.get(entity).ok()?;
+        if !raw {
+            return Some(node.node_id);
+        }
+        entity = child_of?.parent();
+    }
+    None
+}
+
+type SpanStyleTargets = (
+    Entity,
+    Option<&'static ChildOf>,
+    Option<&'static ReactTextStyle>,
+    &'static mut TextFont,
+    &'static mut TextColor,
+    Option<&'static ReactFadedColors>,
+);
+
+/// Write each span's inherited color and font. Runs before
+/// `apply_react_opacity`, which fades the written colors.
+pub fn inherit_text_span_styles(
+    asset_server: Res<AssetServer>,
+    fonts: Res<ReactFontRegistry>,
+    mut nodes: Query<SpanStyleTargets, With<ReactTextNode>>,
+) {
+    // Parent of every text node, `None` at the edge of a text tree.
+    let text_parents: HashMap<Entity, Option<Entity>> = nodes
+        .iter()
+        .map(|(entity, child_of, ..)| (entity, child_of.map(ChildOf::parent)))
+        .collect();
+    let text_parent = |entity: Entity| {
+        text_parents
+            .get(&entity)
+            .copied()
+            .flatten()
+            .filter(|parent| text_parents.contains_key(parent))
+    };
+
+    for (&span, _) in text_parents
+        .iter()
+        .filter(|(span, _)| text_parent(**span).is_some())
+    {
+        let own = nodes
+            .get(span)
+            .ok()
+            .and_then(|(_, _, style, ..)| style.cloned())
+            .unwrap_or_default();
+        let mut inherited = ReactTextStyle::default();
+        let mut root_font = Handle::default();
+        let mut ancestor = text_parent(span);
+        while let Some(entity) = ancestor {
+            let Ok((_, _, style, font, color, faded)) = nodes.get(entity) else {
+                break;
+            };
+            ancestor = text_parent(entity);
+            if ancestor.is_some() {
+                if let Some(style) = style {
+                    inherited = inherited.or(style);
+                }
+                continue;
+            }
+            // The root's components already hold its resolved style; its
+            // family / weight / style are kept for spans that change one.
+            let root_style = style.cloned().unwrap_or_default();
+            inherited = inherited.or(&ReactTextStyle {
+                color: Some(faded.and_then(ReactFadedColors::text).unwrap_or(color.0)),
+                font_size: Some(font.font_size),
+                line_height: Some(font.line_height),
+                ..root_style
+            });
+            root_font = font.font.clone();
+        }
+
+        let Ok((_, _, _, mut font, mut color, faded)) = nodes.get_mut(span) else {
+            continue;
+        };
+        let resolved = own.clone().or(&inherited);
+        let face = if own.sets_font() || inherited.sets_font() {
+            resolved.font_families.as_deref().and_then(|families| {
+                fonts.resolve(
+                    families,
+                    resolved.font_weight.unwrap_or(400),
+                    resolved.font_style.unwrap_or_default(),
+                    &asset_server,
+                )
+            })
+        } else {
+            None
+        };
+        let target = TextFont {
+            font: face.unwrap_or(root_font),
+            font_size: resolved.font_size.unwrap_or(font.font_size),
+            line_height: resolved.line_height.unwrap_or(font.line_height),
+            ..font.clone()
+        };
+        if *font != target {
+            *font = target;
+        }
+        // Own colors are written (and transitioned) by the style systems.
+        if own.color.is_none()
+            && let Some(inherited) = inherited.color
+            && faded.and_then(ReactFadedColors::text).unwrap_or(color.0) != inherited
+        {
+            color.0 = inherited;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spawn_text(app: &mut App, content: &str, parent: Option<Entity>) -> Entity {
+        let mut entity = app.world_mut().spawn((Text::new(content), ReactTextNode));
+        if let Some(parent) = parent {
+            entity.insert(ChildOf(parent));
+        }
+        entity.id()
+    }
+
+    #[test]
+    fn test_nested_text_becomes_spans_and_back() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Font>()
+            .init_resource::<ReactFontRegistry>()
+            .add_systems(Update, (sync_text_spans, inherit_text_span_styles).chain());
+        let root = spawn_text(&mut app, "Deal ", None);
+        app.world_mut().entity_mut(root).insert((
+            TextColor(Color::WHITE),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+        ));
+        let damage = spawn_text(&mut app, "50", Some(root));
+        let red = Color::srgb(1.0, 0.0, 0.0);
+        app.world_mut().entity_mut(damage).insert((
+            TextColor(red),
+            ReactTextStyle {
+                color: Some(red),
+                font_size: Some(24.0),
+                ..default()
+            },
+        ));
+        let tail = spawn_text(&mut app, " damage", Some(root));
+        app.update();
+        app.update();
+
+        let world = app.world();
+        assert!(world.get::<Text>(root).is_some());
+        assert_eq!(world.get::<TextSpan>(damage).unwrap().0, "50");
+        assert!(world.get::<Text>(damage).is_none());
+        assert!(world.get::<Node>(tail).is_none());
+        assert!(world.get::<TextLayout>(tail).is_none());
+        assert_eq!(world.get::<TextColor>(damage).unwrap().0, red);
+        assert_eq!(world.get::<TextFont>(damage).unwrap().font_size, 24.0);
+        assert_eq!(world.get::<TextColor>(tail).unwrap().0, Color::WHITE);
+        assert_eq!(world.get::<TextFont>(tail).unwrap().font_size, 18.0);
+
+        let mut commands = app.world_mut().commands();
+        set_text_content(&mut commands, damage, "75".into());
+        app.world_mut().flush();
+        assert_eq!(app.world().get::<TextSpan>(damage).unwrap().0, "75");
+
+        app.world_mut().entity_mut(damage).remove::<ChildOf>();
+        app.update();
+        assert_eq!(app.world().get::<Text>(damage).unwrap().0, "75");
+        assert!(app.world().get::<TextSpan>(damage).is_none());
+        assert!(app.world().get::<Node>(damage).is_some());
+    }
+
+    #[test]
+    fn test_span_target_skips_raw_text_and_root() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut world = World::new();
+        let node = |node_id| (ReactNode { node_id }, ReactTextNode);
+        let root = world.spawn((node(1), Text::new("Click "))).id();
+        let link = world
+            .spawn((node(2), TextSpan::default(), ChildOf(root)))
+            .id();
+        let link_text = world
+            .spawn((node(3), ReactRawText, TextSpan::new("here"), ChildOf(link)))
+            .id();
+        let tail = world
+            .spawn((node(4), ReactRawText, TextSpan::new("!"), ChildOf(root)))
+            .id();
+        let section = |entity, x: f32| (entity, Rect::new(x, 0.0, x + 50.0, 20.0));
+        let layout = TextLayoutInfo {
+            section_rects: vec![
+                section(root, 0.0),
+                section(link, 50.0),
+                section(link_text, 100.0),
+                section(tail, 150.0),
+            ],
+            ..default()
+        };
+
+        let targets = world
+            .run_system_once(move |spans: Query<SpanTargets, With<ReactTextNode>>| {
+                [10.0, 60.0, 110.0, 160.0, 400.0]
+                    .map(|x| text_span_target(root, Vec2::new(x, 10.0), &layout, &spans))
+            })
+            .unwrap();
+        assert_eq!(targets, [None, Some(2), Some(2), None, None]);
+    }
+}
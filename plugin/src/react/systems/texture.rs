@@ -0,0 +1,568 @@
+//! Render-to-texture roots ([`ReactTextureTarget`]) and mesh pointer forwarding.
+//!
+//! Bevy's `ui_focus_system` only tracks window cursors, so nodes on an image
+//! target never get [`Interaction`] or [`RelativeCursorPosition`] from it.
+//! [`apply_react_texture_pointer`] fills them in from the root's
+//! [`ReactTextureCursor`], which lets `handle_input_interactions` and
+//! `handle_pointer_move` treat texture roots like any other root.
+
+use bevy::camera::RenderTarget;
+use bevy::mesh::{Indices, PrimitiveTopology, VertexAttributeValues};
+use bevy::picking::backend::HitData;
+use bevy::picking::events::{Move, Out, Pointer, Press, Release};
+use bevy::picking::mesh_picking::ray_cast::{Backfaces, ray_mesh_intersection};
+use bevy::picking::pointer::PointerButton;
+use bevy::platform::collections::{HashMap, HashSet};
+use bevy::prelude::*;
+use bevy::ui::{
+    FocusPolicy, OverrideClip, RelativeCursorPosition, UiGlobalTransform, UiStack,
+    clip_check_recursive,
+};
+
+use crate::react::systems::types::*;
+
+/// Observer: spawn the offscreen camera for a new [`ReactTextureTarget`].
+pub(crate) fn on_react_texture_target_added(
+    add: On<Add, ReactTextureTarget>,
+    targets: Query<&ReactTextureTarget>,
+    mut commands: Commands,
+) {
+    let root = add.entity;
+    let Ok(target) = targets.get(root) else {
+        return;
+    };
+
+    let camera = commands
+        .spawn((
+            Camera2d,
+            Camera {
+                target: RenderTarget::from(target.image.clone()),
+                // Draw before window cameras so the texture is ready this frame.
+                order: -1,
+                clear_color: ClearColorConfig::Custom(Color::NONE),
+                ..default()
+            },
+            ReactTextureCursor::default(),
+        ))
+        .id();
+    commands
+        .entity(root)
+        .insert((UiTargetCamera(camera), ReactTextureCamera(camera)));
+}
+
+/// Observer: despawn the offscreen camera when [`ReactTextureTarget`] goes away.
+pub(crate) fn on_react_texture_target_removed(
+    remove: On<Remove, ReactTextureTarget>,
+    cameras: Query<&ReactTextureCamera>,
+    mut commands: Commands,
+) {
+    let Ok(camera) = cameras.get(remove.entity) else {
+        return;
+    };
+    if let Ok(mut camera_commands) = commands.get_entity(camera.0) {
+        camera_commands.try_despawn();
+    }
+    // `Remove` also fires on despawn, so the root may already be going away.
+    if let Ok(mut root_commands) = commands.get_entity(remove.entity) {
+        root_commands.try_remove::<(UiTargetCamera, ReactTextureCamera)>();
+    }
+}
+
+/// Observer: track the cursor while it moves over a [`ReactTextureSurface`].
+pub(crate) fn on_react_texture_surface_move(
+    event: On<Pointer<Move>>,
+    surfaces: Query<(&ReactTextureSurface, &GlobalTransform, &Mesh3d)>,
+    meshes: Res<Assets<Mesh>>,
+    roots: Query<(&ReactTextureTarget, &ReactTextureCamera)>,
+    mut cursors: Query<&mut ReactTextureCursor>,
+) {
+    update_surface_cursor(
+        event.entity,
+        &event.event.hit,
+        &surfaces,
+        &meshes,
+        &roots,
+        &mut cursors,
+        |cursor, position| cursor.position = position,
+    );
+}
+
+/// Observer: primary press on a [`ReactTextureSurface`].
+pub(crate) fn on_react_texture_surface_press(
+    event: On<Pointer<Press>>,
+    surfaces: Query<(&ReactTextureSurface, &GlobalTransform, &Mesh3d)>,
+    meshes: Res<Assets<Mesh>>,
+    roots: Query<(&ReactTextureTarget, &ReactTextureCamera)>,
+    mut cursors: Query<&mut ReactTextureCursor>,
+) {
+    if event.event.button != PointerButton::Primary {
+        return;
+    }
+    update_surface_cursor(
+        event.entity,
+        &event.event.hit,
+        &surfaces,
+        &meshes,
+        &roots,
+        &mut cursors,
+        |cursor, position| {
+            cursor.position = position;
+            cursor.pressed = true;
+        },
+    );
+}
+
+/// Observer: primary release on a [`ReactTextureSurface`].
+pub(crate) fn on_react_texture_surface_release(
+    event: On<Pointer<Release>>,
+    surfaces: Query<(&ReactTextureSurface, &GlobalTransform, &Mesh3d)>,
+    meshes: Res<Assets<Mesh>>,
+    roots: Query<(&ReactTextureTarget, &ReactTextureCamera)>,
+    mut cursors: Query<&mut ReactTextureCursor>,
+) {
+    if event.event.button != PointerButton::Primary {
+        return;
+    }
+    update_surface_cursor(
+        event.entity,
+        &event.event.hit,
+        &surfaces,
+        &meshes,
+        &roots,
+        &mut cursors,
+        |cursor, _| cursor.pressed = false,
+    );
+}
+
+/// Observer: the pointer left a [`ReactTextureSurface`].
+pub(crate) fn on_react_texture_surface_out(
+    event: On<Pointer<Out>>,
+    surfaces: Query<(&ReactTextureSurface, &GlobalTransform, &Mesh3d)>,
+    meshes: Res<Assets<Mesh>>,
+    roots: Query<(&ReactTextureTarget, &ReactTextureCamera)>,
+    mut cursors: Query<&mut ReactTextureCursor>,
+) {
+    update_surface_cursor(
+        event.entity,
+        &event.event.hit,
+        &surfaces,
+        &meshes,
+        &roots,
+        &mut cursors,
+        |cursor, _| *cursor = ReactTextureCursor::default(),
+    );
+}
+
+fn update_surface_cursor(
+    surface: Entity,
+    hit: &HitData,
+    surfaces: &Query<(&ReactTextureSurface, &GlobalTransform, &Mesh3d)>,
+    meshes: &Assets<Mesh>,
+    roots: &Query<(&ReactTextureTarget, &ReactTextureCamera)>,
+    cursors: &mut Query<&mut ReactTextureCursor>,
+    update: impl FnOnce(&mut ReactTextureCursor, Option<Vec2>),
+) {
+    let Ok((surface, transform, mesh)) = surfaces.get(surface) else {
+        return;
+    };
+    let Ok((target, camera)) = roots.get(surface.root) else {
+        return;
+    };
+    let Ok(mut cursor) = cursors.get_mut(camera.0) else {
+        return;
+    };
+
+    let position = meshes
+        .get(&mesh.0)
+        .and_then(|mesh| surface_uv(mesh, transform, hit))
+        .map(|uv| uv_to_target(uv, target.size));
+    update(&mut cursor, position);
+}
+
+/// UV under a mesh picking hit, interpolated from the hit triangle's
+/// `ATTRIBUTE_UV_0`. `None` without a hit position and normal, or when the
+/// mesh is not a triangle list with positions and UVs.
+pub(crate) fn surface_uv(mesh: &Mesh, transform: &GlobalTransform, hit: &HitData) -> Option<Vec2> {
+    if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
+        return None;
+    }
+    let positions = mesh.attribute(Mesh::ATTRIBUTE_POSITION)?.as_float3()?;
+    let uvs = match mesh.attribute(Mesh::ATTRIBUTE_UV_0)? {
+        VertexAttributeValues::Float32x2(uvs) => uvs.as_slice(),
+        _ => return None,
+    };
+    // Re-cast a short ray onto the hit point to find its triangle.
+    let normal = Dir3::new(hit.normal?).ok()?;
+    let ray = Ray3d::new(hit.position? + *normal * SURFACE_RAY_OFFSET, -normal);
+    let transform = transform.affine();
+    let hit = match mesh.indices() {
+        Some(Indices::U16(indices)) => ray_mesh_intersection(
+            ray,
+            &transform,
+            positions,
+            None,
+            Some(indices),
+            Some(uvs),
+            Backfaces::Include,
+        ),
+        Some(Indices::U32(indices)) => ray_mesh_intersection(
+            ray,
+            &transform,
+            positions,
+            None,
+            Some(indices),
+            Some(uvs),
+            Backfaces::Include,
+        ),
+        None => ray_mesh_intersection::<usize>(
+            ray,
+            &transform,
+            positions,
+            None,
+            None,
+            Some(uvs),
+            Backfaces::Include,
+        ),
+    };
+    hit?.uv
+}
+
+/// How far above the hit point [`surface_uv`] starts its ray, in world units.
+const SURFACE_RAY_OFFSET: f32 = 1e-3;
+
+/// Scale a surface UV to a pixel position in a target of `size`.
+pub(crate) fn uv_to_target(uv: Vec2, size: UVec2) -> Vec2 {
+    uv * size.as_vec2()
+}
+
+/// Per-node interaction this system last applied, and per-camera press state.
+///
+/// `ui_focus_system` resets texture nodes every frame, so the components
+/// themselves cannot carry state between frames.
+#[derive(Default)]
+pub(crate) struct TexturePointerState {
+    interactions: HashMap<Entity, Interaction>,
+    pressed: HashMap<Entity, bool>,
+}
+
+/// Apply each texture root's [`ReactTextureCursor`] to its nodes' [`Interaction`]
+/// and [`RelativeCursorPosition`], mirroring `ui_focus_system` (top-most first,
+/// stopping at [`FocusPolicy::Block`], ignoring points clipped by an ancestor).
+/// Runs after `UiSystems::Focus`.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub(crate) fn apply_react_texture_pointer(
+    roots: Query<(Entity, &ReactTextureCamera)>,
+    cursors: Query<&ReactTextureCursor>,
+    children: Query<&Children>,
+    clipping: Query<(&ComputedNode, &UiGlobalTransform, &Node)>,
+    child_of: Query<&ChildOf, Without<OverrideClip>>,
+    ui_stack: Option<Res<UiStack>>,
+    mut nodes: Query<(
+        &ComputedNode,
+        &UiGlobalTransform,
+        Option<&InheritedVisibility>,
+        Option<&FocusPolicy>,
+        Option<&mut Interaction>,
+        Option<&mut RelativeCursorPosition>,
+    )>,
+    mut state: Local<TexturePointerState>,
+) {
+    let Some(ui_stack) = ui_stack else {
+        return;
+    };
+
+    let mut interactions = HashMap::default();
+    let mut pressed = HashMap::default();
+
+    for (root, camera) in &roots {
+        let cursor = cursors.get(camera.0).copied().unwrap_or_default();
+        let was_pressed = state.pressed.get(&camera.0).copied().unwrap_or(false);
+        let just_pressed = cursor.pressed && !was_pressed;
+        pressed.insert(camera.0, cursor.pressed);
+
+        let subtree: HashSet<Entity> = std::iter::once(root)
+            .chain(children.iter_descendants(root))
+            .collect();
+        let mut blocked = false;
+
+        for &entity in ui_stack.uinodes.iter().rev() {
+            if !subtree.contains(&entity) {
+                continue;
+            }
+            let Ok((node, transform, visibility, focus_policy, interaction, relative)) =
+                nodes.get_mut(entity)
+            else {
+                continue;
+            };
+
+            let visible = visibility.is_none_or(|v| v.get());
+            let contains = visible
+                && cursor.position.is_some_and(|point| {
+                    node.contains_point(*transform, point)
+                        && clip_check_recursive(point, entity, &clipping, &child_of)
+                });
+            let normalized = cursor
+                .position
+                .filter(|_| visible)
+                .and_then(|point| node.normalize_point(*transform, point));
+
+            if let Some(mut relative) = relative {
+                relative.set_if_neq(RelativeCursorPosition {
+                    cursor_over: contains,
+                    normalized,
+                });
+            }
+
+            let Some(mut interaction) = interaction else {
+                if contains && focus_policy.is_none_or(|p| *p == FocusPolicy::Block) {
+                    blocked = true;
+                }
+                continue;
+            };
+
+            let previous = state
+                .interactions
+                .get(&entity)
+                .copied()
+                .unwrap_or(Interaction::None);
+            let next = if contains && !blocked {
+                if just_pressed || (previous == Interaction::Pressed && cursor.pressed) {
+                    Interaction::Pressed
+                } else {
+                    Interaction::Hovered
+                }
+            } else if previous == Interaction::Pressed
+                && cursor.pressed
+                && cursor.position.is_some()
+            {
+                // Like window UI, a press stays captured while dragging off the node.
+                Interaction::Pressed
+            } else {
+                Interaction::None
+            };
+            interaction.set_if_neq(next);
+            interactions.insert(entity, next);
+
+            if contains && focus_policy.is_none_or(|p| *p == FocusPolicy::Block) {
+                blocked = true;
+            }
+        }
+    }
+
+    state.interactions = interactions;
+    state.pressed = pressed;
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::Affine2;
+
+    use super::*;
+
+    const TARGET: UVec2 = UVec2::new(200, 100);
+
+    fn setup_app() -> (App, Entity, Entity, Entity) {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_observer(on_react_texture_target_added)
+            .add_observer(on_react_texture_target_removed)
+            .init_resource::<UiStack>()
+            .add_systems(Update, apply_react_texture_pointer);
+
+        let root = app
+            .world_mut()
+            .spawn((
+                ReactRoot::new(),
+                ComputedNode {
+                    size: TARGET.as_vec2(),
+                    ..default()
+                },
+                UiGlobalTransform::from(Affine2::from_translation(TARGET.as_vec2() / 2.0)),
+                ReactTextureTarget {
+                    image: Handle::default(),
+                    size: TARGET,
+                },
+            ))
+            .id();
+        // 50x50 button in the top-left corner of the texture.
+        let button = app
+            .world_mut()
+            .spawn((
+                ComputedNode {
+                    size: Vec2::splat(50.0),
+                    ..default()
+                },
+                UiGlobalTransform::from(Affine2::from_translation(Vec2::splat(25.0))),
+                Interaction::None,
+                RelativeCursorPosition::default(),
+                ChildOf(root),
+            ))
+            .id();
+        app.world_mut().resource_mut::<UiStack>().uinodes = vec![root, button];
+        app.update();
+
+        let camera = app.world().get::<ReactTextureCamera>(root).unwrap().0;
+        (app, root, button, camera)
+    }
+
+    fn set_cursor(app: &mut App, camera: Entity, position: Option<Vec2>, pressed: bool) {
+        *app.world_mut()
+            .get_mut::<ReactTextureCursor>(camera)
+            .unwrap() = ReactTextureCursor { position, pressed };
+        app.update();
+    }
+
+    #[test]
+    fn test_target_spawns_camera_and_despawns_it_with_root() {
+        let (mut app, root, _, camera) = setup_app();
+        assert_eq!(
+            app.world().get::<UiTargetCamera>(root).map(|t| t.entity()),
+            Some(camera)
+        );
+        assert!(app.world().get::<ReactTextureCursor>(camera).is_some());
+
+        app.world_mut().entity_mut(root).despawn();
+        app.update();
+        assert!(app.world().get_entity(camera).is_err());
+    }
+
+    #[test]
+    fn test_surface_uv_maps_rectangle_corners() {
+        let mesh = Mesh::from(Rectangle::new(2.0, 1.0));
+        let transform = GlobalTransform::from_translation(Vec3::new(10.0, 0.0, 0.0));
+        let uv = |position: Vec3| {
+            let hit = HitData::new(Entity::PLACEHOLDER, 0.0, Some(position), Some(Vec3::Z));
+            surface_uv(&mesh, &transform, &hit)
+        };
+
+        let top_left = uv(Vec3::new(9.0, 0.5, 0.0)).unwrap();
+        assert!(top_left.abs_diff_eq(Vec2::ZERO, 1e-4));
+        let center = uv(Vec3::new(10.0, 0.0, 0.0)).unwrap();
+        assert!(uv_to_target(center, TARGET).abs_diff_eq(Vec2::new(100.0, 50.0), 1e-2));
+        assert_eq!(uv(Vec3::new(12.0, 0.0, 0.0)), None);
+    }
+
+    #[test]
+    fn test_surface_uv_reads_mesh_uvs() {
+        // A plane facing +Y with its own UV layout.
+        let mesh = Mesh::from(Plane3d::new(Vec3::Y, Vec2::splat(1.0))).with_inserted_attribute(
+            Mesh::ATTRIBUTE_UV_0,
+            vec![[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]],
+        );
+        let positions = mesh
+            .attribute(Mesh::ATTRIBUTE_POSITION)
+            .and_then(|p| p.as_float3())
+            .unwrap()
+            .to_vec();
+        let transform = GlobalTransform::IDENTITY;
+        for (position, expected) in
+            positions
+                .iter()
+                .zip([[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]])
+        {
+            // Nudge towards the centre so the hit lands inside a triangle.
+            let point = Vec3::from(*position) * 0.999;
+            let hit = HitData::new(Entity::PLACEHOLDER, 0.0, Some(point), Some(Vec3::Y));
+            let uv = surface_uv(&mesh, &transform, &hit).unwrap();
+            assert!(
+                uv.abs_diff_eq(Vec2::from(expected), 1e-2),
+                "{uv} != {expected:?}"
+            );
+        }
+
+        let strip = Mesh::new(PrimitiveTopology::TriangleStrip, default());
+        let hit = HitData::new(Entity::PLACEHOLDER, 0.0, Some(Vec3::ZERO), Some(Vec3::Y));
+        assert_eq!(surface_uv(&strip, &transform, &hit), None);
+    }
+
+    #[test]
+    fn test_cursor_drives_interaction_and_relative_position() {
+        let (mut app, _, button, camera) = setup_app();
+
+        set_cursor(&mut app, camera, Some(Vec2::new(25.0, 25.0)), false);
+        assert_eq!(
+            app.world().get::<Interaction>(button),
+            Some(&Interaction::Hovered)
+        );
+        let relative = app.world().get::<RelativeCursorPosition>(button).unwrap();
+        assert!(relative.cursor_over);
+        assert_eq!(relative.normalized, Some(Vec2::ZERO));
+
+        set_cursor(&mut app, camera, Some(Vec2::new(25.0, 25.0)), true);
+        assert_eq!(
+            app.world().get::<Interaction>(button),
+            Some(&Interaction::Pressed)
+        );
+
+        set_cursor(&mut app, camera, Some(Vec2::new(25.0, 25.0)), false);
+        assert_eq!(
+            app.world().get::<Interaction>(button),
+            Some(&Interaction::Hovered)
+        );
+
+        set_cursor(&mut app, camera, Some(Vec2::new(150.0, 75.0)), false);
+        assert_eq!(
+            app.world().get::<Interaction>(button),
+            Some(&Interaction::None)
+        );
+    }
+
+    #[test]
+    fn test_press_outside_node_does_not_press_it() {
+        let (mut app, _, button, camera) = setup_app();
+
+        set_cursor(&mut app, camera, Some(Vec2::new(150.0, 75.0)), true);
+        set_cursor(&mut app, camera, Some(Vec2::new(25.0, 25.0)), true);
+        assert_eq!(
+            app.world().get::<Interaction>(button),
+            Some(&Interaction::Hovered)
+        );
+    }
+
+    #[test]
+    fn test_node_clipped_by_parent_is_not_hovered() {
+        let (mut app, root, button, camera) = setup_app();
+        // A 50x50 clipping container in the top-left corner, holding a node
+        // that overflows it to the right.
+        let container = app
+            .world_mut()
+            .spawn((
+                Node {
+                    overflow: Overflow::clip(),
+                    ..default()
+                },
+                ComputedNode {
+                    size: Vec2::splat(50.0),
+                    ..default()
+                },
+                UiGlobalTransform::from(Affine2::from_translation(Vec2::splat(25.0))),
+                ChildOf(root),
+            ))
+            .id();
+        let clipped = app
+            .world_mut()
+            .spawn((
+                ComputedNode {
+                    size: Vec2::new(100.0, 50.0),
+                    ..default()
+                },
+                UiGlobalTransform::from(Affine2::from_translation(Vec2::new(50.0, 25.0))),
+                Interaction::None,
+                ChildOf(container),
+            ))
+            .id();
+        app.world_mut().resource_mut::<UiStack>().uinodes = vec![root, button, container, clipped];
+
+        set_cursor(&mut app, camera, Some(Vec2::new(25.0, 25.0)), false);
+        assert_eq!(
+            app.world().get::<Interaction>(clipped),
+            Some(&Interaction::Hovered)
+        );
+
+        set_cursor(&mut app, camera, Some(Vec2::new(75.0, 25.0)), false);
+        assert_eq!(
+            app.world().get::<Interaction>(clipped),
+            Some(&Interaction::None)
+        );
+    }
+}
@@ -0,0 +1,133 @@
+//! Host-side CSS transforms: [`ReactTransform`] → Bevy [`UiTransform`].
+//!
+//! Bevy scales and rotates UI nodes about their centre. A `transformOrigin`
+//! elsewhere needs the node's size, so [`apply_react_transforms`] resolves it
+//! against the last computed layout and writes the result as a translation.
+
+use bevy::prelude::*;
+
+use crate::react::style::ReactTransform;
+
+/// Insert [`ReactTransform`], or clear it and reset [`UiTransform`] if it was set.
+pub(crate) fn sync_react_transform(
+    commands: &mut Commands,
+    entity: Entity,
+    transform: Option<ReactTransform>,
+) {
+    match transform {
+        Some(transform) => {
+            commands.entity(entity).insert(transform);
+        }
+        None => {
+            commands.queue(move |world: &mut World| {
+                let Ok(mut entity_mut) = world.get_entity_mut(entity) else {
+                    return;
+                };
+                if entity_mut.take::<ReactTransform>().is_some() {
+                    entity_mut.insert(UiTransform::IDENTITY);
+                }
+            });
+        }
+    }
+}
+
+/// Resolve transforms into `UiTransform`. Runs in `PostUpdate` before UI layout,
+/// so off-centre origins use the previous frame's size.
+pub fn apply_react_transforms(
+    mut nodes: Query<(&ReactTransform, &ComputedNode, &mut UiTransform)>,
+) {
+    for (transform, computed, mut ui_transform) in &mut nodes {
+        let size = computed.size * computed.inverse_scale_factor;
+        let resolved = transform.to_ui_transform(size);
+        // Keep change detection quiet for static transforms.
+        if *ui_transform != resolved {
+            *ui_transform = resolved;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spawn_node(app: &mut App, transform: ReactTransform) -> Entity {
+        app.world_mut()
+            .spawn((
+                Node::default(),
+                ComputedNode {
+                    size: Vec2::new(100.0, 50.0),
+                    ..default()
+                },
+                transform,
+            ))
+            .id()
+    }
+
+    #[test]
+    fn test_centered_transform_keeps_css_units() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_systems(Update, apply_react_transforms);
+        let node = spawn_node(
+            &mut app,
+            ReactTransform {
+                translation: Val2::percent(10.0, 0.0),
+                scale: Vec2::splat(2.0),
+                ..default()
+            },
+        );
+        app.update();
+
+        let ui_transform = app.world().get::<UiTransform>(node).unwrap();
+        assert_eq!(ui_transform.translation, Val2::percent(10.0, 0.0));
+        assert_eq!(ui_transform.scale, Vec2::splat(2.0));
+    }
+
+    #[test]
+    fn test_top_left_origin_scales_away_from_corner() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_systems(Update, apply_react_transforms);
+        let node = spawn_node(
+            &mut app,
+            ReactTransform {
+                scale: Vec2::splat(2.0),
+                origin: Val2::ZERO,
+                ..default()
+            },
+        );
+        app.update();
+
+        // Doubling about the top-left corner moves the centre by half the size.
+        let ui_transform = app.world().get::<UiTransform>(node).unwrap();
+        assert_eq!(ui_transform.translation, Val2::px(50.0, 25.0));
+    }
+
+    #[test]
+    fn test_clearing_transform_resets_ui_transform() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_systems(Update, apply_react_transforms);
+        let node = spawn_node(
+            &mut app,
+            ReactTransform {
+                rotation: 1.0,
+                ..default()
+            },
+        );
+        app.update();
+        assert_ne!(
+            app.world().get::<UiTransform>(node),
+            Some(&UiTransform::IDENTITY)
+        );
+
+        let mut commands = app.world_mut().commands();
+        sync_react_transform(&mut commands, node, None);
+        app.world_mut().flush();
+        assert!(app.world().get::<ReactTransform>(node).is_none());
+        assert_eq!(
+            app.world().get::<UiTransform>(node),
+            Some(&UiTransform::IDENTITY)
+        );
+    }
+}
@@ -1,9 +1,12 @@
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::path::Path;
 use std::{fs, io};
 
 use bevy::asset::uuid::Uuid;
+use bevy::image::BevyDefault;
 use bevy::platform::collections::HashMap;
 use bevy::prelude::*;
+use bevy::render::render_resource::TextureFormat;
 
 use crate::react::client::ReactClientReceiver;
 
@@ -24,6 +27,11 @@ pub struct ReactBundleNames(pub Vec<String>);
 #[derive(Component)]
 pub struct ReactTextNode;
 
+/// Marker for raw text (`CreateText`): a string, not an element, so events on
+/// it go to the enclosing text element.
+#[derive(Component)]
+pub struct ReactRawText;
+
 /// Optional default font for a React root (used when `fontFamily` is unset).
 ///
 /// Attach to the same entity as [`ReactRoot`]. Takes precedence over
@@ -80,6 +88,183 @@ impl ReactBundle {
             ReactDirtyFlag,
         )
     }
+
+    /// Like [`Self::new`], but mounts the named export instead of `default`.
+    ///
+    /// Roots that share a [`ReactScriptSource::module_name`] evaluate the module
+    /// once, so one bundle can serve several screens:
+    ///
+    /// ```ignore
+    /// commands.spawn(ReactBundle::with_export(Node::default(), source.clone(), "InventoryPanel"));
+    /// commands.spawn(ReactBundle::with_export(Node::default(), source, "PauseMenu"));
+    /// ```
+    pub fn with_export(
+        root_node: Node,
+        source: ReactScriptSource,
+        export: impl Into<String>,
+    ) -> impl Bundle {
+        (Self::new(root_node, source), ReactRootExport::new(export))
+    }
+}
+
+/// Module export a [`ReactRoot`] mounts (`mod[export].render(rootId)`).
+///
+/// Attach to the same entity as [`ReactRoot`]. Roots without it use `default`.
+#[derive(Component, Clone, Debug, PartialEq, Eq)]
+pub struct ReactRootExport(pub String);
+
+impl ReactRootExport {
+    pub const DEFAULT: &'static str = "default";
+
+    pub fn new(export: impl Into<String>) -> Self {
+        Self(export.into())
+    }
+
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+
+    /// Bracket accessor for generated JS (`["InventoryPanel"]`), safely quoted.
+    pub(crate) fn js_accessor(&self) -> String {
+        format!("[{}]", serde_json::Value::from(self.0.as_str()))
+    }
+}
+
+impl Default for ReactRootExport {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT)
+    }
+}
+
+/// Pause a mounted [`ReactRoot`] without tearing it down.
+///
+/// While present, host events for the root are dropped, the root entity is
+/// [`Visibility::Hidden`], and JS is told to `suspend(rootId)` so timers and
+/// effects can stop. Removing it restores visibility and calls `resume(rootId)`;
+/// React state is kept.
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct ReactRootPaused;
+
+/// Render a [`ReactRoot`] into an offscreen [`Image`] instead of the window.
+///
+/// Attach to the same entity as [`ReactRoot`]. The plugin spawns a `Camera2d`
+/// targeting `image`, points the root at it with [`UiTargetCamera`], and records
+/// it in [`ReactTextureCamera`]. Size the root node to fill the target
+/// (e.g. `Val::Percent(100.0)`) and show `image` on a mesh with a
+/// [`ReactTextureSurface`] to make it interactive.
+#[derive(Component, Clone, Debug)]
+pub struct ReactTextureTarget {
+    pub image: Handle<Image>,
+    /// Target size in physical pixels; UV hits are scaled by this.
+    pub size: UVec2,
+}
+
+impl ReactTextureTarget {
+    /// Allocate a transparent render-target image of `size` in `images`.
+    pub fn new(images: &mut Assets<Image>, size: UVec2) -> Self {
+        let image = Image::new_target_texture(size.x, size.y, TextureFormat::bevy_default());
+        Self {
+            image: images.add(image),
+            size,
+        }
+    }
+}
+
+/// Camera rendering a [`ReactTextureTarget`] root; despawned with the target.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReactTextureCamera(pub Entity);
+
+/// Cursor of a texture root, in target pixels, kept on its [`ReactTextureCamera`].
+///
+/// Written by the mesh picking observers for [`ReactTextureSurface`] hits; other
+/// input sources (a gamepad crosshair, a laser pointer) can write it directly.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct ReactTextureCursor {
+    pub position: Option<Vec2>,
+    pub pressed: bool,
+}
+
+/// Mesh displaying a [`ReactTextureTarget`] root; pointer hits on it drive that root.
+///
+/// Hits are mapped through the mesh's `ATTRIBUTE_UV_0`, so any triangle-list
+/// mesh works, with UV `(0, 0)` at the texture's top-left corner.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ReactTextureSurface {
+    pub root: Entity,
+}
+
+impl ReactTextureSurface {
+    pub fn new(root: Entity) -> Self {
+        Self { root }
+    }
+}
+
+/// A root's module imported and its export's `render(rootId)` ran.
+///
+/// The first [`ReactRootCommitted`] follows once React flushes its first frame.
+#[derive(Message, Clone, Debug)]
+pub struct ReactRootMounted {
+    pub root: Entity,
+}
+
+/// React committed a frame for `root`; `ops` host mutations were applied.
+#[derive(Message, Clone, Debug)]
+pub struct ReactRootCommitted {
+    pub root: Entity,
+    pub ops: usize,
+}
+
+/// Importing the root's module or rendering its export threw.
+#[derive(Message, Clone, Debug)]
+pub struct ReactRootFailed {
+    pub root: Entity,
+    pub error: String,
+}
+
+/// A root was torn down (its [`ReactRoot`] removed or the entity despawned).
+///
+/// `root` may already be despawned when this is read; `root_id` identifies it.
+#[derive(Message, Clone, Debug)]
+pub struct ReactRootUnmounted {
+    pub root: Entity,
+    pub root_id: String,
+}
+
+/// Write a lifecycle message if the app registered it; headless apps may not.
+pub(crate) fn write_lifecycle_message<M: Message>(commands: &mut Commands, message: M) {
+    commands.queue(move |world: &mut World| {
+        if let Some(mut messages) = world.get_resource_mut::<Messages<M>>() {
+            messages.write(message);
+        }
+    });
+}
+
+/// ESM modules already handed to the JS engine, keyed by module name.
+///
+/// Lets several roots share one evaluated bundle (and one copy of React).
+/// A module is re-sent only when its source changes; HMR clears the set
+/// together with the engine's module cache.
+#[derive(Resource, Default, Debug)]
+pub struct ReactLoadedModules {
+    sources: HashMap<String, u64>,
+}
+
+impl ReactLoadedModules {
+    /// Record `source` as loaded. Returns `true` when the engine still needs it.
+    pub fn mark_loaded(&mut self, source: &ReactScriptSource) -> bool {
+        let mut hasher = DefaultHasher::new();
+        source.source_string.hash(&mut hasher);
+        let hash = hasher.finish();
+        self.sources.insert(source.module_name.clone(), hash) != Some(hash)
+    }
+
+    pub fn is_loaded(&self, module_name: &str) -> bool {
+        self.sources.contains_key(module_name)
+    }
+
+    pub fn clear(&mut self) {
+        self.sources.clear();
+    }
 }
 
 #[derive(Clone, Debug, Component)]
@@ -208,6 +393,8 @@ pub struct ReactStyleState {
     pub focused: Option<crate::react::style::StyleProps>,
     pub checked: Option<crate::react::style::StyleProps>,
     pub transition: crate::react::style::StyleTransitions,
+    /// Keyframe animations from the base style's `animation` prop.
+    pub animations: Vec<crate::react::animation::AnimationSpec>,
     pub anim: StyleAnimationState,
 }
 
@@ -232,56 +419,123 @@ impl ReactStyleState {
                 .as_ref()
                 .map(|c| c.without_interaction_meta()),
             transition: props.transition.clone(),
+            animations: crate::react::animation::style_animations(props),
             anim: StyleAnimationState::default(),
         }
     }
 }
 
-/// Runtime tracks for host-side color / opacity transitions.
+/// Runtime tracks for host-side transitions.
+///
+/// Tracks outlive the transition that created them, so a later target change
+/// (interaction or React prop update) starts from the last displayed value.
 #[derive(Clone, Debug, Default)]
 pub struct StyleAnimationState {
     pub background_color: Option<ColorAnim>,
     pub border_color: Option<ColorAnim>,
     pub opacity: Option<FloatAnim>,
     pub color: Option<ColorAnim>,
+    pub transform: Option<TransformAnim>,
+    /// Layout lengths and border radii, keyed by camelCase longhand (`paddingLeft`).
+    pub lengths: HashMap<&'static str, ValAnim>,
+    /// One run per entry of [`ReactStyleState::animations`].
+    pub keyframes: Vec<KeyframeRun>,
+    /// Sampled keyframe style, layered over the displayed style.
+    pub keyframe_overlay: Option<crate::react::style::StyleProps>,
 }
 
+/// Playback of one keyframe animation on a node.
 #[derive(Clone, Debug)]
-pub struct ColorAnim {
-    pub from: Color,
-    pub to: Color,
+pub struct KeyframeRun {
+    pub name: String,
     pub elapsed: f32,
-    pub duration: f32,
+    pub started: bool,
+    pub ended: bool,
 }
 
-impl ColorAnim {
-    pub fn current(&self) -> Color {
-        if self.duration <= 0.0 {
-            return self.to;
+impl KeyframeRun {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            elapsed: 0.0,
+            started: false,
+            ended: false,
         }
-        let t = (self.elapsed / self.duration).clamp(0.0, 1.0);
-        lerp_color(self.from, self.to, t)
     }
 }
 
+/// A value that host-side transitions can interpolate.
+pub trait StyleLerp: Copy {
+    fn style_lerp(&self, to: &Self, t: f32) -> Self;
+
+    fn approx_eq(&self, other: &Self) -> bool;
+}
+
+impl StyleLerp for Color {
+    fn style_lerp(&self, to: &Self, t: f32) -> Self {
+        lerp_color(*self, *to, t)
+    }
+
+    fn approx_eq(&self, other: &Self) -> bool {
+        let a = self.to_srgba();
+        let b = other.to_srgba();
+        (a.red - b.red).abs() < 0.001
+            && (a.green - b.green).abs() < 0.001
+            && (a.blue - b.blue).abs() < 0.001
+            && (a.alpha - b.alpha).abs() < 0.001
+    }
+}
+
+impl StyleLerp for f32 {
+    fn style_lerp(&self, to: &Self, t: f32) -> Self {
+        self + (to - self) * t
+    }
+
+    fn approx_eq(&self, other: &Self) -> bool {
+        (self - other).abs() < 0.0001
+    }
+}
+
+impl StyleLerp for Val {
+    fn style_lerp(&self, to: &Self, t: f32) -> Self {
+        crate::react::style::lerp_val(*self, *to, t)
+    }
+
+    fn approx_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl StyleLerp for crate::react::style::ReactTransform {
+    fn style_lerp(&self, to: &Self, t: f32) -> Self {
+        self.lerp(to, t)
+    }
+
+    fn approx_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+/// One property's transition from `from` to `to`.
 #[derive(Clone, Debug)]
-pub struct FloatAnim {
-    pub from: f32,
-    pub to: f32,
+pub struct StyleAnim<T> {
+    pub from: T,
+    pub to: T,
     pub elapsed: f32,
-    pub duration: f32,
+    pub spec: crate::react::style::TransitionSpec,
 }
 
-impl FloatAnim {
-    pub fn current(&self) -> f32 {
-        if self.duration <= 0.0 {
-            return self.to;
-        }
-        let t = (self.elapsed / self.duration).clamp(0.0, 1.0);
-        self.from + (self.to - self.from) * t
+impl<T: StyleLerp> StyleAnim<T> {
+    pub fn current(&self) -> T {
+        self.from.style_lerp(&self.to, self.spec.progress(self.elapsed))
     }
 }
 
+pub type ColorAnim = StyleAnim<Color>;
+pub type FloatAnim = StyleAnim<f32>;
+pub type ValAnim = StyleAnim<Val>;
+pub type TransformAnim = StyleAnim<crate::react::style::ReactTransform>;
+
 fn lerp_color(from: Color, to: Color, t: f32) -> Color {
     let a = from.to_srgba();
     let b = to.to_srgba();
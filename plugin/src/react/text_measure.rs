@@ -0,0 +1,170 @@
+//! Host-side text measurement for React (`measureText` in JS).
+//!
+//! [`MEASURE_TEXT_COMMAND`] lays a string out with the same font resolution
+//! as text nodes (`fontFamily` / `fontWeight` / `fontStyle` through
+//! [`ReactFontRegistry`], then [`ReactDefaultFont`]) and returns its size in
+//! logical pixels, wrapped at `maxWidth` when given.
+
+use bevy::prelude::*;
+use bevy::text::{ComputedTextBlock, CosmicFontSystem, TextBounds, TextPipeline};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::react::ReactBridge;
+use crate::react::fonts::{ReactFontRegistry, style_font};
+use crate::react::style::{
+    StyleProps, parse_val, style_line_height, style_text_layout, style_white_space,
+};
+use crate::react::systems::ReactDefaultFont;
+
+/// Bridge command: `{ text, style?, maxWidth? }` → `{ width, height }`.
+pub const MEASURE_TEXT_COMMAND: &str = "measure_text";
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MeasureTextArgs {
+    text: String,
+    #[serde(default)]
+    style: StyleProps,
+    max_width: Option<f32>,
+}
+
+/// Size of `text` in logical pixels with `style`'s font, size, line height and
+/// `whiteSpace`, wrapped at `max_width` (unbounded when `None`). Fails while
+/// the font is still loading.
+pub fn measure_text(
+    world: &mut World,
+    text: &str,
+    style: &StyleProps,
+    max_width: Option<f32>,
+) -> Result<Vec2, String> {
+    let Some(asset_server) = world.get_resource::<AssetServer>() else {
+        return Err("no AssetServer".to_string());
+    };
+    let font = world
+        .get_resource::<ReactFontRegistry>()
+        .and_then(|fonts| style_font(style, fonts, asset_server))
+        .or_else(|| {
+            world
+                .get_resource::<ReactDefaultFont>()
+                .and_then(|font| font.0.clone())
+        })
+        .unwrap_or_default();
+    let mut text_font = TextFont { font, ..default() };
+    if let Some(font_size) = style.font_size.as_ref()
+        && let Val::Px(px) = parse_val(&font_size.0)
+    {
+        text_font.font_size = px;
+    }
+    if let Some(line_height) = style_line_height(style) {
+        text_font.line_height = line_height;
+    }
+    let layout = style_text_layout(style).unwrap_or_default();
+    let text = match style_white_space(style) {
+        Some(white_space) => white_space.collapse(text),
+        None => text.to_string(),
+    };
+
+    if !world.contains_resource::<TextPipeline>()
+        || !world.contains_resource::<CosmicFontSystem>()
+        || !world.contains_resource::<Assets<Font>>()
+    {
+        return Err("text rendering is not set up".to_string());
+    }
+    world.resource_scope(|world, mut pipeline: Mut<TextPipeline>| {
+        world.resource_scope(|world, mut font_system: Mut<CosmicFontSystem>| {
+            let mut computed = ComputedTextBlock::default();
+            let spans = std::iter::once((
+                Entity::PLACEHOLDER,
+                0,
+                text.as_str(),
+                &text_font,
+                Color::WHITE,
+            ));
+            let mut measure = pipeline
+                .create_text_measure(
+                    Entity::PLACEHOLDER,
+                    world.resource::<Assets<Font>>(),
+                    spans,
+                    1.0,
+                    &layout,
+                    &mut computed,
+                    &mut font_system,
+                )
+                .map_err(|err| format!("cannot measure text: {err}"))?;
+            Ok(match max_width {
+                Some(width) => measure.compute_size(
+                    TextBounds::new_horizontal(width),
+                    &mut computed,
+                    &mut font_system,
+                ),
+                None => measure.max,
+            })
+        })
+    })
+}
+
+/// Handle [`MEASURE_TEXT_COMMAND`] calls on `bridge`.
+pub(crate) fn register_measure_text(bridge: &ReactBridge) {
+    bridge.register(MEASURE_TEXT_COMMAND, |world, args| {
+        let args: MeasureTextArgs = match serde_json::from_value(args) {
+            Ok(args) => args,
+            Err(err) => return json!({ "error": format!("invalid measureText args: {err}") }),
+        };
+        match measure_text(world, &args.text, &args.style, args.max_width) {
+            Ok(size) => json!({ "width": size.x, "height": size.y }),
+            Err(err) => json!({ "error": err }),
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::text::TextPlugin;
+
+    use super::*;
+    use crate::react::process_react_bridge_calls;
+
+    #[test]
+    fn test_measure_text_command() {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            ImagePlugin::default(),
+            TextPlugin,
+        ))
+        .init_resource::<ReactBridge>()
+        .add_systems(Update, process_react_bridge_calls);
+        let bridge = app.world().resource::<ReactBridge>().clone();
+        register_measure_text(&bridge);
+
+        let size = |app: &mut App, args: serde_json::Value| {
+            bridge.enqueue_call(MEASURE_TEXT_COMMAND.to_string(), args, Some(1));
+            app.update();
+            bridge.drain_call_results().remove(0).value
+        };
+        let short = size(
+            &mut app,
+            json!({ "text": "Sword", "style": { "fontSize": 16 } }),
+        );
+        let long = size(
+            &mut app,
+            json!({ "text": "Sword of a thousand truths", "style": { "fontSize": 16 } }),
+        );
+        let wrapped = size(
+            &mut app,
+            json!({ "text": "Sword of a thousand truths", "style": { "fontSize": 16 }, "maxWidth": 80 }),
+        );
+        let width = |value: &serde_json::Value| value["width"].as_f64().unwrap();
+        let height = |value: &serde_json::Value| value["height"].as_f64().unwrap();
+        assert!(width(&short) > 0.0 && width(&long) > width(&short));
+        assert!(width(&wrapped) <= 80.0 && height(&wrapped) > height(&long));
+
+        assert!(
+            size(&mut app, json!({ "style": {} }))
+                .get("error")
+                .is_some()
+        );
+    }
+}
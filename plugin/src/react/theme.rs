@@ -0,0 +1,101 @@
+//! Theme tokens — `var(--name)` in style props, resolved on the host.
+//!
+//! Variables come from [`ReactTheme`]: the global resource, a component on a
+//! root entity, or a node's `theme` prop (which scopes its subtree). Nodes whose
+//! style uses `var()` keep it in
+//! [`ReactComposedStyle`](crate::react::ReactComposedStyle), and
+//! [`apply_composed_styles`](crate::react::apply_composed_styles) resolves it
+//! once the node is attached and again whenever a variable it reads changes,
+//! so switching palettes restyles the UI without a React render.
+
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use serde_json::Value;
+
+/// Theme variables for `var(--name)` in style props.
+///
+/// As a resource it is the global theme; as a component on a root entity or a
+/// node (the `theme` prop) it overrides variables for that subtree. Names are
+/// stored without the leading `--`.
+#[derive(Resource, Component, Debug, Clone, Default, PartialEq)]
+pub struct ReactTheme {
+    vars: HashMap<String, String>,
+}
+
+impl ReactTheme {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builder form of [`ReactTheme::set`].
+    pub fn with(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.set(name, value);
+        self
+    }
+
+    /// Set `name` (with or without `--`) to a CSS value, e.g. `"#4af"` or `"8px"`.
+    pub fn set(&mut self, name: &str, value: impl Into<String>) {
+        self.vars.insert(var_name(name).to_string(), value.into());
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.vars.get(var_name(name)).map(String::as_str)
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<String> {
+        self.vars.remove(var_name(name))
+    }
+
+    /// Replace every variable at once (e.g. switching light → dark).
+    pub fn replace(&mut self, other: ReactTheme) {
+        self.vars = other.vars;
+    }
+
+    /// Parse a `theme` prop object: `{ "--accent": "#4af", "space-2": 8 }`.
+    pub fn from_json(value: &Value) -> Option<Self> {
+        let map = value.as_object()?;
+        let mut theme = Self::new();
+        for (name, value) in map {
+            match value {
+                Value::String(s) => theme.set(name, s.as_str()),
+                Value::Number(n) => theme.set(name, n.to_string()),
+                other => log::warn!("Invalid theme value for '{}': {}", name, other),
+            }
+        }
+        Some(theme)
+    }
+}
+
+fn var_name(name: &str) -> &str {
+    let name = name.trim();
+    name.strip_prefix("--").unwrap_or(name)
+}
+
+/// Sync the node's `theme` prop, which scopes variables to its subtree.
+pub(crate) fn sync_theme_scope(commands: &mut Commands, entity: Entity, props: &Value) {
+    let scope = props.get("theme").and_then(ReactTheme::from_json);
+    match scope {
+        Some(scope) => commands.entity(entity).insert(scope),
+        None => commands.entity(entity).remove::<ReactTheme>(),
+    };
+}
+
+/// Look `name` up on `entity`, its ancestors, then the global theme.
+pub(crate) fn lookup_var(
+    entity: Entity,
+    name: &str,
+    global: Option<&ReactTheme>,
+    scopes: &Query<Ref<ReactTheme>>,
+    parents: &Query<&ChildOf>,
+) -> Option<String> {
+    let mut current = Some(entity);
+    while let Some(e) = current {
+        if let Ok(scope) = scopes.get(e)
+            && let Some(value) = scope.get(name)
+        {
+            return Some(value.to_string());
+        }
+        current = parents.get(e).ok().map(ChildOf::parent);
+    }
+    global.and_then(|theme| theme.get(name)).map(str::to_string)
+}